@@ -17,5 +17,7 @@ fuzz_target!(|data| {
         roundtrip!(data, date);
         roundtrip!(data, time);
         roundtrip!(data, datetime);
+        roundtrip!(data, interval);
+        roundtrip!(data, repeating_interval);
     }
 });
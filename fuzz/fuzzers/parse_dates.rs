@@ -8,5 +8,7 @@ fuzz_target!(|data| {
         let _ = iso8601::date(data);
         let _ = iso8601::time(data);
         let _ = iso8601::datetime(data);
+        let _ = iso8601::interval(data);
+        let _ = iso8601::repeating_interval(data);
     }
 });
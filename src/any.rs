@@ -0,0 +1,65 @@
+use core::str::FromStr;
+
+use crate::{error, parsers, Component, Date, DateTime, Duration, Interval, ParseError, Time};
+
+/// Any of the representations [`parse_any()`] tells apart.
+///
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::Iso8601::from_str("P1D"),
+///     Ok(iso8601::Iso8601::Duration(iso8601::duration("P1D").unwrap()))
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Iso8601 {
+    /// A calendar, week or ordinal date, like `2023-02-08`
+    Date(Date),
+    /// A time of day, like `23:40:00Z`
+    Time(Time),
+    /// A date and time, like `2023-02-08T23:40:00Z`
+    DateTime(DateTime),
+    /// A duration, like `P1DT2H`
+    Duration(Duration),
+    /// A time interval, like `2023-02-08/P1D`
+    Interval(Interval),
+}
+
+impl FromStr for Iso8601 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_any(s)
+    }
+}
+
+/// Parses a string that may be any of a datetime, a date, a time, a duration or an interval.
+///
+/// Unlike the other top-level functions, the whole string has to match,
+/// as otherwise `2023-02-08T23:40:00Z` would already be a date followed by leftovers.
+/// The representations are tried in the order datetime, date, time, duration and interval,
+/// so that a bare duration like `P1D` is an [`Iso8601::Duration`] rather than an interval.
+///
+/// See [`datetime()`][`crate::datetime()`], [`date()`][`crate::date()`],
+/// [`time()`][`crate::time()`], [`duration()`][`crate::duration()`]
+/// and [`interval()`][`crate::interval()`] for the supported formats.
+///
+/// ## Example
+///
+/// ```rust
+/// use iso8601::Iso8601;
+///
+/// assert!(matches!(iso8601::parse_any("2023-02-08T23:40:00Z"), Ok(Iso8601::DateTime(_))));
+/// assert!(matches!(iso8601::parse_any("2023-02-08"), Ok(Iso8601::Date(_))));
+/// assert!(matches!(iso8601::parse_any("23:40:00Z"), Ok(Iso8601::Time(_))));
+/// assert!(matches!(iso8601::parse_any("PT2H"), Ok(Iso8601::Duration(_))));
+/// assert!(matches!(iso8601::parse_any("2023-02-08/PT2H"), Ok(Iso8601::Interval(_))));
+/// ```
+pub fn parse_any(string: &str) -> Result<Iso8601, ParseError> {
+    error::prefix(
+        Component::Any,
+        string.as_bytes(),
+        parsers::parse_any(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
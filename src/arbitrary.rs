@@ -0,0 +1,210 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Date, DateTime, Duration, Fraction, Precision, Time, Unit, UtcOffset};
+
+/// Dates with each component in range, as parsed, so without [`Date::violations()`]
+/// but for those that do not exist in the calendar, like `2023-02-30`.
+impl<'a> Arbitrary<'a> for Date {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let year = i32::arbitrary(u)?;
+        Ok(match u.int_in_range(0..=2)? {
+            0 => Date::YMD {
+                year,
+                month: u.int_in_range(1..=12)?,
+                day: u.int_in_range(1..=31)?,
+            },
+            1 => Date::Week {
+                year,
+                ww: u.int_in_range(1..=53)?,
+                d: u.int_in_range(1..=7)?,
+            },
+            _ => Date::Ordinal {
+                year,
+                ddd: u.int_in_range(1..=366)?,
+            },
+        })
+    }
+}
+
+/// Offsets of less than a day, as parsed.
+impl<'a> Arbitrary<'a> for UtcOffset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => UtcOffset::Utc,
+            1 => UtcOffset::Fixed {
+                seconds: u.int_in_range(-86_399..=86_399)?,
+            },
+            2 => UtcOffset::UnknownLocal,
+            _ => UtcOffset::Unspecified,
+        })
+    }
+}
+
+/// Times without [`Time::violations()`], including leap seconds and the end of a day `24:00:00`,
+/// whose fraction of a second has as many digits as it was written with.
+impl<'a> Arbitrary<'a> for Time {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let precision = *u.choose(&[
+            Precision::Hour,
+            Precision::Minute,
+            Precision::Second,
+            Precision::Fraction,
+        ])?;
+        let offset = UtcOffset::arbitrary(u)?;
+
+        let hour = u.int_in_range(0..=24)?;
+        if hour == 24 {
+            return Ok(Time {
+                hour,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset,
+                precision: precision.min(Precision::Second),
+            });
+        }
+
+        let fraction_digits = match precision {
+            Precision::Fraction => u.int_in_range(1..=9)?,
+            _ => 0,
+        };
+        let unit = 10u32.pow(9 - u32::from(fraction_digits));
+        Ok(Time {
+            hour,
+            minute: u.int_in_range(0..=59)?,
+            second: u.int_in_range(0..=60)?,
+            nanosecond: u.int_in_range(0..=999_999_999 / unit)? * unit,
+            fraction_digits,
+            offset,
+            precision,
+        })
+    }
+}
+
+/// Datetimes of an arbitrary [`Date`] and [`Time`].
+impl<'a> Arbitrary<'a> for DateTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(DateTime {
+            date: Date::arbitrary(u)?,
+            time: Time::arbitrary(u)?,
+        })
+    }
+}
+
+/// Durations without [`Duration::violations()`], with units of any sign and size,
+/// and a fraction only of their smallest unit.
+impl<'a> Arbitrary<'a> for Duration {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1, 8)? {
+            return Ok(Duration::Weeks(i64::arbitrary(u)?));
+        }
+
+        let mut units = [0i64; 6];
+        for unit in units.iter_mut() {
+            *unit = i64::arbitrary(u)?;
+        }
+        let [year, month, day, hour, minute, second] = units;
+        let duration = Duration::YMDHMS {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond: u.int_in_range(-999..=999)?,
+            fraction: None,
+        };
+        if !u.ratio(1, 4)? {
+            return Ok(duration);
+        }
+
+        // everything after the unit with the fraction is zero
+        let unit = *u.choose(&[Unit::Year, Unit::Month, Unit::Day, Unit::Hour, Unit::Minute])?;
+        let fraction = Some(Fraction {
+            unit,
+            billionths: u.int_in_range(-999_999_999..=999_999_999)?,
+        });
+        let units = match unit {
+            Unit::Year => [year, 0, 0, 0, 0],
+            Unit::Month => [year, month, 0, 0, 0],
+            Unit::Day => [year, month, day, 0, 0],
+            Unit::Hour => [year, month, day, hour, 0],
+            _ => [year, month, day, hour, minute],
+        };
+        let [year, month, day, hour, minute] = units;
+        Ok(Duration::YMDHMS {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: 0,
+            millisecond: 0,
+            fraction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{Date, DateTime, Duration, Time};
+
+    // Bytes that look random enough to build many different values from
+    fn bytes(len: usize) -> Vec<u8> {
+        let mut state = 0x2023_0208_2340_u64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn values_are_in_range() {
+        let bytes = bytes(1 << 16);
+        let mut u = Unstructured::new(&bytes);
+
+        for _ in 0..500 {
+            let date = Date::arbitrary(&mut u).unwrap();
+            assert!(date
+                .violations()
+                .iter()
+                .all(|&kind| kind == crate::ErrorKind::NonexistentDate));
+
+            let time = Time::arbitrary(&mut u).unwrap();
+            assert_eq!(time.violations(), [], "{:?}", time);
+            assert_eq!(
+                crate::time(&time.to_string()).unwrap().to_string(),
+                time.to_string()
+            );
+
+            let dt = DateTime::arbitrary(&mut u).unwrap();
+            assert!(dt.time.violations().is_empty());
+
+            let duration = Duration::arbitrary(&mut u).unwrap();
+            assert_eq!(duration.violations(), [], "{:?}", duration);
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(
+            Date::arbitrary(&mut u).unwrap(),
+            Date::YMD {
+                year: 0,
+                month: 1,
+                day: 1
+            }
+        );
+        assert!(Duration::arbitrary(&mut u).unwrap().is_zero());
+    }
+}
@@ -7,6 +7,8 @@ pub fn print_result<T: fmt::Debug>(input: &str, rest: &[u8], result: &T) {
     );
 }
 
+/// Asserts that a parser consumes the whole line, produces the expectation
+/// and that the result serializes back to the original line.
 #[macro_export]
 macro_rules! assert_parser {
     ($parser:ident, $line:expr, $expectation:expr) => {{
@@ -0,0 +1,163 @@
+//! Calendar math for the proleptic Gregorian calendar, like the parsers and [`Date`](crate::Date)
+//! use it, with astronomical year numbering where year `0` is 1 BC.
+//!
+//! ```
+//! use iso8601::calendar;
+//!
+//! assert!(calendar::is_leap_year(2024));
+//! assert_eq!(calendar::days_in_month(2023, 2), 28);
+//! assert_eq!(calendar::days_in_year(2024), 366);
+//! assert_eq!(calendar::weeks_in_iso_year(2020), 53);
+//! ```
+
+/// Whether `year` has 366 days.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days in `month` of `year`, `0` for months outside of `1..=12`.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// The number of days in `year`.
+pub fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// The number of weeks in the ISO week-numbering `year`, either 52 or 53.
+pub fn weeks_in_iso_year(year: i32) -> u32 {
+    // A year has 53 weeks if it starts on a Thursday,
+    // or if it is a leap year starting on a Wednesday.
+    match weekday_from_days(days_from_civil(year, 1, 1)) {
+        4 => 53,
+        3 if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+/// The Julian Day Number of 1970-01-01, the number of days since
+/// the 1st of January 4713 BC of the proleptic Julian calendar.
+pub(crate) const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+
+/// The Modified Julian Day of 1970-01-01, the number of days since 1858-11-17.
+pub(crate) const MODIFIED_JULIAN_DAY_OF_EPOCH: i64 = 40_587;
+
+/// The Rata Die of 1970-01-01, the number of days since 0000-12-31.
+pub(crate) const RATA_DIE_OF_EPOCH: i64 = 719_163;
+
+/// The number of days since 1970-01-01 for a day of the proleptic Gregorian calendar.
+///
+/// Based on <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The year, month and day of the proleptic Gregorian calendar for a number of days since 1970-01-01.
+///
+/// Based on <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+/// The year has to fit into an `i32`.
+pub(crate) fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as i32, month as u32, day as u32)
+}
+
+/// The ISO weekday, from 1 (Monday) to 7 (Sunday), for a number of days since 1970-01-01.
+pub(crate) fn weekday_from_days(days: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    ((days + 3).rem_euclid(7) + 1) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(-1));
+    }
+
+    #[test]
+    fn month_lengths() {
+        assert_eq!(days_in_month(2023, 1), 31);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 4), 30);
+        assert_eq!(days_in_month(2023, 13), 0);
+    }
+
+    #[test]
+    fn iso_weeks() {
+        assert_eq!(weeks_in_iso_year(2015), 53);
+        assert_eq!(weeks_in_iso_year(2020), 53);
+        assert_eq!(weeks_in_iso_year(2023), 52);
+        assert_eq!(weeks_in_iso_year(2026), 53);
+    }
+
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(0, 1, 1), -719_528);
+        assert_eq!(weekday_from_days(days_from_civil(2023, 2, 8)), 3);
+        assert_eq!(weekday_from_days(days_from_civil(1969, 12, 28)), 7);
+    }
+
+    #[test]
+    fn days_to_civil() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(-719_528), (0, 1, 1));
+        for (year, month, day) in [
+            (2024, 2, 29),
+            (-333, 7, 11),
+            (i32::MIN, 1, 1),
+            (i32::MAX, 12, 31),
+        ] {
+            assert_eq!(
+                civil_from_days(days_from_civil(year, month, day)),
+                (year, month, day)
+            );
+        }
+    }
+}
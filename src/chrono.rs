@@ -1,24 +1,40 @@
 use chrono::TimeZone;
 use core::convert::TryFrom;
-use num_traits::FromPrimitive;
 
 // TODO: we already do validity checks on our own,
 // would be nice if we could use the unsafe versions of these conversions
+/// Fails with [`ConversionError::Invalid`](crate::ConversionError::Invalid) for dates that do not
+/// exist, and with [`ConversionError::OutOfRange`](crate::ConversionError::OutOfRange)
+/// for those outside of the years chrono supports, which are fewer than those of a [`Date`](crate::Date).
 impl TryFrom<crate::Date> for chrono::NaiveDate {
-    type Error = ();
+    type Error = crate::ConversionError;
 
     fn try_from(iso: crate::Date) -> Result<Self, Self::Error> {
+        iso.validate()
+            .map_err(|_| crate::ConversionError::Invalid)?;
         let maybe = match iso {
             crate::Date::YMD { year, month, day } => {
                 chrono::NaiveDate::from_ymd_opt(year, month, day)
             }
 
-            crate::Date::Week { year, ww, d } => chrono::Weekday::from_u32(d)
-                .and_then(|d| chrono::NaiveDate::from_isoywd_opt(year, ww, d)),
+            crate::Date::Week { year, ww, d } => crate::Weekday::from_number_from_monday(d)
+                .and_then(|d| chrono::NaiveDate::from_isoywd_opt(year, ww, d.into())),
 
             crate::Date::Ordinal { year, ddd } => chrono::NaiveDate::from_yo_opt(year, ddd),
         };
-        maybe.ok_or(())
+        maybe.ok_or(crate::ConversionError::OutOfRange)
+    }
+}
+
+/// Converts a date of chrono into a calendar date.
+impl From<chrono::NaiveDate> for crate::Date {
+    fn from(naive: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        crate::Date::YMD {
+            year: naive.year(),
+            month: naive.month(),
+            day: naive.day(),
+        }
     }
 }
 
@@ -29,8 +45,37 @@ impl crate::Date {
     }
 }
 
+impl From<crate::Weekday> for chrono::Weekday {
+    fn from(weekday: crate::Weekday) -> Self {
+        match weekday {
+            crate::Weekday::Monday => chrono::Weekday::Mon,
+            crate::Weekday::Tuesday => chrono::Weekday::Tue,
+            crate::Weekday::Wednesday => chrono::Weekday::Wed,
+            crate::Weekday::Thursday => chrono::Weekday::Thu,
+            crate::Weekday::Friday => chrono::Weekday::Fri,
+            crate::Weekday::Saturday => chrono::Weekday::Sat,
+            crate::Weekday::Sunday => chrono::Weekday::Sun,
+        }
+    }
+}
+
+impl From<chrono::Weekday> for crate::Weekday {
+    fn from(weekday: chrono::Weekday) -> Self {
+        match weekday {
+            chrono::Weekday::Mon => crate::Weekday::Monday,
+            chrono::Weekday::Tue => crate::Weekday::Tuesday,
+            chrono::Weekday::Wed => crate::Weekday::Wednesday,
+            chrono::Weekday::Thu => crate::Weekday::Thursday,
+            chrono::Weekday::Fri => crate::Weekday::Friday,
+            chrono::Weekday::Sat => crate::Weekday::Saturday,
+            chrono::Weekday::Sun => crate::Weekday::Sunday,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_date {
+    use alloc::string::ToString;
     use chrono::Datelike;
     use core::convert::TryFrom;
 
@@ -45,11 +90,93 @@ mod test_date {
 
     #[test]
     fn naivedate_from_ywd() {
-        let iso = crate::date("2023-W06-2").unwrap();
+        let iso = crate::date("2023-W06-3").unwrap();
         let naive = chrono::NaiveDate::try_from(iso).unwrap();
         assert_eq!(naive.year(), 2023);
         assert_eq!(naive.month(), 2);
         assert_eq!(naive.day(), 8);
+        assert_eq!(naive.weekday(), chrono::Weekday::Wed);
+
+        let iso = crate::date("2023-W06-7").unwrap();
+        let naive = chrono::NaiveDate::try_from(iso).unwrap();
+        assert_eq!(naive.day(), 12);
+        assert_eq!(naive.weekday(), chrono::Weekday::Sun);
+    }
+
+    #[test]
+    fn naivedate_from_negative_years() {
+        let naive = |s| chrono::NaiveDate::try_from(crate::date(s).unwrap()).unwrap();
+        let expected = chrono::NaiveDate::from_ymd_opt(-333, 7, 11).unwrap();
+
+        assert_eq!(naive("-0333-07-11"), expected);
+        assert_eq!(naive("-0333-W28-1"), expected);
+        assert_eq!(naive("-0333-192"), expected);
+        assert_eq!(
+            naive("0000-W01-1"),
+            chrono::NaiveDate::from_ymd_opt(0, 1, 3).unwrap()
+        );
+        assert_eq!(
+            naive("-0001-W52-7"),
+            chrono::NaiveDate::from_ymd_opt(0, 1, 2).unwrap()
+        );
+        assert_eq!(naive("0000-02-29").ordinal(), 60);
+    }
+
+    #[test]
+    fn canonical_like_chrono() {
+        use chrono::Datelike;
+
+        let mut naive = chrono::NaiveDate::from_ymd_opt(-401, 1, 1).unwrap();
+        while naive.year() < 401 {
+            let week = naive.iso_week();
+            let iso = crate::Date::Week {
+                year: week.year(),
+                ww: week.week(),
+                d: naive.weekday().number_from_monday(),
+            };
+            let canonical = iso.canonical().unwrap();
+            assert_eq!(chrono::NaiveDate::try_from(canonical), Ok(naive));
+            assert_eq!(chrono::Weekday::from(canonical.weekday()), naive.weekday());
+            assert_eq!(crate::Weekday::from(naive.weekday()), iso.weekday());
+            assert_eq!(canonical.iso_week(), (week.year(), week.week()));
+            assert_eq!(iso.ordinal(), naive.ordinal());
+            naive = naive.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn date_from_naivedate() {
+        let date = |y, m, d| crate::Date::from(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(date(2023, 2, 8), crate::date("2023-02-08").unwrap());
+        assert_eq!(date(-333, 7, 11), crate::date("-0333-07-11").unwrap());
+        assert_eq!(date(2024, 2, 29).to_string(), "2024-02-29");
+
+        for naive in [chrono::NaiveDate::MIN, chrono::NaiveDate::MAX] {
+            assert_eq!(
+                chrono::NaiveDate::try_from(crate::Date::from(naive)),
+                Ok(naive)
+            );
+        }
+    }
+
+    #[test]
+    fn naivedate_errors() {
+        use crate::ConversionError;
+
+        let iso = crate::date("2023-02-30").unwrap();
+        assert_eq!(
+            chrono::NaiveDate::try_from(iso),
+            Err(ConversionError::Invalid)
+        );
+        // chrono supports fewer years than a `Date`
+        assert_eq!(
+            chrono::NaiveDate::try_from(crate::Date::MAX),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            chrono::DateTime::<chrono::FixedOffset>::try_from(crate::DateTime::MIN),
+            Err(ConversionError::OutOfRange)
+        );
     }
 
     #[test]
@@ -63,9 +190,49 @@ mod test_date {
 }
 
 impl TryFrom<crate::Time> for chrono::NaiveTime {
-    type Error = ();
+    type Error = crate::ConversionError;
     fn try_from(iso: crate::Time) -> Result<Self, Self::Error> {
-        chrono::NaiveTime::from_hms_opt(iso.hour, iso.minute, iso.second).ok_or(())
+        if iso.is_leap_second() {
+            // chrono represents leap seconds as an additional second worth of nanoseconds
+            iso.nanosecond
+                .checked_add(1_000_000_000)
+                .and_then(|nanos| {
+                    chrono::NaiveTime::from_hms_nano_opt(iso.hour, iso.minute, 59, nanos)
+                })
+                .ok_or(crate::ConversionError::Invalid)
+        } else {
+            chrono::NaiveTime::from_hms_nano_opt(iso.hour, iso.minute, iso.second, iso.nanosecond)
+                .ok_or(crate::ConversionError::Invalid)
+        }
+    }
+}
+
+/// Converts a time of chrono into a time without an offset,
+/// written with seconds and as many digits of a fraction of a second as it needs.
+///
+/// A leap second, which chrono represents as second `59` with more than a second
+/// worth of nanoseconds, is second `60`.
+impl From<chrono::NaiveTime> for crate::Time {
+    fn from(naive: chrono::NaiveTime) -> Self {
+        use chrono::Timelike;
+        let midnight = crate::Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: crate::UtcOffset::Unspecified,
+            precision: crate::Precision::Second,
+        };
+        let leap_second = naive.nanosecond() >= 1_000_000_000;
+        let nanoseconds = i128::from(naive.num_seconds_from_midnight()) * 1_000_000_000
+            + i128::from(naive.nanosecond() % 1_000_000_000);
+        // within the day, as chrono only has leap seconds at second 59
+        let (mut time, _) = midnight.add_nanoseconds(nanoseconds);
+        if leap_second {
+            time.second = 60;
+        }
+        time
     }
 }
 
@@ -76,26 +243,136 @@ impl crate::Time {
     }
 }
 
+#[cfg(test)]
+mod test_time {
+    use alloc::string::ToString;
+    use chrono::Timelike;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn naivetime_from_leap_second() {
+        let iso = crate::time("23:59:60").unwrap();
+        let naive = chrono::NaiveTime::try_from(iso).unwrap();
+        assert_eq!(naive.hour(), 23);
+        assert_eq!(naive.minute(), 59);
+        assert_eq!(naive.second(), 59);
+        assert_eq!(naive.nanosecond(), 1_000_000_000);
+    }
+
+    #[test]
+    fn naivetime_from_nanoseconds() {
+        let iso = crate::time("23:40:00.123456789").unwrap();
+        let naive = chrono::NaiveTime::try_from(iso).unwrap();
+        assert_eq!(naive.second(), 0);
+        assert_eq!(naive.nanosecond(), 123_456_789);
+
+        let iso = crate::time("23:59:60.5").unwrap();
+        let naive = chrono::NaiveTime::try_from(iso).unwrap();
+        assert_eq!(naive.nanosecond(), 1_500_000_000);
+    }
+
+    #[test]
+    fn time_from_naivetime() {
+        let time =
+            |s| crate::Time::from(chrono::NaiveTime::try_from(crate::time(s).unwrap()).unwrap());
+        assert_eq!(time("23:40").to_string(), "23:40:00");
+        assert_eq!(time("23:40:00.5").to_string(), "23:40:00.5");
+        assert_eq!(time("23:40:00.123456789").to_string(), "23:40:00.123456789");
+        assert_eq!(time("23:40:00.120").to_string(), "23:40:00.12");
+        assert_eq!(time("23:59:60").to_string(), "23:59:60");
+        assert_eq!(time("23:59:60.25").to_string(), "23:59:60.25");
+        assert_eq!(time("00:00:00Z").offset(), crate::UtcOffset::Unspecified);
+
+        let naive = chrono::NaiveTime::from_hms_nano_opt(12, 34, 56, 789_000_000).unwrap();
+        assert_eq!(
+            crate::Time::from(naive),
+            crate::time("12:34:56.789").unwrap()
+        );
+        assert_eq!(crate::Time::from(naive).into_naive(), Some(naive));
+    }
+
+    #[test]
+    fn naivetime_from_leap_second_policy() {
+        let iso = crate::time("23:59:60").unwrap();
+
+        let (clamped, _) = iso
+            .resolve_leap_second(crate::LeapSecondPolicy::Clamp)
+            .unwrap();
+        let naive = chrono::NaiveTime::try_from(clamped).unwrap();
+        assert_eq!(naive.second(), 59);
+        assert!(naive.nanosecond() < 1_000_000_000);
+
+        let (carried, _) = iso
+            .resolve_leap_second(crate::LeapSecondPolicy::Carry)
+            .unwrap();
+        let naive = chrono::NaiveTime::try_from(carried).unwrap();
+        assert_eq!(naive.hour(), 0);
+        assert_eq!(naive.second(), 0);
+    }
+}
+
+/// Converts a datetime into a [`chrono::DateTime`],
+/// taking a datetime without an offset to be in UTC.
+///
+/// See [`crate::DateTime::into_fixed_offset_with()`] for other choices.
 impl TryFrom<crate::DateTime> for chrono::DateTime<chrono::FixedOffset> {
-    type Error = ();
+    type Error = crate::ConversionError;
 
     fn try_from(iso: crate::DateTime) -> Result<Self, Self::Error> {
-        let crate::Time {
-            tz_offset_hours,
-            tz_offset_minutes,
-            ..
-        } = iso.time;
+        fixed_offset(iso, crate::MissingOffsetPolicy::AssumeUtc)
+    }
+}
 
-        let offset_minutes = tz_offset_hours * 3600 + tz_offset_minutes;
-        let offset = chrono::FixedOffset::east_opt(offset_minutes).ok_or(())?;
+fn fixed_offset(
+    iso: crate::DateTime,
+    policy: crate::MissingOffsetPolicy,
+) -> Result<chrono::DateTime<chrono::FixedOffset>, crate::ConversionError> {
+    // chrono has no notion of `24:00:00`
+    let iso = iso.normalize_midnight();
 
-        let naive_time = chrono::NaiveTime::try_from(iso.time)?;
-        let naive_date_time = chrono::NaiveDate::try_from(iso.date)?.and_time(naive_time);
+    let naive_time = chrono::NaiveTime::try_from(iso.time)?;
+    let naive_date_time = chrono::NaiveDate::try_from(iso.date)?.and_time(naive_time);
 
-        offset
+    let seconds = match (iso.time.offset_seconds(), policy) {
+        (Some(seconds), _) => seconds,
+        (None, crate::MissingOffsetPolicy::AssumeUtc) => 0,
+        #[cfg(feature = "std")]
+        (None, crate::MissingOffsetPolicy::AssumeLocal) => chrono::Local
             .from_local_datetime(&naive_date_time)
             .single()
-            .ok_or(())
+            .ok_or(crate::ConversionError::AmbiguousLocalTime)?
+            .offset()
+            .local_minus_utc(),
+        #[cfg(not(feature = "std"))]
+        (None, crate::MissingOffsetPolicy::AssumeLocal) => {
+            return Err(crate::ConversionError::MissingOffset)
+        }
+        (None, crate::MissingOffsetPolicy::Error) => {
+            return Err(crate::ConversionError::MissingOffset)
+        }
+    };
+
+    chrono::FixedOffset::east_opt(seconds)
+        .ok_or(crate::ConversionError::OutOfRange)?
+        .from_local_datetime(&naive_date_time)
+        .single()
+        .ok_or(crate::ConversionError::OutOfRange)
+}
+
+/// Converts a datetime of chrono into a calendar date and a time with its fixed offset,
+/// like [`crate::Date::from()`] and [`crate::Time::from()`] do.
+///
+/// The offset is [`UtcOffset::Fixed`](crate::UtcOffset::Fixed) also for UTC, written as `+00:00`.
+impl From<chrono::DateTime<chrono::FixedOffset>> for crate::DateTime {
+    fn from(datetime: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        let naive = datetime.naive_local();
+        let offset = crate::UtcOffset::Fixed {
+            seconds: datetime.offset().local_minus_utc(),
+        };
+        crate::DateTime {
+            date: naive.date().into(),
+            time: crate::Time::from(naive.time()).set_offset(offset),
+        }
     }
 }
 
@@ -105,6 +382,23 @@ impl crate::DateTime {
         chrono::DateTime::<chrono::FixedOffset>::try_from(self).ok()
     }
 
+    /// create a [`chrono::DateTime<chrono::FixedOffset>`] if possible,
+    /// with `policy` deciding about a datetime without an offset
+    ///
+    /// ```
+    /// use iso8601::MissingOffsetPolicy;
+    ///
+    /// let dt = iso8601::datetime("2023-02-08T23:40:00").unwrap();
+    /// assert!(dt.into_fixed_offset_with(MissingOffsetPolicy::AssumeUtc).is_some());
+    /// assert!(dt.into_fixed_offset_with(MissingOffsetPolicy::Error).is_none());
+    /// ```
+    pub fn into_fixed_offset_with(
+        self,
+        policy: crate::MissingOffsetPolicy,
+    ) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        fixed_offset(self, policy).ok()
+    }
+
     /// create a [`chrono::NativeDateTime`] if possible
     pub fn into_naive(self) -> Option<chrono::NaiveDateTime> {
         self.into_fixed_offset().map(|fxed| fxed.naive_local())
@@ -113,6 +407,7 @@ impl crate::DateTime {
 
 #[cfg(test)]
 mod test_datetime {
+    use alloc::string::ToString;
     use chrono::{Datelike, Offset, Timelike};
     use core::convert::TryFrom;
 
@@ -127,7 +422,15 @@ mod test_datetime {
         assert_eq!(datetime.hour(), 23);
         assert_eq!(datetime.minute(), 40);
         assert_eq!(datetime.second(), 00);
-        assert_eq!(datetime.offset().fix().local_minus_utc(), 3623);
+        assert_eq!(datetime.offset().fix().local_minus_utc(), 4980);
+    }
+
+    #[test]
+    fn datetime_from_iso_offset_seconds() {
+        let iso = crate::datetime("1900-01-01T00:00:00+00:00:32").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+
+        assert_eq!(datetime.offset().fix().local_minus_utc(), 32);
     }
 
     #[test]
@@ -158,9 +461,106 @@ mod test_datetime {
         assert_eq!(datetime.offset().fix().local_minus_utc(), 0);
     }
 
+    #[test]
+    fn datetime_from_iso_end_of_day() {
+        let iso = crate::datetime("2023-02-28T24:00:00Z").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+
+        assert_eq!(datetime.year(), 2023);
+        assert_eq!(datetime.month(), 3);
+        assert_eq!(datetime.day(), 1);
+        assert_eq!(datetime.hour(), 0);
+        assert_eq!(datetime.minute(), 0);
+        assert_eq!(datetime.second(), 00);
+    }
+
+    #[test]
+    fn datetime_from_iso_leap_second() {
+        let iso = crate::datetime("2016-12-31T23:59:60Z").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+
+        assert_eq!(datetime.year(), 2016);
+        assert_eq!(datetime.day(), 31);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.nanosecond(), 1_000_000_000);
+    }
+
+    #[test]
+    fn datetime_from_iso_negative_offset() {
+        let offset = |s| {
+            let datetime = chrono::DateTime::try_from(crate::datetime(s).unwrap()).unwrap();
+            datetime.offset().fix().local_minus_utc()
+        };
+        assert_eq!(offset("2023-02-08T23:40:00-05:00"), -18_000);
+        assert_eq!(offset("2023-02-08T23:40:00-03:30"), -12_600);
+        assert_eq!(offset("2023-02-08T23:40:00-00:30"), -1800);
+        assert_eq!(offset("2023-02-08T23:40:00-0030"), -1800);
+
+        let iso = crate::datetime("2023-02-08T23:40:00-00:30").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+        assert_eq!(datetime.naive_utc().hour(), 0);
+        assert_eq!(datetime.naive_utc().minute(), 10);
+    }
+
+    #[test]
+    fn datetime_missing_offset_policy() {
+        use crate::MissingOffsetPolicy;
+
+        let iso = crate::datetime("2023-02-08T23:40:00").unwrap();
+        let utc = iso
+            .into_fixed_offset_with(MissingOffsetPolicy::AssumeUtc)
+            .unwrap();
+        assert_eq!(utc.offset().local_minus_utc(), 0);
+        assert_eq!(Some(utc), iso.into_fixed_offset());
+        assert_eq!(iso.into_fixed_offset_with(MissingOffsetPolicy::Error), None);
+
+        #[cfg(feature = "std")]
+        {
+            use chrono::TimeZone;
+
+            let local = iso
+                .into_fixed_offset_with(MissingOffsetPolicy::AssumeLocal)
+                .unwrap();
+            assert_eq!(local.naive_local(), utc.naive_local());
+            assert_eq!(
+                local.offset().local_minus_utc(),
+                chrono::Local
+                    .from_local_datetime(&local.naive_local())
+                    .unwrap()
+                    .offset()
+                    .local_minus_utc()
+            );
+        }
+
+        // a written offset always wins
+        let iso = crate::datetime("2023-02-08T23:40:00+01:00").unwrap();
+        for policy in [
+            MissingOffsetPolicy::AssumeUtc,
+            MissingOffsetPolicy::AssumeLocal,
+            MissingOffsetPolicy::Error,
+        ] {
+            let datetime = iso.into_fixed_offset_with(policy).unwrap();
+            assert_eq!(datetime.offset().local_minus_utc(), 3600);
+        }
+    }
+
+    #[test]
+    fn datetime_from_iso_fraction() {
+        let iso = crate::datetime("2023-02-08T23:40:00.123456789+01:00").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+        assert_eq!(datetime.second(), 0);
+        assert_eq!(datetime.nanosecond(), 123_456_789);
+
+        let naive = iso.into_naive().unwrap();
+        assert_eq!(naive.nanosecond(), 123_456_789);
+
+        let iso = crate::datetime("2023-02-08T23:40,5").unwrap();
+        assert_eq!(iso.into_naive().unwrap().second(), 30);
+    }
+
     #[test]
     fn datetime_from_iso_ywd() {
-        let iso = crate::datetime("2023-W06-2T23:40:00+01:23").unwrap();
+        let iso = crate::datetime("2023-W06-3T23:40:00+01:23").unwrap();
         let datetime = chrono::DateTime::try_from(iso).unwrap();
 
         assert_eq!(datetime.year(), 2023);
@@ -169,6 +569,124 @@ mod test_datetime {
         assert_eq!(datetime.hour(), 23);
         assert_eq!(datetime.minute(), 40);
         assert_eq!(datetime.second(), 00);
-        assert_eq!(datetime.offset().fix().local_minus_utc(), 3623);
+        assert_eq!(datetime.offset().fix().local_minus_utc(), 4980);
+    }
+
+    #[test]
+    fn datetime_from_chrono() {
+        let roundtrip = |s| {
+            let datetime = chrono::DateTime::try_from(crate::datetime(s).unwrap()).unwrap();
+            crate::DateTime::from(datetime).to_string()
+        };
+        assert_eq!(
+            roundtrip("2023-02-08T23:40:00+01:23"),
+            "2023-02-08T23:40:00+01:23"
+        );
+        assert_eq!(
+            roundtrip("2023-02-08T23:40:00.5-05:00"),
+            "2023-02-08T23:40:00.5-05:00"
+        );
+        assert_eq!(
+            roundtrip("2023-02-08T23:40:00Z"),
+            "2023-02-08T23:40:00+00:00"
+        );
+        assert_eq!(
+            roundtrip("2023-02-08T23:40:00"),
+            "2023-02-08T23:40:00+00:00"
+        );
+        assert_eq!(
+            roundtrip("2023-W06-3T24:00:00Z"),
+            "2023-02-09T00:00:00+00:00"
+        );
+        assert_eq!(
+            roundtrip("2016-12-31T23:59:60Z"),
+            "2016-12-31T23:59:60+00:00"
+        );
+
+        let datetime =
+            chrono::DateTime::parse_from_rfc3339("2023-02-08T23:40:00.123+05:30").unwrap();
+        let iso = crate::DateTime::from(datetime);
+        assert_eq!(iso.offset(), crate::UtcOffset::Fixed { seconds: 19_800 });
+        assert_eq!(iso.into_fixed_offset(), Some(datetime));
+        assert_eq!(iso.to_unix(), (datetime.timestamp(), 123_000_000));
+    }
+}
+
+/// Converts a duration into a [`chrono::TimeDelta`],
+/// with a year counting as 365 days and a month as 30 days.
+///
+/// Negative durations and those with a sign for each unit are kept as they are.
+/// Fails for durations too long to be represented.
+impl TryFrom<crate::Duration> for chrono::TimeDelta {
+    type Error = crate::ConversionError;
+
+    fn try_from(iso: crate::Duration) -> Result<Self, Self::Error> {
+        let nanos = iso.total_nanoseconds();
+        // the nanoseconds are always positive, even for negative durations
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000))
+            .map_err(|_| crate::ConversionError::OutOfRange)?;
+        let nanos = nanos.rem_euclid(1_000_000_000) as u32;
+        chrono::TimeDelta::new(secs, nanos).ok_or(crate::ConversionError::OutOfRange)
+    }
+}
+
+impl crate::Duration {
+    /// create a [`chrono::TimeDelta`] if possible
+    pub fn into_time_delta(self) -> Option<chrono::TimeDelta> {
+        chrono::TimeDelta::try_from(self).ok()
+    }
+}
+
+#[cfg(test)]
+mod test_duration {
+    use core::convert::TryFrom;
+
+    #[test]
+    fn time_delta_from_duration() {
+        let iso = crate::duration("P1DT2H3M4.5S").unwrap();
+        let delta = chrono::TimeDelta::try_from(iso).unwrap();
+        assert_eq!(delta.num_milliseconds(), 93_784_500);
+
+        let delta = chrono::TimeDelta::try_from(crate::duration("P2W").unwrap()).unwrap();
+        assert_eq!(delta.num_days(), 14);
+    }
+
+    #[test]
+    fn time_delta_from_negative_duration() {
+        let delta = chrono::TimeDelta::try_from(crate::duration("-PT1.5S").unwrap()).unwrap();
+        assert_eq!(delta.num_milliseconds(), -1500);
+
+        let delta = chrono::TimeDelta::try_from(crate::duration("P1M-3D").unwrap()).unwrap();
+        assert_eq!(delta.num_days(), 27);
+
+        let delta = chrono::TimeDelta::try_from(crate::duration("-PT0.5H").unwrap()).unwrap();
+        assert_eq!(delta.num_seconds(), -1800);
+    }
+
+    #[test]
+    fn time_delta_from_huge_duration() {
+        // chrono keeps up to i64::MAX milliseconds
+        let max = i64::MAX / 1000;
+        let iso = crate::duration(&format!("PT{}S", max)).unwrap();
+        assert_eq!(chrono::TimeDelta::try_from(iso).unwrap().num_seconds(), max);
+        let iso = crate::duration(&format!("-PT{}S", max)).unwrap();
+        assert_eq!(
+            chrono::TimeDelta::try_from(iso).unwrap().num_seconds(),
+            -max
+        );
+
+        let iso = crate::duration(&format!("PT{}S", i64::MAX)).unwrap();
+        assert_eq!(
+            chrono::TimeDelta::try_from(iso),
+            Err(crate::ConversionError::OutOfRange)
+        );
+        let iso = crate::duration(&format!("-PT{}S", i64::MAX)).unwrap();
+        assert_eq!(
+            chrono::TimeDelta::try_from(iso),
+            Err(crate::ConversionError::OutOfRange)
+        );
+        let iso = crate::duration(&format!("P{}Y", i64::MAX)).unwrap();
+        assert_eq!(iso.into_time_delta(), None);
+        assert_eq!(crate::Duration::Weeks(i64::MIN).into_time_delta(), None);
     }
 }
@@ -0,0 +1,921 @@
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+
+use nom::branch::alt;
+
+use crate::{
+    calendar, error,
+    parsers::{self, IResult},
+    Component, Date, DateTime, Diagnostic, Duration, ErrorKind, Expected, Interval, Iso8601,
+    IxdtfDateTime, ParseError, Precision, ReducedDate, RepeatingInterval, Time, TruncatedDate,
+    UtcOffset, Week,
+};
+#[cfg(feature = "edtf")]
+use crate::{DateSet, EdtfDate, LongYear, QualifiedDate, SubYearGrouping, UnspecifiedDate};
+
+type Parser<T> = fn(&[u8]) -> IResult<&[u8], T>;
+
+/// Options for parsing with something other than the defaults of the top-level functions.
+///
+/// The options can be set one by one, or start from one of the named [`Profile`]s.
+///
+/// ```
+/// let config = iso8601::ParserConfig {
+///     case_insensitive: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(config.duration("p1dt2h"), iso8601::duration("P1DT2H"));
+///
+/// let config = iso8601::ParserConfig::from(iso8601::Profile::Rfc3339);
+/// assert!(config.datetime("2023-02-08T23:40Z").is_err());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct ParserConfig {
+    /// Whether designators like `T`, `Z`, `W` and `P` may also be written in lowercase,
+    /// like `2023-02-08t23:40z`, `2023-w06-2` or `p1dt2h`
+    pub case_insensitive: bool,
+    /// What to do with input following the parsed value
+    pub trailing: Trailing,
+    /// Which grammar datetimes are parsed with
+    pub datetime_syntax: DateTimeSyntax,
+    /// Whether dates that do not exist in the calendar, like `2023-02-30` or `2023-W53-1`,
+    /// are rejected, see [`Date::is_valid()`]
+    pub validate_calendar: bool,
+    /// Whether durations without any units, `P` and `PT`, are accepted as zero durations
+    /// instead of rejected, see [`Duration::is_zero()`]
+    pub empty_durations: bool,
+    /// How many digits of a fraction of a second times and datetimes keep, at most nine,
+    /// or `None` for all nine that fit into [`Time::nanosecond()`]
+    pub max_fraction_digits: Option<u8>,
+    /// What to do with the digits of a fraction of a second beyond
+    /// [`ParserConfig::max_fraction_digits`]
+    pub excess_fraction_digits: ExcessDigits,
+    /// Which UTC offsets times, datetimes and offsets may have
+    pub offset_range: OffsetRange,
+    /// Whether the date, time and offset of times and datetimes have to be written
+    /// either all in the basic format like `20230208T234000+0100`
+    /// or all in the extended format like `2023-02-08T23:40:00+01:00`,
+    /// instead of mixing them like `2023-02-08T234000`
+    pub consistent_format: bool,
+}
+
+/// The grammar datetimes are parsed with, see [`ParserConfig::datetime_syntax`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum DateTimeSyntax {
+    /// ISO 8601 like [`datetime()`][`crate::datetime()`]
+    #[default]
+    Iso8601,
+    /// ISO 8601 with the variations of [`datetime_lenient()`][`crate::datetime_lenient()`]
+    Lenient,
+    /// The RFC 3339 profile like [`datetime_rfc3339()`][`crate::datetime_rfc3339()`]
+    Rfc3339,
+    /// The HTML `datetime` attribute like [`datetime_html()`][`crate::datetime_html()`]
+    Html,
+    /// Python's `datetime.isoformat()` like [`datetime_python()`][`crate::datetime_python()`]
+    Python,
+}
+
+/// What to do with the digits of a fraction of a second beyond those kept,
+/// see [`ParserConfig::excess_fraction_digits`].
+///
+/// ```
+/// use iso8601::{ExcessDigits, ParserConfig};
+///
+/// let config = ParserConfig {
+///     max_fraction_digits: Some(3),
+///     excess_fraction_digits: ExcessDigits::Round,
+///     ..Default::default()
+/// };
+/// let time = config.time("23:59:59.999999999999999Z").unwrap();
+/// assert_eq!(time, iso8601::time("24:00:00.000Z").unwrap());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum ExcessDigits {
+    /// Drop them, rounding towards zero, like the top-level functions do after nine digits.
+    #[default]
+    Truncate,
+    /// Round to the nearest value with halves rounded up, carrying into the seconds,
+    /// minutes and hours, so `23:59:59.9999` becomes `24:00:00.000` with three digits.
+    Round,
+    /// Refuse the input with [`ErrorKind::ExcessFractionDigits`], as well as the digits
+    /// of a duration's seconds beyond milliseconds that are not zero, like in `PT0.0001S`.
+    Reject,
+}
+
+/// The UTC offsets that are accepted, see [`ParserConfig::offset_range`].
+///
+/// Offsets of a day or more, like `+24:00` or `+99:00`, are never accepted.
+///
+/// ```
+/// use iso8601::{ErrorKind, OffsetRange, ParserConfig, Profile};
+///
+/// let config = ParserConfig {
+///     offset_range: OffsetRange::EighteenHours,
+///     ..Profile::Rfc3339.into()
+/// };
+/// assert!(config.datetime("2023-02-08T23:40:00-18:00").is_ok());
+/// let error = config.datetime("2023-02-08T23:40:00-18:01").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::OffsetOutOfRange);
+/// assert_eq!(error.offset, 20);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum OffsetRange {
+    /// Anything less than a day, from `-23:59:59` to `+23:59:59`, like the top-level functions
+    #[default]
+    Day,
+    /// From `-18:00` to `+18:00`, like Java's `ZoneOffset` and many databases
+    EighteenHours,
+}
+
+/// Named sets of options for common sources of dates and times.
+///
+/// All of them but [`Profile::Lenient`] reject dates that do not exist in the calendar
+/// and durations without any units.
+///
+/// All of them turn into a [`ParserConfig`], which can be adjusted further:
+///
+/// ```
+/// use iso8601::{ParserConfig, Profile, Trailing};
+///
+/// let config = ParserConfig {
+///     trailing: Trailing::Ignore,
+///     ..Profile::Html.into()
+/// };
+/// assert!(config.datetime("2023-02-08T23:40 local").is_ok());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Profile {
+    /// ISO 8601 as written, with uppercase designators and nothing following the value
+    Strict8601,
+    /// RFC 3339 datetimes like `2023-02-08T23:40:00Z`, with nothing following them
+    Rfc3339,
+    /// HTML `datetime` attribute values like `2023-02-08T23:40`, with nothing following them
+    Html,
+    /// SQL timestamps like `2023-02-08 23:40:00+01`, with nothing following them
+    Sql,
+    /// Python `datetime.isoformat()` output like `2023-02-08T23:40:00.000123+01:00`,
+    /// with nothing following it
+    Python,
+    /// Anything close enough, in any case and ignoring whatever follows the value
+    Lenient,
+}
+
+impl From<Profile> for ParserConfig {
+    fn from(profile: Profile) -> Self {
+        let (case_insensitive, trailing, datetime_syntax, validate_calendar, empty_durations) =
+            match profile {
+                Profile::Strict8601 => (
+                    false,
+                    Trailing::Reject,
+                    DateTimeSyntax::Iso8601,
+                    true,
+                    false,
+                ),
+                Profile::Rfc3339 => (
+                    false,
+                    Trailing::Reject,
+                    DateTimeSyntax::Rfc3339,
+                    true,
+                    false,
+                ),
+                Profile::Html => (false, Trailing::Reject, DateTimeSyntax::Html, true, false),
+                Profile::Sql => (
+                    false,
+                    Trailing::Reject,
+                    DateTimeSyntax::Lenient,
+                    true,
+                    false,
+                ),
+                Profile::Python => (false, Trailing::Reject, DateTimeSyntax::Python, true, false),
+                Profile::Lenient => (true, Trailing::Ignore, DateTimeSyntax::Lenient, false, true),
+            };
+
+        ParserConfig {
+            case_insensitive,
+            trailing,
+            datetime_syntax,
+            validate_calendar,
+            empty_durations,
+            ..Default::default()
+        }
+    }
+}
+
+/// How closely input has to follow ISO 8601, from pedantic to lenient,
+/// for all parsing methods of a [`ParserConfig`] and every `parse_with()` at once.
+///
+/// Each level turns into a [`ParserConfig`] that sets every option accordingly:
+///
+/// | | `Pedantic` | `Standard` | `Lenient` |
+/// |---|---|---|---|
+/// | lowercase designators like `t` and `z` | no | no | yes |
+/// | a space between date and time | no | no | yes |
+/// | mixing the basic and extended format | no | yes | yes |
+/// | input following the value | no | ignored | ignored |
+/// | dates that do not exist like `2023-02-30` | no | yes | yes |
+/// | durations without units like `PT` | no | no | yes |
+/// | fractions of a second beyond nine digits | no | truncated | truncated |
+///
+/// ```
+/// use iso8601::{ParserConfig, Strictness};
+///
+/// let pedantic = ParserConfig::from(Strictness::Pedantic);
+/// assert!(pedantic.datetime("2023-02-08T23:40:00+01:00").is_ok());
+/// assert!(pedantic.datetime("2023-02-08T234000+0100").is_err());
+/// assert!(pedantic.datetime("2023-02-30T23:40:00Z").is_err());
+///
+/// let lenient = ParserConfig::from(Strictness::Lenient);
+/// assert!(lenient.datetime("2023-02-30 23:40:00z").is_ok());
+/// ```
+///
+/// Every parsed type takes a level directly in its `parse_with()`:
+///
+/// ```
+/// use iso8601::{Date, Duration, Strictness};
+///
+/// assert!(Date::parse_with("2023-02-30", Strictness::Standard).is_ok());
+/// assert!(Date::parse_with("2023-02-30", Strictness::Pedantic).is_err());
+/// assert!(Duration::parse_with("p1d", Strictness::Lenient).is_ok());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Strictness {
+    /// Exactly as ISO 8601 writes it, rejecting anything questionable
+    Pedantic,
+    /// Like the top-level functions, the same as [`ParserConfig::default()`]
+    #[default]
+    Standard,
+    /// Anything close enough, the same as [`Profile::Lenient`]
+    Lenient,
+}
+
+impl From<Strictness> for ParserConfig {
+    fn from(strictness: Strictness) -> Self {
+        match strictness {
+            Strictness::Pedantic => ParserConfig {
+                excess_fraction_digits: ExcessDigits::Reject,
+                consistent_format: true,
+                ..Profile::Strict8601.into()
+            },
+            Strictness::Standard => ParserConfig::default(),
+            Strictness::Lenient => Profile::Lenient.into(),
+        }
+    }
+}
+
+/// What to do with input following a parsed value, see [`ParserConfig::trailing`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Trailing {
+    /// Stop at the first byte that does not belong to the value and ignore the rest,
+    /// like the top-level functions do.
+    #[default]
+    Ignore,
+    /// Refuse any input following the value, so the whole string has to match.
+    Reject,
+}
+
+impl ParserConfig {
+    fn duration_parser(&self) -> Parser<Duration> {
+        if self.empty_durations {
+            |i| alt((parsers::parse_duration, parsers::empty_duration))(i)
+        } else {
+            parsers::parse_duration
+        }
+    }
+
+    // Applies `max_fraction_digits` to the time in the values of `parser`, picked by `time`
+    fn limit_fraction<T>(
+        &self,
+        parser: Parser<T>,
+        time: fn(&mut T) -> &mut Time,
+    ) -> impl Fn(&[u8]) -> IResult<&[u8], T> {
+        let max = usize::from(self.max_fraction_digits.map_or(9, |max| max.min(9)));
+        let excess = self.excess_fraction_digits;
+
+        move |i| {
+            let (rest, (consumed, mut parsed)) = nom::combinator::consumed(parser)(i)?;
+            let t = time(&mut parsed);
+            // the fraction of a second follows the first separator of a time with one
+            let start = match consumed.iter().position(|&b| b == b'.' || b == b',') {
+                Some(separator) if t.precision == Precision::Fraction => separator + 1,
+                _ => return Ok((rest, parsed)),
+            };
+            let digits = &consumed[start..];
+            if digits.iter().take_while(|b| b.is_ascii_digit()).count() > max {
+                match excess {
+                    ExcessDigits::Truncate => t.shorten_fraction(digits, max, false),
+                    ExcessDigits::Round => t.shorten_fraction(digits, max, true),
+                    ExcessDigits::Reject => {
+                        return Err(nom::Err::Error(parsers::Error {
+                            input: &i[start + max..],
+                            kind: Some(ErrorKind::ExcessFractionDigits),
+                        }))
+                    }
+                }
+            }
+            Ok((rest, parsed))
+        }
+    }
+
+    // Applies `ExcessDigits::Reject` to the seconds of durations, which are kept to the millisecond
+    fn limit_duration_fraction(
+        &self,
+        parser: Parser<Duration>,
+    ) -> impl Fn(&[u8]) -> IResult<&[u8], Duration> {
+        let reject = self.excess_fraction_digits == ExcessDigits::Reject;
+
+        move |i| {
+            let (rest, (consumed, parsed)) = nom::combinator::consumed(parser)(i)?;
+            // any other unit keeps its whole fraction
+            let start = match consumed.iter().position(|&b| b == b'.' || b == b',') {
+                Some(separator)
+                    if reject && matches!(parsed, Duration::YMDHMS { fraction: None, .. }) =>
+                {
+                    separator + 1
+                }
+                _ => return Ok((rest, parsed)),
+            };
+            let digits = consumed[start..].iter().take_while(|b| b.is_ascii_digit());
+            if digits.skip(3).any(|&digit| digit != b'0') {
+                return Err(nom::Err::Error(parsers::Error {
+                    input: &i[start + 3..],
+                    kind: Some(ErrorKind::ExcessFractionDigits),
+                }));
+            }
+            Ok((rest, parsed))
+        }
+    }
+
+    // Applies `offset_range` to the offset of the values of `parser`, picked by `offset`
+    fn limit_offset<T>(
+        &self,
+        parser: impl Fn(&[u8]) -> IResult<&[u8], T>,
+        offset: fn(&T) -> UtcOffset,
+    ) -> impl Fn(&[u8]) -> IResult<&[u8], T> {
+        let max = match self.offset_range {
+            OffsetRange::Day => 86_399,
+            OffsetRange::EighteenHours => 18 * 3600,
+        };
+
+        move |i| {
+            let (rest, (consumed, parsed)) = nom::combinator::consumed(&parser)(i)?;
+            if offset(&parsed).offset_seconds().unsigned_abs() <= max {
+                return Ok((rest, parsed));
+            }
+            // the hours of an offset follow its sign, the last one of the value,
+            // which is either `+`, `-` or the last byte of the minus sign `−`
+            let hours = consumed
+                .iter()
+                .rposition(|&b| b == b'+' || b == b'-' || b == 0x92)
+                .map_or(0, |sign| sign + 1);
+            Err(nom::Err::Error(parsers::Error {
+                input: &i[hours..],
+                kind: Some(ErrorKind::OffsetOutOfRange),
+            }))
+        }
+    }
+
+    // Applies `consistent_format` to the values of `parser`,
+    // which are times if `dated` is false and datetimes otherwise
+    fn check_format<T>(
+        &self,
+        parser: impl Fn(&[u8]) -> IResult<&[u8], T>,
+        dated: bool,
+    ) -> impl Fn(&[u8]) -> IResult<&[u8], T> {
+        let consistent = self.consistent_format;
+
+        move |i| {
+            let (rest, (consumed, parsed)) = nom::combinator::consumed(&parser)(i)?;
+            if !consistent {
+                return Ok((rest, parsed));
+            }
+            match mixed_format(consumed, dated) {
+                None => Ok((rest, parsed)),
+                Some(at) => Err(nom::Err::Error(parsers::Error {
+                    input: &i[at..],
+                    kind: Some(ErrorKind::MixedFormat),
+                })),
+            }
+        }
+    }
+
+    fn parse<T>(
+        &self,
+        string: &str,
+        component: Component,
+        parser: impl Fn(&[u8]) -> IResult<&[u8], T>,
+        valid: impl Fn(&T) -> bool,
+    ) -> Result<T, ParseError> {
+        // only copy the input if there is anything to uppercase, to parse without allocating
+        let prepared = if self.case_insensitive && string.bytes().any(|b| b.is_ascii_lowercase()) {
+            Cow::Owned(string.to_ascii_uppercase())
+        } else {
+            Cow::Borrowed(string)
+        };
+        let input = prepared.as_bytes();
+
+        // uppercasing keeps the length, so the offsets are those of `string` as well
+        let with_input = |e: ParseError| ParseError {
+            input: String::from(string),
+            ..e
+        };
+        let (parsed, rest) = error::prefix(component, input, parser(input)).map_err(with_input)?;
+
+        if !rest.is_empty() && self.trailing == Trailing::Reject {
+            return Err(with_input(ParseError::new(
+                component,
+                input,
+                rest,
+                Expected::End,
+            )));
+        }
+        if self.validate_calendar && !valid(&parsed) {
+            return Err(with_input(ParseError::new(
+                component,
+                input,
+                input,
+                Expected::ExistingDate,
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// Parses a date string like [`date()`][`crate::date()`].
+    pub fn date(&self, string: &str) -> Result<Date, ParseError> {
+        self.parse(string, Component::Date, parsers::parse_date, Date::is_valid)
+    }
+
+    /// Parses a time string like [`time()`][`crate::time()`].
+    pub fn time(&self, string: &str) -> Result<Time, ParseError> {
+        let parser = self.limit_fraction(parsers::parse_time, |time| time);
+        let parser = self.limit_offset(parser, |time| time.offset);
+        let parser = self.check_format(parser, false);
+        self.parse(string, Component::Time, parser, |_| true)
+    }
+
+    fn datetime_parser(&self) -> (Component, Parser<DateTime>) {
+        match self.datetime_syntax {
+            DateTimeSyntax::Iso8601 => (Component::DateTime, parsers::parse_datetime),
+            DateTimeSyntax::Lenient => (Component::DateTime, parsers::parse_datetime_lenient),
+            DateTimeSyntax::Rfc3339 => {
+                (Component::Rfc3339DateTime, parsers::parse_datetime_rfc3339)
+            }
+            DateTimeSyntax::Html => (Component::HtmlDateTime, parsers::parse_datetime_html),
+            DateTimeSyntax::Python => (Component::PythonDateTime, parsers::parse_datetime_python),
+        }
+    }
+
+    /// Parses a datetime string with the grammar chosen by [`ParserConfig::datetime_syntax`].
+    pub fn datetime(&self, string: &str) -> Result<DateTime, ParseError> {
+        let (component, parser) = self.datetime_parser();
+        let parser = self.limit_fraction(parser, |dt| &mut dt.time);
+        let parser = self.limit_offset(parser, |dt| dt.time.offset);
+        let parser = self.check_format(parser, true);
+        self.parse(string, component, parser, DateTime::is_valid)
+    }
+
+    /// Parses a UTC offset string like [`offset()`][`crate::offset()`].
+    pub fn offset(&self, string: &str) -> Result<UtcOffset, ParseError> {
+        let parser = self.limit_offset(parsers::parse_offset, |&offset| offset);
+        self.parse(string, Component::Offset, parser, |_| true)
+    }
+
+    /// Parses a duration string like [`duration()`][`crate::duration()`].
+    ///
+    /// With [`ExcessDigits::Reject`], seconds with non-zero digits beyond milliseconds,
+    /// like `PT0.0001S`, are rejected rather than truncated.
+    pub fn duration(&self, string: &str) -> Result<Duration, ParseError> {
+        let parser = self.limit_duration_fraction(self.duration_parser());
+        self.parse(string, Component::Duration, parser, |_| true)
+    }
+
+    /// Parses an interval string like [`interval()`][`crate::interval()`].
+    pub fn interval(&self, string: &str) -> Result<Interval, ParseError> {
+        self.parse(
+            string,
+            Component::Interval,
+            parsers::parse_interval,
+            Interval::is_valid,
+        )
+    }
+
+    /// Parses a repeating interval string like [`repeating_interval()`][`crate::repeating_interval()`].
+    pub fn repeating_interval(&self, string: &str) -> Result<RepeatingInterval, ParseError> {
+        self.parse(
+            string,
+            Component::RepeatingInterval,
+            parsers::parse_repeating_interval,
+            |repeating| repeating.interval.is_valid(),
+        )
+    }
+
+    /// Parses a string that may be any of a datetime, a date, a time, a duration or an interval
+    /// like [`parse_any()`][`crate::parse_any()`], with the method of this config for each.
+    ///
+    /// Like for [`parse_any()`][`crate::parse_any()`], the whole string has to match
+    /// whatever [`ParserConfig::trailing`] is set to.
+    pub fn parse_any(&self, string: &str) -> Result<Iso8601, ParseError> {
+        let config = ParserConfig {
+            trailing: Trailing::Reject,
+            ..*self
+        };
+        let mut telling = match config.datetime(string) {
+            Ok(dt) => return Ok(Iso8601::DateTime(dt)),
+            Err(e) => e,
+        };
+        let others: [&dyn Fn() -> Result<Iso8601, ParseError>; 4] = [
+            &|| config.date(string).map(Iso8601::Date),
+            &|| config.time(string).map(Iso8601::Time),
+            &|| config.duration(string).map(Iso8601::Duration),
+            &|| config.interval(string).map(Iso8601::Interval),
+        ];
+        // of the failed representations, one that only failed the calendar is the most telling,
+        // and otherwise the one that got furthest
+        let rank = |e: &ParseError| (e.expected == Expected::ExistingDate, e.offset);
+        for parse in others {
+            match parse() {
+                Ok(parsed) => return Ok(parsed),
+                Err(e) if rank(&e) > rank(&telling) => telling = e,
+                Err(_) => {}
+            }
+        }
+        Err(ParseError {
+            component: Component::Any,
+            ..telling
+        })
+    }
+
+    /// Parses a date string with an expanded year like [`date_expanded()`][`crate::date_expanded()`].
+    pub fn date_expanded(&self, string: &str, extra_digits: usize) -> Result<Date, ParseError> {
+        self.parse(
+            string,
+            Component::ExpandedDate,
+            parsers::parse_date_expanded(extra_digits),
+            Date::is_valid,
+        )
+    }
+
+    /// Parses a reduced precision date string like [`reduced_date()`][`crate::reduced_date()`].
+    pub fn reduced_date(&self, string: &str) -> Result<ReducedDate, ParseError> {
+        self.parse(
+            string,
+            Component::ReducedDate,
+            parsers::parse_reduced_date,
+            |_| true,
+        )
+    }
+
+    /// Parses a week string like [`week()`][`crate::week()`].
+    ///
+    /// With [`ParserConfig::validate_calendar`], weeks after the last one of their year,
+    /// like `2023-W53`, are rejected.
+    pub fn week(&self, string: &str) -> Result<Week, ParseError> {
+        self.parse(string, Component::Week, parsers::parse_week, |week| {
+            week.ww <= calendar::weeks_in_iso_year(week.year)
+        })
+    }
+
+    /// Parses a truncated date string without a year like [`truncated_date()`][`crate::truncated_date()`].
+    ///
+    /// With [`ParserConfig::validate_calendar`], days that their month never has,
+    /// like `--02-30`, are rejected, while `--02-29` is accepted.
+    pub fn truncated_date(&self, string: &str) -> Result<TruncatedDate, ParseError> {
+        self.parse(
+            string,
+            Component::TruncatedDate,
+            parsers::parse_truncated_date,
+            |date| match *date {
+                // the year 0 is a leap year, in which each month has all of its days
+                TruncatedDate::MonthDay { month, day } => day <= calendar::days_in_month(0, month),
+                TruncatedDate::Month { .. } | TruncatedDate::Day { .. } => true,
+            },
+        )
+    }
+
+    /// Parses an IXDTF datetime string like [`datetime_ixdtf()`][`crate::datetime_ixdtf()`].
+    ///
+    /// [`ParserConfig::datetime_syntax`] and [`ParserConfig::consistent_format`] do not apply,
+    /// as the datetime is always one of RFC 3339,
+    /// and neither does [`ParserConfig::case_insensitive`], as time zone names and keys
+    /// keep their case.
+    pub fn datetime_ixdtf(&self, string: &str) -> Result<IxdtfDateTime, ParseError> {
+        self.ixdtf(
+            string,
+            Component::IxdtfDateTime,
+            parsers::parse_datetime_ixdtf,
+        )
+    }
+
+    /// Parses a Temporal datetime string like [`datetime_temporal()`][`crate::datetime_temporal()`].
+    ///
+    /// The options apply like for [`ParserConfig::datetime_ixdtf()`].
+    #[cfg(feature = "temporal")]
+    pub fn datetime_temporal(&self, string: &str) -> Result<IxdtfDateTime, ParseError> {
+        self.ixdtf(
+            string,
+            Component::TemporalDateTime,
+            parsers::parse_datetime_temporal,
+        )
+    }
+
+    fn ixdtf(
+        &self,
+        string: &str,
+        component: Component,
+        parser: Parser<IxdtfDateTime>,
+    ) -> Result<IxdtfDateTime, ParseError> {
+        let config = ParserConfig {
+            case_insensitive: false,
+            ..*self
+        };
+        let parser = config.limit_fraction(parser, |dt| &mut dt.datetime.time);
+        let parser = config.limit_offset(parser, |dt| dt.datetime.time.offset);
+        config.parse(string, component, parser, |dt| dt.datetime.is_valid())
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl ParserConfig {
+    /// Parses a qualified date string like [`qualified_date()`][`crate::qualified_date()`].
+    pub fn qualified_date(&self, string: &str) -> Result<QualifiedDate, ParseError> {
+        self.parse(
+            string,
+            Component::QualifiedDate,
+            parsers::parse_qualified_date,
+            |qualified| match qualified.date {
+                EdtfDate::Date(date) => date.is_valid(),
+                EdtfDate::Reduced(_) | EdtfDate::Grouping(_) => true,
+            },
+        )
+    }
+
+    /// Parses a sub-year grouping string like [`sub_year_grouping()`][`crate::sub_year_grouping()`].
+    pub fn sub_year_grouping(&self, string: &str) -> Result<SubYearGrouping, ParseError> {
+        self.parse(
+            string,
+            Component::SubYearGrouping,
+            parsers::parse_sub_year_grouping,
+            |_| true,
+        )
+    }
+
+    /// Parses a date string with unspecified digits like
+    /// [`unspecified_date()`][`crate::unspecified_date()`].
+    pub fn unspecified_date(&self, string: &str) -> Result<UnspecifiedDate, ParseError> {
+        self.parse(
+            string,
+            Component::UnspecifiedDate,
+            parsers::parse_unspecified_date,
+            |_| true,
+        )
+    }
+
+    /// Parses a set of dates string like [`date_set()`][`crate::date_set()`].
+    pub fn date_set(&self, string: &str) -> Result<DateSet, ParseError> {
+        self.parse(string, Component::DateSet, parsers::parse_date_set, |_| {
+            true
+        })
+    }
+
+    /// Parses a long year string like [`long_year()`][`crate::long_year()`].
+    pub fn long_year(&self, string: &str) -> Result<LongYear, ParseError> {
+        self.parse(
+            string,
+            Component::LongYear,
+            parsers::parse_long_year,
+            |_| true,
+        )
+    }
+}
+
+// `T::parse_with()` for each parsed type, taking a `Strictness`, a `Profile` or a whole config
+macro_rules! parse_with {
+    ($($(#[$attr:meta])* $type:ty => $method:ident,)*) => {$(
+        $(#[$attr])*
+        impl $type {
+            #[doc = concat!(
+                "Parses a string with [`ParserConfig::", stringify!($method), "()`], ",
+                "from a [`Strictness`], a [`Profile`] or a whole [`ParserConfig`]."
+            )]
+            pub fn parse_with(
+                string: &str,
+                config: impl Into<ParserConfig>,
+            ) -> Result<Self, ParseError> {
+                config.into().$method(string)
+            }
+        }
+    )*};
+}
+
+parse_with! {
+    Date => date,
+    Time => time,
+    DateTime => datetime,
+    UtcOffset => offset,
+    Duration => duration,
+    Interval => interval,
+    RepeatingInterval => repeating_interval,
+    Iso8601 => parse_any,
+    ReducedDate => reduced_date,
+    Week => week,
+    TruncatedDate => truncated_date,
+    IxdtfDateTime => datetime_ixdtf,
+    #[cfg(feature = "edtf")]
+    QualifiedDate => qualified_date,
+    #[cfg(feature = "edtf")]
+    SubYearGrouping => sub_year_grouping,
+    #[cfg(feature = "edtf")]
+    UnspecifiedDate => unspecified_date,
+    #[cfg(feature = "edtf")]
+    DateSet => date_set,
+    #[cfg(feature = "edtf")]
+    LongYear => long_year,
+}
+
+// The offset of the first part of a time or datetime written in another format than the ones
+// before it, the date, the time and the offset, or `None` if they are all written in the same one
+fn mixed_format(consumed: &[u8], dated: bool) -> Option<usize> {
+    // a datetime's time follows the first separator, a time's its optional `T`
+    let time_start = if dated {
+        consumed
+            .iter()
+            .position(|&b| b == b'T' || b == b't' || b == b' ')?
+            + 1
+    } else {
+        usize::from(consumed.first() == Some(&b'T'))
+    };
+    let time = &consumed[time_start..];
+    // the offset follows its sign, either `+`, `-` or the minus sign `−` ending in `0x92`
+    let (clock_end, offset_start) = match time
+        .iter()
+        .position(|&b| b == b'+' || b == b'-' || b == 0x92)
+    {
+        Some(sign) if time[sign] == 0x92 => (sign.saturating_sub(2), sign + 1),
+        Some(sign) => (sign, sign + 1),
+        None => (time.len(), time.len()),
+    };
+
+    // an hour alone, or an offset of hours alone, is written the same in both formats
+    let extended = |part: &[u8]| {
+        let part = part
+            .iter()
+            .position(|&b| b == b'.' || b == b',' || b == b'Z' || b == b'z')
+            .map_or(part, |end| &part[..end]);
+        (part.len() > 2).then(|| part.contains(&b':'))
+    };
+    // the date is extended if it has a `-` other than the sign of its year
+    let date = consumed.get(1..time_start).unwrap_or_default();
+    let parts = [
+        (0, dated.then(|| date.contains(&b'-'))),
+        (time_start, extended(&time[..clock_end])),
+        (time_start + offset_start, extended(&time[offset_start..])),
+    ];
+
+    let mut parts = parts
+        .into_iter()
+        .filter_map(|(start, extended)| Some((start, extended?)));
+    let (_, format) = parts.next()?;
+    parts
+        .find(|&(_, extended)| extended != format)
+        .map(|(start, _)| start)
+}
+
+// How a number rejected with `kind` is replaced to carry on parsing after it,
+// or `None` if parsing cannot carry on
+fn placeholder(kind: ErrorKind, digits: usize) -> Option<Vec<u8>> {
+    match kind {
+        ErrorKind::InvalidMonth | ErrorKind::InvalidDay | ErrorKind::InvalidWeek => {
+            Some(b"01".to_vec())
+        }
+        ErrorKind::InvalidWeekday => Some(b"1".to_vec()),
+        ErrorKind::InvalidOrdinal => Some(b"001".to_vec()),
+        ErrorKind::InvalidHour
+        | ErrorKind::InvalidMinute
+        | ErrorKind::InvalidSecond
+        | ErrorKind::OffsetOutOfRange => Some(b"00".to_vec()),
+        ErrorKind::InvalidGrouping => Some(b"21".to_vec()),
+        ErrorKind::NumberTooLarge => Some(vec![b'0'; digits]),
+        ErrorKind::Syntax
+        | ErrorKind::InvalidFraction
+        | ErrorKind::ExcessFractionDigits
+        | ErrorKind::MixedFormat
+        | ErrorKind::TrailingInput
+        | ErrorKind::NonexistentDate => None,
+    }
+}
+
+/// Finding all problems in a string instead of stopping at the first.
+///
+/// A number out of range is reported and replaced with a valid one to parse the rest,
+/// until the string parses or does not follow the format any more.
+/// No problems means the corresponding parsing method succeeds.
+impl ParserConfig {
+    fn diagnose<T>(
+        &self,
+        string: &str,
+        parser: impl Fn(&[u8]) -> IResult<&[u8], T>,
+        valid: impl Fn(&T) -> bool,
+    ) -> Vec<Diagnostic> {
+        let mut input = string.as_bytes().to_vec();
+        if self.case_insensitive {
+            input.make_ascii_uppercase();
+        }
+        let mut diagnostics = Vec::new();
+
+        loop {
+            let error = match parser(&input) {
+                Ok((rest, parsed)) => {
+                    let end = input.len() - rest.len();
+                    if !rest.is_empty() && self.trailing == Trailing::Reject {
+                        diagnostics.push(Diagnostic {
+                            kind: ErrorKind::TrailingInput,
+                            span: end..input.len(),
+                        });
+                    }
+                    if self.validate_calendar && !valid(&parsed) {
+                        diagnostics.push(Diagnostic {
+                            kind: ErrorKind::NonexistentDate,
+                            span: 0..end,
+                        });
+                    }
+                    return diagnostics;
+                }
+                Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+                Err(nom::Err::Incomplete(_)) => parsers::Error {
+                    input: &[][..],
+                    kind: None,
+                },
+            };
+            let start = input.len() - error.input.len();
+            let digits = error
+                .input
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            let kind = error.kind.unwrap_or(ErrorKind::Syntax);
+
+            // a number that was replaced before does not get any further
+            let repaired = diagnostics
+                .iter()
+                .any(|d: &Diagnostic| d.span.start == start);
+            match placeholder(kind, digits).filter(|_| !repaired) {
+                Some(number) => {
+                    let span = start..start + number.len();
+                    input[span.clone()].copy_from_slice(&number);
+                    diagnostics.push(Diagnostic { kind, span });
+                }
+                None => {
+                    diagnostics.push(Diagnostic {
+                        kind,
+                        span: start..input.len(),
+                    });
+                    return diagnostics;
+                }
+            }
+        }
+    }
+
+    /// Finds all problems in a date string, see [`ParserConfig::date()`].
+    pub fn diagnose_date(&self, string: &str) -> Vec<Diagnostic> {
+        self.diagnose(string, parsers::parse_date, Date::is_valid)
+    }
+
+    /// Finds all problems in a time string, see [`ParserConfig::time()`].
+    pub fn diagnose_time(&self, string: &str) -> Vec<Diagnostic> {
+        let parser = self.limit_fraction(parsers::parse_time, |time| time);
+        let parser = self.limit_offset(parser, |time| time.offset);
+        let parser = self.check_format(parser, false);
+        self.diagnose(string, parser, |_| true)
+    }
+
+    /// Finds all problems in a datetime string, see [`ParserConfig::datetime()`].
+    pub fn diagnose_datetime(&self, string: &str) -> Vec<Diagnostic> {
+        let (_, parser) = self.datetime_parser();
+        let parser = self.limit_fraction(parser, |dt| &mut dt.time);
+        let parser = self.limit_offset(parser, |dt| dt.time.offset);
+        let parser = self.check_format(parser, true);
+        self.diagnose(string, parser, DateTime::is_valid)
+    }
+
+    /// Finds all problems in a UTC offset string, see [`ParserConfig::offset()`].
+    pub fn diagnose_offset(&self, string: &str) -> Vec<Diagnostic> {
+        let parser = self.limit_offset(parsers::parse_offset, |&offset| offset);
+        self.diagnose(string, parser, |_| true)
+    }
+
+    /// Finds all problems in a duration string, see [`ParserConfig::duration()`].
+    pub fn diagnose_duration(&self, string: &str) -> Vec<Diagnostic> {
+        self.diagnose(string, self.duration_parser(), |_| true)
+    }
+
+    /// Finds all problems in an interval string, see [`ParserConfig::interval()`].
+    pub fn diagnose_interval(&self, string: &str) -> Vec<Diagnostic> {
+        self.diagnose(string, parsers::parse_interval, Interval::is_valid)
+    }
+
+    /// Finds all problems in a repeating interval string,
+    /// see [`ParserConfig::repeating_interval()`].
+    pub fn diagnose_repeating_interval(&self, string: &str) -> Vec<Diagnostic> {
+        self.diagnose(string, parsers::parse_repeating_interval, |repeating| {
+            repeating.interval.is_valid()
+        })
+    }
+}
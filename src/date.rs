@@ -1,9 +1,28 @@
-use alloc::string::String;
-use core::str::FromStr;
+use core::{cmp::Ordering, iter::FusedIterator, ops::Deref, str::FromStr};
 
-use crate::parsers;
+use alloc::vec::Vec;
+
+use crate::{
+    calendar, error, parsers, Component, Duration, ErrorKind, Expected, ParseError, Precision, Unit,
+};
 
 /// A date, can hold three different formats.
+///
+/// The variants can be built by hand, but [`Date::from_ymd()`], [`Date::from_iso_week()`]
+/// and [`Date::from_ordinal()`] make sure the date exists.
+///
+/// Dates are in the proleptic Gregorian calendar with astronomical year numbering,
+/// like ISO 8601 and chrono: year `0` is 1 BC, which is a leap year, and year `-333` is 334 BC.
+/// They are written with at least four digits after the sign, like `-0333-07-11`.
+///
+/// Dates range from [`Date::MIN`] to [`Date::MAX`], the first and last day of the years an `i32`
+/// holds. Week dates are built from their year too, but the first of them fall
+/// before that range, so [`Date::from_iso_week()`] rejects those.
+///
+/// Unlike the fields of [`Time`](crate::Time), the variants of a date are public even without
+/// the `legacy-fields` feature, so a date that does not exist, like `2023-13-40`, can still be
+/// built by hand. [`Date::from_ymd()`] and the other constructors check them,
+/// and [`Date::validate()`] turns any date into a [`ValidDate`] that is known to exist.
 /// ```
 /// # use std::str::FromStr;
 /// assert_eq!(
@@ -12,7 +31,7 @@ use crate::parsers;
 /// )
 /// ```
 #[allow(missing_docs)]
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
 pub enum Date {
     /// consists of year, month and day of month
     YMD { year: i32, month: u32, day: u32 },
@@ -22,6 +41,1059 @@ pub enum Date {
     Ordinal { year: i32, ddd: u32 },
 }
 
+/// Why a [`Date`] could not be built by [`Date::from_ymd()`],
+/// [`Date::from_iso_week()`] or [`Date::from_ordinal()`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum DateError {
+    /// The month is not within `1..=12`
+    Month,
+    /// The day is not within the days of its month
+    Day,
+    /// The week is not within the weeks of its ISO week-numbering year
+    Week,
+    /// The day of the week is not within `1..=7`
+    Weekday,
+    /// The day of the year is not within the days of its year
+    Ordinal,
+    /// The date is before [`Date::MIN`] or after [`Date::MAX`]
+    OutOfRange,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateError {}
+
+/// A day of the week, ordered from Monday to Sunday like in ISO 8601.
+/// ```
+/// let date = iso8601::date("2023-02-08").unwrap();
+/// assert_eq!(date.weekday(), iso8601::Weekday::Wednesday);
+/// assert_eq!(date.weekday().to_string(), "Wednesday");
+/// assert_eq!(date.weekday().number_from_monday(), 3);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    /// the first day of an ISO week, `1` in week dates
+    Monday,
+    /// `2` in week dates
+    Tuesday,
+    /// `3` in week dates
+    Wednesday,
+    /// `4` in week dates
+    Thursday,
+    /// `5` in week dates
+    Friday,
+    /// `6` in week dates
+    Saturday,
+    /// the last day of an ISO week, `7` in week dates
+    Sunday,
+}
+
+impl Weekday {
+    /// The number of this day in week dates, from `1` for Monday to `7` for Sunday.
+    pub fn number_from_monday(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// The day numbered like in week dates, from `1` for Monday to `7` for Sunday,
+    /// or `None` for any other number.
+    pub fn from_number_from_monday(number: u32) -> Option<Weekday> {
+        Some(match number {
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            6 => Weekday::Saturday,
+            7 => Weekday::Sunday,
+            _ => return None,
+        })
+    }
+}
+
+/// What [`Date::add_months()`] and [`Date::add_years()`] do with a day
+/// that its new month does not have, like the 31st in `2023-01-31` plus one month.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum OverflowPolicy {
+    /// Take the last day of the month instead, so `2023-01-31` plus one month is `2023-02-28`.
+    #[default]
+    Constrain,
+    /// Count the excess days into the following month,
+    /// so `2023-01-31` plus one month is `2023-03-03`.
+    Overflow,
+    /// Fail with [`DateError::Day`].
+    Reject,
+}
+
+/// The day that [`Date::to_days_since()`] and [`Date::from_days()`] count days from.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Epoch {
+    /// `1970-01-01`, which is day 0, like the `DATE` of Arrow and Parquet.
+    Unix,
+    /// Rata Die, which makes `0001-01-01` day 1 and `0000-12-31` day 0.
+    RataDie,
+}
+
+impl Epoch {
+    // The number of this epoch's day 1970-01-01
+    fn unix_day(self) -> i64 {
+        match self {
+            Epoch::Unix => 0,
+            Epoch::RataDie => calendar::RATA_DIE_OF_EPOCH,
+        }
+    }
+}
+
+impl Date {
+    /// The earliest date, `-2147483648-01-01`.
+    pub const MIN: Date = Date::YMD {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+    };
+
+    /// The latest date, `+2147483647-12-31`.
+    pub const MAX: Date = Date::YMD {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+    };
+
+    /// Builds a calendar date, checking that it exists.
+    ///
+    /// ```
+    /// use iso8601::{Date, DateError};
+    ///
+    /// assert_eq!(Date::from_ymd(2024, 2, 29), Ok(Date::YMD { year: 2024, month: 2, day: 29 }));
+    /// assert_eq!(Date::from_ymd(2023, 2, 29), Err(DateError::Day));
+    /// assert_eq!(Date::from_ymd(2023, 13, 1), Err(DateError::Month));
+    /// ```
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Date, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::Month);
+        }
+        if !(1..=calendar::days_in_month(year, month)).contains(&day) {
+            return Err(DateError::Day);
+        }
+        Ok(Date::YMD { year, month, day })
+    }
+
+    /// Builds a week date, checking that it exists.
+    ///
+    /// ```
+    /// use iso8601::{Date, DateError};
+    ///
+    /// assert_eq!(Date::from_iso_week(2020, 53, 7), Ok(Date::Week { year: 2020, ww: 53, d: 7 }));
+    /// assert_eq!(Date::from_iso_week(2023, 53, 1), Err(DateError::Week));
+    /// assert_eq!(Date::from_iso_week(2023, 1, 8), Err(DateError::Weekday));
+    /// // before -2147483648-01-01
+    /// assert_eq!(Date::from_iso_week(i32::MIN, 1, 1), Err(DateError::OutOfRange));
+    /// ```
+    pub fn from_iso_week(year: i32, ww: u32, d: u32) -> Result<Date, DateError> {
+        if !(1..=calendar::weeks_in_iso_year(year)).contains(&ww) {
+            return Err(DateError::Week);
+        }
+        if !(1..=7).contains(&d) {
+            return Err(DateError::Weekday);
+        }
+        let date = Date::Week { year, ww, d };
+        if !(Date::MIN.days_since_epoch()..=Date::MAX.days_since_epoch())
+            .contains(&date.days_since_epoch())
+        {
+            return Err(DateError::OutOfRange);
+        }
+        Ok(date)
+    }
+
+    /// Builds an ordinal date, checking that it exists.
+    ///
+    /// ```
+    /// use iso8601::{Date, DateError};
+    ///
+    /// assert_eq!(Date::from_ordinal(2024, 366), Ok(Date::Ordinal { year: 2024, ddd: 366 }));
+    /// assert_eq!(Date::from_ordinal(2023, 366), Err(DateError::Ordinal));
+    /// ```
+    pub fn from_ordinal(year: i32, ddd: u32) -> Result<Date, DateError> {
+        if !(1..=calendar::days_in_year(year)).contains(&ddd) {
+            return Err(DateError::Ordinal);
+        }
+        Ok(Date::Ordinal { year, ddd })
+    }
+
+    /// The year of this date in the calendar, whatever its format.
+    ///
+    /// The year of a week date can differ from its ISO week-numbering year,
+    /// which is the `year` of [`Date::Week`], like for `2020-W53-5`, the 1st of January 2021.
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// let date = Date::Week { year: 2020, ww: 53, d: 5 };
+    /// assert_eq!((date.year(), date.month(), date.day()), (2021, 1, 1));
+    /// let date = Date::Ordinal { year: 2023, ddd: 39 };
+    /// assert_eq!((date.year(), date.month(), date.day()), (2023, 2, 8));
+    /// ```
+    pub fn year(&self) -> i32 {
+        self.ymd().0
+    }
+
+    /// The month of this date, from `1` to `12` for dates that exist,
+    /// also for week and ordinal dates, see [`Date::year()`].
+    pub fn month(&self) -> u32 {
+        self.ymd().1
+    }
+
+    /// The day of the month of this date, from `1` to `31` for dates that exist,
+    /// also for week and ordinal dates, see [`Date::year()`].
+    pub fn day(&self) -> u32 {
+        self.ymd().2
+    }
+
+    // The calendar date of any date, as written for calendar dates, so even for those that
+    // do not exist, and computed for the others, which stay within `MIN` and `MAX`
+    fn ymd(&self) -> (i32, u32, u32) {
+        match *self {
+            Date::YMD { year, month, day } => (year, month, day),
+            Date::Week { .. } | Date::Ordinal { .. } => {
+                calendar::civil_from_days(self.day_number())
+            }
+        }
+    }
+
+    /// The day of the year of this date, from `1` to `366` for dates that exist,
+    /// also for calendar and week dates, see [`Date::year()`].
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::YMD { year: 2024, month: 12, day: 31 }.ordinal(), 366);
+    /// assert_eq!(Date::Week { year: 2020, ww: 53, d: 5 }.ordinal(), 1);
+    /// ```
+    pub fn ordinal(&self) -> u32 {
+        match *self {
+            Date::Ordinal { ddd, .. } => ddd,
+            Date::YMD { .. } | Date::Week { .. } => {
+                let days = self.day_number() - calendar::days_from_civil(self.year(), 1, 1);
+                // at most the days of a year, as the year is the one of the day
+                u32::try_from(days + 1).unwrap_or(0)
+            }
+        }
+    }
+
+    /// The ISO week-numbering year and the week of this date, whatever its format.
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with its first Thursday,
+    /// so the days from the 29th of December to the 3rd of January can belong
+    /// to a week of the other year.
+    /// The last two days of [`Date::MAX`] fall into the first week of a year
+    /// after the last one an `i32` holds, which is given as `i32::MAX`.
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::YMD { year: 2021, month: 1, day: 3 }.iso_week(), (2020, 53));
+    /// assert_eq!(Date::YMD { year: 2024, month: 12, day: 30 }.iso_week(), (2025, 1));
+    /// assert_eq!(Date::Ordinal { year: 2023, ddd: 39 }.iso_week(), (2023, 6));
+    /// ```
+    pub fn iso_week(&self) -> (i32, u32) {
+        let days = self.day_number();
+        // the week belongs to the year of its Thursday
+        let thursday = days - i64::from(calendar::weekday_from_days(days)) + 4;
+        if thursday > Date::MAX.days_since_epoch() {
+            return (i32::MAX, 1);
+        }
+        let (year, _, _) = calendar::civil_from_days(thursday);
+        let week = (thursday - calendar::days_from_civil(year, 1, 1)) / 7 + 1;
+        (year, u32::try_from(week).unwrap_or(1))
+    }
+
+    // The number of days since 1970-01-01 for this date, within those of `MIN` and `MAX`
+    fn day_number(&self) -> i64 {
+        self.days_since_epoch()
+            .clamp(Date::MIN.days_since_epoch(), Date::MAX.days_since_epoch())
+    }
+
+    /// The day of the week of this date, whatever its format, computed without chrono.
+    ///
+    /// Dates that do not exist, like `2023-02-30`, are taken to be the day they would be
+    /// counting on from the start of their month, week or year, like `2023-03-02`.
+    ///
+    /// ```
+    /// use iso8601::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::YMD { year: 2000, month: 1, day: 1 }.weekday(), Weekday::Saturday);
+    /// assert_eq!(Date::Ordinal { year: 2024, ddd: 366 }.weekday(), Weekday::Tuesday);
+    /// assert_eq!(Date::Week { year: 2023, ww: 6, d: 7 }.weekday(), Weekday::Sunday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        // the calendar numbers the days of the week like week dates, so always from 1 to 7
+        Weekday::from_number_from_monday(calendar::weekday_from_days(self.days_since_epoch()))
+            .unwrap_or(Weekday::Monday)
+    }
+
+    /// The Julian Day Number of this date, whatever its format,
+    /// which counts the days since the 1st of January 4713 BC of the proleptic Julian calendar.
+    ///
+    /// Dates that do not exist are taken to be the day they would be, like in [`Date::weekday()`].
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::YMD { year: 2000, month: 1, day: 1 }.to_julian_day(), 2_451_545);
+    /// assert_eq!(Date::Week { year: 2023, ww: 6, d: 3 }.to_julian_day(), 2_459_984);
+    /// assert_eq!(Date::YMD { year: -4713, month: 11, day: 24 }.to_julian_day(), 0);
+    /// ```
+    pub fn to_julian_day(&self) -> i64 {
+        self.days_since_epoch()
+            .saturating_add(calendar::JULIAN_DAY_OF_EPOCH)
+    }
+
+    /// The calendar date of a Julian Day Number, undoing [`Date::to_julian_day()`],
+    /// or [`DateError::OutOfRange`] before [`Date::MIN`] or after [`Date::MAX`].
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::from_julian_day(2_451_545), Ok(Date::YMD { year: 2000, month: 1, day: 1 }));
+    /// assert_eq!(Date::from_julian_day(-1), Ok(Date::YMD { year: -4713, month: 11, day: 23 }));
+    /// ```
+    pub fn from_julian_day(day: i64) -> Result<Date, DateError> {
+        let days = day
+            .checked_sub(calendar::JULIAN_DAY_OF_EPOCH)
+            .ok_or(DateError::OutOfRange)?;
+        Date::YMD {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+        .with_days(days)
+    }
+
+    /// The Modified Julian Day of this date, whatever its format,
+    /// which counts the days since 1858-11-17, like [`Date::to_julian_day()`].
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::YMD { year: 1858, month: 11, day: 17 }.to_modified_julian_day(), 0);
+    /// assert_eq!(Date::YMD { year: 2023, month: 2, day: 8 }.to_modified_julian_day(), 59_983);
+    /// ```
+    pub fn to_modified_julian_day(&self) -> i64 {
+        self.days_since_epoch()
+            .saturating_add(calendar::MODIFIED_JULIAN_DAY_OF_EPOCH)
+    }
+
+    /// The calendar date of a Modified Julian Day, undoing [`Date::to_modified_julian_day()`],
+    /// or [`DateError::OutOfRange`] before [`Date::MIN`] or after [`Date::MAX`].
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// assert_eq!(Date::from_modified_julian_day(59_983), Ok(Date::YMD { year: 2023, month: 2, day: 8 }));
+    /// ```
+    pub fn from_modified_julian_day(day: i64) -> Result<Date, DateError> {
+        let days = day
+            .checked_sub(calendar::MODIFIED_JULIAN_DAY_OF_EPOCH)
+            .ok_or(DateError::OutOfRange)?;
+        Date::YMD {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+        .with_days(days)
+    }
+
+    /// The number of days since `epoch`, whatever the format of this date,
+    /// negative for dates before it.
+    ///
+    /// The days since the Unix epoch fit an `i32` from `-5877641-06-23` to `5881580-07-11`,
+    /// and [`Date::from_days()`] turns them back into the same calendar date.
+    ///
+    /// ```
+    /// use iso8601::{Date, Epoch};
+    ///
+    /// let date = iso8601::date("2023-02-08").unwrap();
+    /// assert_eq!(date.to_days_since(Epoch::Unix), 19_396);
+    /// assert_eq!(date.to_days_since(Epoch::RataDie), 738_559);
+    /// assert_eq!(Date::from_days(19_396, Epoch::Unix), Ok(date));
+    /// ```
+    pub fn to_days_since(&self, epoch: Epoch) -> i64 {
+        self.days_since_epoch().saturating_add(epoch.unix_day())
+    }
+
+    /// The calendar date of a number of days since `epoch`, undoing [`Date::to_days_since()`],
+    /// or [`DateError::OutOfRange`] before [`Date::MIN`] or after [`Date::MAX`].
+    pub fn from_days(days: i64, epoch: Epoch) -> Result<Date, DateError> {
+        let days = days
+            .checked_sub(epoch.unix_day())
+            .ok_or(DateError::OutOfRange)?;
+        Date::YMD {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+        .with_days(days)
+    }
+
+    /// The smallest component this date was written with, which is always [`Precision::Day`]
+    /// as all of its formats name a day, see [`ReducedDate`] for dates without one.
+    pub fn precision(&self) -> Precision {
+        Precision::Day
+    }
+
+    /// Whether this date exists in the calendar.
+    ///
+    /// The parsers only check each component on its own,
+    /// so they accept dates like `2023-02-30`, `2023-W53-1` or `2023-366`.
+    ///
+    /// ```
+    /// assert!(iso8601::date("2024-02-29").unwrap().is_valid());
+    /// assert!(!iso8601::date("2023-02-29").unwrap().is_valid());
+    /// assert!(!iso8601::date("2023-W53-1").unwrap().is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Checks that this date exists in the calendar, see [`Date::is_valid()`],
+    /// and marks it as such.
+    ///
+    /// ```
+    /// use iso8601::DateError;
+    ///
+    /// let date = iso8601::date("2024-02-29").unwrap().validate().unwrap();
+    /// assert_eq!(date.year(), 2024);
+    /// assert_eq!(iso8601::date("2023-02-29").unwrap().validate(), Err(DateError::Day));
+    /// ```
+    pub fn validate(&self) -> Result<ValidDate, DateError> {
+        match *self {
+            Date::YMD { year, month, day } => Date::from_ymd(year, month, day),
+            Date::Week { year, ww, d } => Date::from_iso_week(year, ww, d),
+            Date::Ordinal { year, ddd } => Date::from_ordinal(year, ddd),
+        }
+        .map(ValidDate)
+    }
+
+    /// All reasons why this date would not be parsed, empty if there are none.
+    ///
+    /// These are the kinds of [`ParseError`]s the parsers give for components out of range,
+    /// and [`ErrorKind::NonexistentDate`] for those that are within range but do not exist,
+    /// which [`ParserConfig::validate_calendar`](crate::ParserConfig::validate_calendar) rejects.
+    /// Unlike [`Date::validate()`], this finds all of them at once.
+    ///
+    /// ```
+    /// use iso8601::{Date, ErrorKind};
+    ///
+    /// assert_eq!(Date::YMD { year: 2023, month: 2, day: 8 }.violations(), vec![]);
+    /// assert_eq!(
+    ///     Date::YMD { year: 2023, month: 13, day: 32 }.violations(),
+    ///     vec![ErrorKind::InvalidMonth, ErrorKind::InvalidDay]
+    /// );
+    /// assert_eq!(
+    ///     Date::Week { year: 2023, ww: 53, d: 1 }.violations(),
+    ///     vec![ErrorKind::NonexistentDate]
+    /// );
+    /// ```
+    pub fn violations(&self) -> Vec<ErrorKind> {
+        let mut violations = Vec::new();
+        let mut check = |ok: bool, kind| {
+            if !ok {
+                violations.push(kind);
+            }
+        };
+
+        match *self {
+            Date::YMD { year, month, day } => {
+                check((1..=12).contains(&month), ErrorKind::InvalidMonth);
+                check((1..=31).contains(&day), ErrorKind::InvalidDay);
+                check(
+                    !(1..=12).contains(&month)
+                        || !(1..=31).contains(&day)
+                        || day <= calendar::days_in_month(year, month),
+                    ErrorKind::NonexistentDate,
+                );
+            }
+            Date::Week { ww, d, .. } => {
+                check((1..=53).contains(&ww), ErrorKind::InvalidWeek);
+                check((1..=7).contains(&d), ErrorKind::InvalidWeekday);
+                check(
+                    !(1..=53).contains(&ww) || !(1..=7).contains(&d) || self.validate().is_ok(),
+                    ErrorKind::NonexistentDate,
+                );
+            }
+            Date::Ordinal { year, ddd } => {
+                check((1..=366).contains(&ddd), ErrorKind::InvalidOrdinal);
+                check(
+                    !(1..=366).contains(&ddd) || ddd <= calendar::days_in_year(year),
+                    ErrorKind::NonexistentDate,
+                );
+            }
+        }
+        violations
+    }
+
+    /// The same day as a calendar date, or why this date does not exist.
+    ///
+    /// The variants of [`Date`] compare unequal even for the same day,
+    /// see [`Date::same_day()`] to compare days regardless of their format.
+    /// Like equality, hashing tells the formats apart,
+    /// so use canonical dates as keys to find the same day in any format.
+    ///
+    /// ```
+    /// use iso8601::{Date, DateError};
+    ///
+    /// let ymd = Date::YMD { year: 2023, month: 2, day: 8 };
+    /// assert_eq!(Date::Week { year: 2023, ww: 6, d: 3 }.canonical(), Ok(ymd));
+    /// assert_eq!(Date::Ordinal { year: 2023, ddd: 39 }.canonical(), Ok(ymd));
+    /// assert_eq!(Date::Ordinal { year: 2023, ddd: 366 }.canonical(), Err(DateError::Ordinal));
+    ///
+    /// let days: std::collections::HashSet<_> = ["2023-02-08", "2023-W06-3", "2023-039"]
+    ///     .iter()
+    ///     .map(|s| iso8601::date(s).unwrap().canonical().unwrap())
+    ///     .collect();
+    /// assert_eq!(days.len(), 1);
+    /// ```
+    pub fn canonical(&self) -> Result<Date, DateError> {
+        self.to_ymd()
+    }
+
+    /// The same day as a calendar date like `2023-02-08`, or why this date does not exist,
+    /// computed without chrono, see [`Date::canonical()`].
+    ///
+    /// ```
+    /// let date = iso8601::date("2023-039").unwrap();
+    /// assert_eq!(date.to_ymd(), Ok(iso8601::date("2023-02-08").unwrap()));
+    /// ```
+    pub fn to_ymd(&self) -> Result<Date, DateError> {
+        let date = self.validate()?;
+        let (year, month, day) = calendar::civil_from_days(date.days_since_epoch());
+        Ok(Date::YMD { year, month, day })
+    }
+
+    /// The same day as a week date like `2023-W06-3`, or why this date does not exist,
+    /// see [`Date::iso_week()`].
+    ///
+    /// The last two days of [`Date::MAX`] fall into a week-numbering year after the last one
+    /// an `i32` holds, so they fail with [`DateError::OutOfRange`].
+    ///
+    /// ```
+    /// let date = iso8601::date("2021-01-03").unwrap();
+    /// assert_eq!(date.to_week_date(), Ok(iso8601::date("2020-W53-7").unwrap()));
+    /// ```
+    pub fn to_week_date(&self) -> Result<Date, DateError> {
+        self.validate()?;
+        let (year, ww) = self.iso_week();
+        let week = Date::Week {
+            year,
+            ww,
+            d: self.weekday().number_from_monday(),
+        };
+        if week.days_since_epoch() != self.days_since_epoch() {
+            return Err(DateError::OutOfRange);
+        }
+        Ok(week)
+    }
+
+    /// The same day as an ordinal date like `2023-039`, or why this date does not exist.
+    ///
+    /// ```
+    /// let date = iso8601::date("2020-W53-7").unwrap();
+    /// assert_eq!(date.to_ordinal(), Ok(iso8601::date("2021-003").unwrap()));
+    /// ```
+    pub fn to_ordinal(&self) -> Result<Date, DateError> {
+        self.validate()?;
+        Ok(Date::Ordinal {
+            year: self.year(),
+            ddd: self.ordinal(),
+        })
+    }
+
+    /// Whether both dates are the same existing day, whatever their format.
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// let ymd = Date::YMD { year: 2023, month: 2, day: 8 };
+    /// assert!(ymd.same_day(&Date::Week { year: 2023, ww: 6, d: 3 }));
+    /// assert!(ymd.same_day(&Date::Ordinal { year: 2023, ddd: 39 }));
+    /// assert!(!ymd.same_day(&Date::Ordinal { year: 2023, ddd: 40 }));
+    /// ```
+    pub fn same_day(&self, other: &Date) -> bool {
+        match (self.canonical(), other.canonical()) {
+            (Ok(date), Ok(other)) => date == other,
+            _ => false,
+        }
+    }
+
+    /// The number of days since 1970-01-01 for this date,
+    /// which is only meaningful if it exists, but never overflows.
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        match *self {
+            Date::YMD { year, month, day } => calendar::days_from_civil(year, month, day),
+            Date::Week { year, ww, d } => {
+                // the first week of a year is the one containing its 4th of January
+                let jan4 = calendar::days_from_civil(year, 1, 4);
+                let monday = jan4 - i64::from(calendar::weekday_from_days(jan4)) + 1;
+                monday + (i64::from(ww) - 1) * 7 + i64::from(d) - 1
+            }
+            Date::Ordinal { year, ddd } => {
+                calendar::days_from_civil(year, 1, 1) + i64::from(ddd) - 1
+            }
+        }
+    }
+
+    /// The following day, keeping the format of this date,
+    /// or `None` if that is after [`Date::MAX`].
+    ///
+    /// ```
+    /// let succ = |s| iso8601::date(s).unwrap().succ().unwrap().to_string();
+    /// assert_eq!(succ("2024-02-28"), "2024-02-29");
+    /// assert_eq!(succ("2020-W53-7"), "2021-W01-1");
+    /// assert_eq!(succ("2023-365"), "2024-001");
+    /// assert_eq!(iso8601::Date::MAX.succ(), None);
+    /// ```
+    pub fn succ(&self) -> Option<Date> {
+        // the last week of the last year ends before its last day, so only the year can overflow
+        let next = match *self {
+            Date::YMD { year, month, day } if day < calendar::days_in_month(year, month) => {
+                Date::YMD {
+                    year,
+                    month,
+                    day: day + 1,
+                }
+            }
+            Date::YMD { year, month, .. } if month < 12 => Date::YMD {
+                year,
+                month: month + 1,
+                day: 1,
+            },
+            Date::YMD { year, .. } => Date::YMD {
+                year: year.checked_add(1)?,
+                month: 1,
+                day: 1,
+            },
+            Date::Week { year, ww, d } if d < 7 => Date::Week { year, ww, d: d + 1 },
+            Date::Week { year, ww, .. } if ww < calendar::weeks_in_iso_year(year) => Date::Week {
+                year,
+                ww: ww + 1,
+                d: 1,
+            },
+            Date::Week { year, .. } => Date::Week {
+                year: year.checked_add(1)?,
+                ww: 1,
+                d: 1,
+            },
+            Date::Ordinal { year, ddd } if ddd < calendar::days_in_year(year) => {
+                Date::Ordinal { year, ddd: ddd + 1 }
+            }
+            Date::Ordinal { year, .. } => Date::Ordinal {
+                year: year.checked_add(1)?,
+                ddd: 1,
+            },
+        };
+        Some(next)
+    }
+
+    /// The preceding day, keeping the format of this date,
+    /// or `None` if that is before [`Date::MIN`].
+    ///
+    /// ```
+    /// let pred = |s| iso8601::date(s).unwrap().pred().unwrap().to_string();
+    /// assert_eq!(pred("2024-03-01"), "2024-02-29");
+    /// assert_eq!(pred("2021-W01-1"), "2020-W53-7");
+    /// assert_eq!(pred("2024-001"), "2023-365");
+    /// assert_eq!(iso8601::Date::MIN.pred(), None);
+    /// ```
+    pub fn pred(&self) -> Option<Date> {
+        let previous = match *self {
+            Date::YMD { year, month, day } if day > 1 => Date::YMD {
+                year,
+                month,
+                day: day - 1,
+            },
+            Date::YMD { year, month, .. } if month > 1 => Date::YMD {
+                year,
+                month: month - 1,
+                day: calendar::days_in_month(year, month - 1),
+            },
+            Date::YMD { year, .. } => Date::YMD {
+                year: year.checked_sub(1)?,
+                month: 12,
+                day: 31,
+            },
+            Date::Week { year, ww, d } if d > 1 => Date::Week { year, ww, d: d - 1 },
+            Date::Week { year, ww, .. } if ww > 1 => Date::Week {
+                year,
+                ww: ww - 1,
+                d: 7,
+            },
+            Date::Week { year, .. } => {
+                let year = year.checked_sub(1)?;
+                Date::Week {
+                    year,
+                    ww: calendar::weeks_in_iso_year(year),
+                    d: 7,
+                }
+            }
+            Date::Ordinal { year, ddd } if ddd > 1 => Date::Ordinal { year, ddd: ddd - 1 },
+            Date::Ordinal { year, .. } => {
+                let year = year.checked_sub(1)?;
+                Date::Ordinal {
+                    year,
+                    ddd: calendar::days_in_year(year),
+                }
+            }
+        };
+        // the first week of the first year starts before it
+        if previous.days_since_epoch() < Date::MIN.days_since_epoch() {
+            return None;
+        }
+        Some(previous)
+    }
+
+    /// This date moved by a number of days, which may be negative, keeping its format.
+    ///
+    /// Fails for dates that do not exist and with [`DateError::OutOfRange`]
+    /// for results before [`Date::MIN`] or after [`Date::MAX`].
+    ///
+    /// ```
+    /// let date = iso8601::date("2023-W06-3").unwrap();
+    /// assert_eq!(date.add_days(7).unwrap().to_string(), "2023-W07-3");
+    /// assert_eq!(date.add_days(-38).unwrap().to_string(), "2022-W52-7");
+    /// ```
+    pub fn add_days(&self, days: i64) -> Result<Date, DateError> {
+        let date = self.validate()?;
+        let days = date
+            .days_since_epoch()
+            .checked_add(days)
+            .ok_or(DateError::OutOfRange)?;
+        self.with_days(days)
+    }
+
+    /// This date moved by a number of months, which may be negative, keeping its format.
+    ///
+    /// The day of the month stays the same where the new month has it,
+    /// and is handled by `policy` where it does not.
+    /// Week and ordinal dates are moved by the months of their calendar date.
+    ///
+    /// Fails for dates that do not exist and with [`DateError::OutOfRange`]
+    /// for results before [`Date::MIN`] or after [`Date::MAX`].
+    ///
+    /// ```
+    /// use iso8601::{DateError, OverflowPolicy};
+    ///
+    /// let date = iso8601::date("2023-01-31").unwrap();
+    /// let add = |policy| date.add_months(1, policy).map(|d| d.to_string());
+    /// assert_eq!(add(OverflowPolicy::Constrain), Ok("2023-02-28".into()));
+    /// assert_eq!(add(OverflowPolicy::Overflow), Ok("2023-03-03".into()));
+    /// assert_eq!(add(OverflowPolicy::Reject), Err(DateError::Day));
+    /// ```
+    pub fn add_months(&self, months: i64, policy: OverflowPolicy) -> Result<Date, DateError> {
+        let date = self.validate()?;
+        let (year, month, day) = calendar::civil_from_days(date.days_since_epoch());
+
+        let months = (i64::from(year) * 12 + i64::from(month) - 1)
+            .checked_add(months)
+            .ok_or(DateError::OutOfRange)?;
+        let year = i32::try_from(months.div_euclid(12)).map_err(|_| DateError::OutOfRange)?;
+        // the remainder is within `0..12`
+        let month = months.rem_euclid(12) as u32 + 1;
+
+        let last = calendar::days_in_month(year, month);
+        let days = match policy {
+            _ if day <= last => calendar::days_from_civil(year, month, day),
+            OverflowPolicy::Constrain => calendar::days_from_civil(year, month, last),
+            OverflowPolicy::Overflow => {
+                calendar::days_from_civil(year, month, last) + i64::from(day - last)
+            }
+            OverflowPolicy::Reject => return Err(DateError::Day),
+        };
+        self.with_days(days)
+    }
+
+    /// This date moved by a number of years, which may be negative, keeping its format,
+    /// like [`Date::add_months()`] with twelve months a year,
+    /// so `policy` handles the 29th of February in years without one.
+    ///
+    /// ```
+    /// use iso8601::OverflowPolicy;
+    ///
+    /// let date = iso8601::date("2024-02-29").unwrap();
+    /// let add = |policy| date.add_years(1, policy).unwrap().to_string();
+    /// assert_eq!(add(OverflowPolicy::Constrain), "2025-02-28");
+    /// assert_eq!(add(OverflowPolicy::Overflow), "2025-03-01");
+    /// ```
+    pub fn add_years(&self, years: i64, policy: OverflowPolicy) -> Result<Date, DateError> {
+        let months = years.checked_mul(12).ok_or(DateError::OutOfRange)?;
+        self.add_months(months, policy)
+    }
+
+    /// Like [`Date::add_days()`], but `None` instead of an error.
+    pub fn checked_add_days(&self, days: i64) -> Option<Date> {
+        self.add_days(days).ok()
+    }
+
+    /// Like [`Date::add_months()`], but `None` instead of an error.
+    pub fn checked_add_months(&self, months: i64, policy: OverflowPolicy) -> Option<Date> {
+        self.add_months(months, policy).ok()
+    }
+
+    /// Like [`Date::add_years()`], but `None` instead of an error.
+    pub fn checked_add_years(&self, years: i64, policy: OverflowPolicy) -> Option<Date> {
+        self.add_years(years, policy).ok()
+    }
+
+    /// Like [`Date::add_days()`], but stopping at the first or last day
+    /// that can be written in the format of this date instead of going out of range.
+    ///
+    /// Dates that [`Date::add_days()`] fails for otherwise, as they do not exist,
+    /// are returned unchanged.
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// let date = iso8601::date("2023-02-08").unwrap();
+    /// assert_eq!(date.saturating_add_days(1), iso8601::date("2023-02-09").unwrap());
+    /// assert_eq!(date.saturating_add_days(i64::MAX), Date::MAX);
+    /// assert_eq!(date.saturating_add_days(i64::MIN), Date::MIN);
+    /// ```
+    pub fn saturating_add_days(&self, days: i64) -> Date {
+        self.saturate(self.add_days(days), days < 0)
+    }
+
+    /// Like [`Date::add_months()`], but stopping at the first or last day
+    /// that can be written in the format of this date instead of going out of range.
+    ///
+    /// Dates that [`Date::add_months()`] fails for otherwise, as they do not exist
+    /// or `policy` rejects them, are returned unchanged.
+    pub fn saturating_add_months(&self, months: i64, policy: OverflowPolicy) -> Date {
+        self.saturate(self.add_months(months, policy), months < 0)
+    }
+
+    /// Like [`Date::add_years()`], but stopping at the first or last day
+    /// that can be written in the format of this date instead of going out of range.
+    ///
+    /// Dates that [`Date::add_years()`] fails for otherwise, as they do not exist
+    /// or `policy` rejects them, are returned unchanged.
+    pub fn saturating_add_years(&self, years: i64, policy: OverflowPolicy) -> Date {
+        self.saturate(self.add_years(years, policy), years < 0)
+    }
+
+    // The result of an addition, or the first or last day in the format of this date
+    // if it went out of range backwards or forwards
+    fn saturate(&self, result: Result<Date, DateError>, backwards: bool) -> Date {
+        match result {
+            Ok(date) => date,
+            Err(DateError::OutOfRange) => self.edge(!backwards),
+            Err(_) => *self,
+        }
+    }
+
+    // The first or `last` day that can be written in the format of this date,
+    // as week dates end two days before `MAX`
+    pub(crate) fn edge(&self, last: bool) -> Date {
+        let (days, step, bound) = if last {
+            (Date::MAX.days_since_epoch(), -1, Date::MAX)
+        } else {
+            (Date::MIN.days_since_epoch(), 1, Date::MIN)
+        };
+        (0..7)
+            .find_map(|i| self.with_days(days + i * step).ok())
+            .unwrap_or(bound)
+    }
+
+    // The first day of the year, month or week of this date in its format,
+    // which is the first of the week-numbering year for week dates,
+    // or this date for smaller units
+    pub(crate) fn truncate_to(&self, unit: Unit) -> Date {
+        let first = match (unit, *self) {
+            (Unit::Day | Unit::Hour | Unit::Minute | Unit::Second, date) => return date,
+            (Unit::Year, Date::YMD { year, .. }) => {
+                return Date::YMD {
+                    year,
+                    month: 1,
+                    day: 1,
+                }
+            }
+            (Unit::Year, Date::Week { year, .. }) => return Date::Week { year, ww: 1, d: 1 },
+            (Unit::Year, Date::Ordinal { year, .. }) => return Date::Ordinal { year, ddd: 1 },
+            (Unit::Month, Date::YMD { year, month, .. }) => {
+                return Date::YMD {
+                    year,
+                    month,
+                    day: 1,
+                }
+            }
+            (Unit::Week, Date::Week { year, ww, .. }) => return Date::Week { year, ww, d: 1 },
+            (Unit::Month, _) => {
+                let (year, month, _) = self.ymd();
+                calendar::days_from_civil(year, month, 1)
+            }
+            (Unit::Week, _) => {
+                let days = self.day_number();
+                days - i64::from(calendar::weekday_from_days(days)) + 1
+            }
+        };
+        // the Monday before `MIN`, or a first day it can not be written as a week date
+        self.with_days(first).unwrap_or_else(|_| self.edge(false))
+    }
+
+    /// The years, months and days from this date to `other`, the way ages are counted,
+    /// like `until()` of a `Temporal.PlainDate` with years as the largest unit.
+    ///
+    /// These are the most whole months that [`Date::add_months()`] with
+    /// [`OverflowPolicy::Constrain`] can add to this date without passing `other`,
+    /// and the days left after that.
+    /// All of them are negative if `other` is earlier.
+    /// Dates that do not exist count as the days they overflow into,
+    /// like `2023-02-30` as `2023-03-02`.
+    ///
+    /// ```
+    /// let date = |s| iso8601::date(s).unwrap();
+    /// let age = |a, b| date(a).age_until(&date(b)).to_string();
+    /// assert_eq!(age("1990-05-17", "2023-02-08"), "P32Y8M22D");
+    /// assert_eq!(age("2023-01-31", "2023-03-01"), "P1M1D");
+    /// assert_eq!(age("2023-03-01", "2023-01-31"), "-P1M1D");
+    /// assert_eq!(age("2024-02-29", "2025-02-28"), "P1Y");
+    /// ```
+    pub fn age_until(&self, other: &Date) -> Duration {
+        let (start, end) = (self.day_number(), other.day_number());
+        let (year, month, day) = calendar::civil_from_days(start);
+        let (end_year, end_month, _) = calendar::civil_from_days(end);
+
+        // the day `months` after this one, constrained to the end of its month
+        let after = |months: i64| {
+            let months = i64::from(year) * 12 + i64::from(month) - 1 + months;
+            // between the months of both dates, so within the range of years
+            let (year, month) = (
+                months.div_euclid(12) as i32,
+                months.rem_euclid(12) as u32 + 1,
+            );
+            calendar::days_from_civil(year, month, day.min(calendar::days_in_month(year, month)))
+        };
+        let mut months =
+            (i64::from(end_year) - i64::from(year)) * 12 + i64::from(end_month) - i64::from(month);
+        if end >= start && after(months) > end {
+            months -= 1;
+        } else if end < start && after(months) < end {
+            months += 1;
+        }
+
+        Duration::YMDHMS {
+            year: months / 12,
+            month: months % 12,
+            day: end - after(months),
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        }
+    }
+
+    // The day a number of days since 1970-01-01 in the format of this date,
+    // or `OutOfRange` if it is before `MIN` or after `MAX`
+    fn with_days(&self, days: i64) -> Result<Date, DateError> {
+        if !(Date::MIN.days_since_epoch()..=Date::MAX.days_since_epoch()).contains(&days) {
+            return Err(DateError::OutOfRange);
+        }
+        let (year, month, day) = calendar::civil_from_days(days);
+        let date = Date::YMD { year, month, day };
+        match self {
+            Date::YMD { .. } => Ok(date),
+            Date::Week { .. } => date.to_week_date(),
+            Date::Ordinal { .. } => date.to_ordinal(),
+        }
+    }
+
+    /// The days from this date up to but not including `end`, in the format of this date.
+    ///
+    /// The range is empty if `end` is not after this date, compared by their days like
+    /// [`Date::cmp()`](Ord::cmp) does, so `end` can have any format.
+    ///
+    /// ```
+    /// let start = iso8601::date("2023-02-27").unwrap();
+    /// let end = iso8601::date("2023-W09-4").unwrap();
+    /// let days: Vec<_> = start.until(end).map(|d| d.to_string()).collect();
+    /// assert_eq!(days, ["2023-02-27", "2023-02-28", "2023-03-01"]);
+    /// ```
+    pub fn until(&self, end: Date) -> DateRange {
+        DateRange {
+            next: Some(*self),
+            end: end.days_since_epoch(),
+        }
+    }
+}
+
+/// The days of a range of dates, from [`Date::until()`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct DateRange {
+    next: Option<Date>,
+    // the number of days since 1970-01-01 of the first day after the range
+    end: i64,
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let date = self
+            .next
+            .filter(|date| date.days_since_epoch() < self.end)?;
+        self.next = date.succ();
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(date) if date.is_valid() => {
+                // the range ends early after the last day
+                let end = self.end.min(Date::MAX.days_since_epoch() + 1);
+                let days = usize::try_from(end - date.days_since_epoch()).unwrap_or(0);
+                (days, Some(days))
+            }
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl FusedIterator for DateRange {}
+
+/// Orders dates by their day, whatever their format.
+///
+/// The same day in different formats is ordered calendar date first, then week date,
+/// then ordinal date, as they are not equal. Dates that do not exist, like `2023-02-30`,
+/// are ordered consistently but not meaningfully.
+///
+/// ```
+/// let mut dates = ["2023-039", "2023-W06-2", "2023-02-09", "2023-02-08"]
+///     .map(|s| iso8601::date(s).unwrap());
+/// dates.sort();
+/// assert_eq!(dates.map(|d| d.to_string()), ["2023-W06-2", "2023-02-08", "2023-039", "2023-02-09"]);
+/// ```
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Date {
+    // The day, then the variant and its fields to tell apart all unequal dates
+    fn sort_key(&self) -> (i64, u8, i32, u32, u32) {
+        let (variant, year, a, b) = match *self {
+            Date::YMD { year, month, day } => (0, year, month, day),
+            Date::Week { year, ww, d } => (1, year, ww, d),
+            Date::Ordinal { year, ddd } => (2, year, ddd, 0),
+        };
+        (self.days_since_epoch(), variant, year, a, b)
+    }
+}
+
 impl Default for Date {
     fn default() -> Date {
         Date::YMD {
@@ -33,30 +1105,316 @@ impl Default for Date {
 }
 
 impl FromStr for Date {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         date(s)
     }
 }
 
+/// A [`Date`] that exists in the calendar, from [`Date::validate()`] or by parsing it.
+///
+/// It dereferences to the [`Date`] it wraps, which can be taken out with [`Date::from()`].
+/// ```
+/// let date: iso8601::ValidDate = "2023-02-08".parse().unwrap();
+/// assert_eq!(*date, iso8601::Date::YMD { year: 2023, month: 2, day: 8 });
+/// assert!("2023-02-30".parse::<iso8601::ValidDate>().is_err());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct ValidDate(pub(crate) Date);
+
+impl Deref for ValidDate {
+    type Target = Date;
+
+    fn deref(&self) -> &Date {
+        &self.0
+    }
+}
+
+impl From<ValidDate> for Date {
+    fn from(date: ValidDate) -> Date {
+        date.0
+    }
+}
+
+impl TryFrom<Date> for ValidDate {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        date.validate()
+    }
+}
+
+impl FromStr for ValidDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        date(s)?.validate().map_err(|_| {
+            ParseError::new(
+                Component::Date,
+                s.as_bytes(),
+                s.as_bytes(),
+                Expected::ExistingDate,
+            )
+        })
+    }
+}
+
+/// A date with reduced precision, missing the day or both month and day.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::ReducedDate::from_str("2023-02"),
+///     Ok(iso8601::ReducedDate::YearMonth { year: 2023, month: 2 })
+/// )
+/// ```
+#[allow(missing_docs)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum ReducedDate {
+    /// consists of a year only
+    Year { year: i32 },
+    /// consists of year and month
+    YearMonth { year: i32, month: u32 },
+}
+
+impl ReducedDate {
+    /// The smallest component this date was written with, [`Precision::Year`] or [`Precision::Month`].
+    ///
+    /// ```
+    /// let date = iso8601::reduced_date("2023-02").unwrap();
+    /// assert_eq!(date.precision(), iso8601::Precision::Month);
+    /// ```
+    pub fn precision(&self) -> Precision {
+        match self {
+            ReducedDate::Year { .. } => Precision::Year,
+            ReducedDate::YearMonth { .. } => Precision::Month,
+        }
+    }
+}
+
+impl FromStr for ReducedDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        reduced_date(s)
+    }
+}
+
+/// A date truncated to omit the year, as permitted by ISO 8601:2000.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::TruncatedDate::from_str("--02-08"),
+///     Ok(iso8601::TruncatedDate::MonthDay { month: 2, day: 8 })
+/// )
+/// ```
+#[allow(missing_docs)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum TruncatedDate {
+    /// consists of month and day of month, like `--02-08`
+    MonthDay { month: u32, day: u32 },
+    /// consists of a month only, like `--02`
+    Month { month: u32 },
+    /// consists of a day of month only, like `---08`
+    Day { day: u32 },
+}
+
+impl FromStr for TruncatedDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        truncated_date(s)
+    }
+}
+
+/// A calendar week of an ISO week-numbering year, without a specific day.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::Week::from_str("2023-W06"),
+///     Ok(iso8601::Week { year: 2023, ww: 6 })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct Week {
+    /// the ISO week-numbering year
+    pub year: i32,
+    /// the week of the year, starting at 1
+    pub ww: u32,
+}
+
+impl Week {
+    /// The first day of this week, its Monday.
+    pub fn first_day(&self) -> Date {
+        Date::Week {
+            year: self.year,
+            ww: self.ww,
+            d: 1,
+        }
+    }
+
+    /// The last day of this week, its Sunday.
+    pub fn last_day(&self) -> Date {
+        Date::Week {
+            year: self.year,
+            ww: self.ww,
+            d: 7,
+        }
+    }
+}
+
+impl FromStr for Week {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        week(s)
+    }
+}
+
 /// Parses a date string.
 ///
-/// A string can have one of the following formats:
+/// A string can have one of the following formats,
+/// either in the extended format with `-` separators or in the basic format without them:
 ///
 /// * `2015-11-02` or `20151102`
-/// * `2015-W45-01` or `2015W451`
+/// * `2015-W45-1` or `2015W451`
 /// * `2015-306` or `2015306`
 ///
+/// The year may be preceded by a `+` or `-` sign, like in `-0333-07-11`, but a signed year
+/// cannot be directly followed by another digit, so `+20151102` is rejected. Years of more
+/// than four digits, like in `+0020230-02-08`, need [`date_expanded()`](crate::date_expanded()).
+///
 /// ## Example
 ///
 /// ```rust
 /// let date = iso8601::date("2015-11-02").unwrap();
+/// assert!(iso8601::date("+0020230-02-08").is_err());
 /// ```
-pub fn date(string: &str) -> Result<Date, String> {
-    if let Ok((_, parsed)) = parsers::parse_date(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse date: {}", string))
-    }
+pub fn date(string: &str) -> Result<Date, ParseError> {
+    date_bytes(string.as_bytes())
+}
+
+/// Parses a date from bytes, like [`date()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::date_bytes(b"2015-11-02").unwrap();
+/// ```
+pub fn date_bytes(bytes: &[u8]) -> Result<Date, ParseError> {
+    error::prefix(Component::Date, bytes, parsers::parse_date(bytes)).map(|(parsed, _)| parsed)
+}
+
+/// Parses a date at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`date()`], the date may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (date, rest) = iso8601::date_prefix("2023-02-08 is a Wednesday").unwrap();
+/// assert_eq!(rest, " is a Wednesday");
+/// ```
+pub fn date_prefix(string: &str) -> Result<(Date, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::Date,
+        string.as_bytes(),
+        parsers::parse_date(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
+}
+
+/// Parses a date string with an expanded year representation.
+///
+/// Expanded years have a mandatory sign and exactly `extra_digits` more digits
+/// than the usual four, as agreed on by the communicating parties.
+/// A string can have one of the following formats, here with `extra_digits = 2`:
+///
+/// * `+002015-11-02` or `+0020151102`
+/// * `+002015-W45-1` or `+002015W451`
+/// * `+002015-306` or `+002015306`
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::date_expanded("+0020230-02-08", 3).unwrap();
+/// assert_eq!(date, iso8601::Date::YMD { year: 20230, month: 2, day: 8 });
+/// ```
+pub fn date_expanded(string: &str, extra_digits: usize) -> Result<Date, ParseError> {
+    error::prefix(
+        Component::ExpandedDate,
+        string.as_bytes(),
+        parsers::parse_date_expanded(extra_digits)(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Parses a reduced precision date string.
+///
+/// A string can have one of the following formats:
+///
+/// * `2015`, a specific year
+/// * `2015-11`, a specific month
+///
+/// The month always needs to be separated by a `-`,
+/// as the basic format `201511` could be confused with other representations.
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::reduced_date("2015-11").unwrap();
+/// ```
+pub fn reduced_date(string: &str) -> Result<ReducedDate, ParseError> {
+    error::prefix(
+        Component::ReducedDate,
+        string.as_bytes(),
+        parsers::parse_reduced_date(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Parses a week string.
+///
+/// A string can have one of the following formats:
+///
+/// * `2015-W45` or `2015W45`
+///
+/// ## Example
+///
+/// ```rust
+/// let week = iso8601::week("2015-W45").unwrap();
+/// let monday = week.first_day();
+/// ```
+pub fn week(string: &str) -> Result<Week, ParseError> {
+    error::prefix(
+        Component::Week,
+        string.as_bytes(),
+        parsers::parse_week(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Parses a truncated date string without a year.
+///
+/// These representations were removed from ISO 8601 with its 2004 revision,
+/// but are still in use, like for birthdays in vCards.
+/// A string can have one of the following formats:
+///
+/// * `--02-08` or `--0208`, a day of a month
+/// * `--02`, a month
+/// * `---08`, a day of a month
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::truncated_date("--02-08").unwrap();
+/// ```
+pub fn truncated_date(string: &str) -> Result<TruncatedDate, ParseError> {
+    error::prefix(
+        Component::TruncatedDate,
+        string.as_bytes(),
+        parsers::parse_truncated_date(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
 }
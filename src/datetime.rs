@@ -1,35 +1,996 @@
-use alloc::string::String;
-use core::str::FromStr;
+use alloc::vec::Vec;
+use core::{cmp::Ordering, ops::Deref, str::FromStr};
 
-use crate::{parsers, Date, Time};
+use crate::{
+    calendar, error, parsers, Component, Date, DateError, Duration, ErrorKind, Expected,
+    LeapSecondPolicy, OverflowPolicy, ParseError, Precision, Time, TimeBuilder, TimeError, Unit,
+    UtcOffset, ValidDate,
+};
 
-/// Compound struct, holds Date and Time.
+legacy_fields! {
+    /// Compound struct, holds Date and Time.
+    ///
+    /// Build one with [`DateTime::new()`] or [`DateTime::builder()`],
+    /// and read it with [`DateTime::date()`] and [`DateTime::time()`].
+    /// The fields are only public with the default `legacy-fields` feature.
+    /// ```
+    /// # use std::str::FromStr;
+    /// let dt = iso8601::DateTime::from_str("2023-02-18T17:08:08.793Z").unwrap();
+    /// assert_eq!(dt.date(), iso8601::Date::YMD{ year: 2023, month: 2, day: 18});
+    /// assert_eq!(
+    ///     dt.time(),
+    ///     iso8601::Time::builder().hour(17).minute(8).second(8).millisecond(793).utc().build().unwrap()
+    /// );
+    /// ```
+    #[derive(Eq, PartialEq, Debug, Copy, Clone, Default, Hash)]
+    pub struct DateTime {
+        /// The date part
+        date: Date,
+        /// The time part
+        time: Time,
+    }
+}
+
+impl DateTime {
+    /// The earliest datetime, the start of [`Date::MIN`] without an offset.
+    pub const MIN: DateTime = DateTime {
+        date: Date::MIN,
+        time: Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second,
+        },
+    };
+
+    /// The latest datetime, the last nanosecond of [`Date::MAX`] without an offset.
+    pub const MAX: DateTime = DateTime {
+        date: Date::MAX,
+        time: Time {
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanosecond: 999_999_999,
+            fraction_digits: 9,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction,
+        },
+    };
+
+    /// A builder for a datetime, which checks the components once they are all set.
+    ///
+    /// ```
+    /// let dt = iso8601::DateTime::builder()
+    ///     .ymd(2023, 2, 8)
+    ///     .hour(23)
+    ///     .minute(40)
+    ///     .utc()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(dt, iso8601::datetime("2023-02-08T23:40:00Z").unwrap());
+    /// ```
+    pub fn builder() -> DateTimeBuilder {
+        DateTimeBuilder::default()
+    }
+
+    /// Combines a date and a time, like those built with [`Date::from_ymd()`] and [`Time::new()`].
+    pub fn new(date: Date, time: Time) -> DateTime {
+        DateTime { date, time }
+    }
+
+    /// Combines a date and a time parsed on their own, like from separate columns,
+    /// undoing [`DateTime::into_parts()`].
+    ///
+    /// ```
+    /// use iso8601::DateTime;
+    ///
+    /// let parts = (iso8601::date("2023-02-08").unwrap(), iso8601::time("23:40:00Z").unwrap());
+    /// let dt = DateTime::from_parts(parts);
+    /// assert_eq!(dt, iso8601::datetime("2023-02-08T23:40:00Z").unwrap());
+    /// assert_eq!(dt.into_parts(), parts);
+    /// ```
+    pub fn from_parts((date, time): (Date, Time)) -> DateTime {
+        DateTime { date, time }
+    }
+
+    /// Splits this datetime into its date and its time.
+    pub fn into_parts(self) -> (Date, Time) {
+        (self.date, self.time)
+    }
+
+    /// The date part.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// The time part.
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// All reasons why this datetime would not be parsed, empty if there are none,
+    /// see [`Date::violations()`] and [`Time::violations()`].
+    ///
+    /// ```
+    /// use iso8601::{Date, DateTime, ErrorKind, Time};
+    ///
+    /// let dt = DateTime::new(Date::from_ymd(2023, 2, 8).unwrap(), Time::new(23, 40, 0, 0).unwrap());
+    /// assert_eq!(dt.violations(), vec![]);
+    ///
+    /// let dt = DateTime::new(
+    ///     Date::Week { year: 2023, ww: 54, d: 3 },
+    ///     dt.time().set_offset(iso8601::UtcOffset::Fixed { seconds: -90_000 }),
+    /// );
+    /// assert_eq!(dt.violations(), vec![ErrorKind::InvalidWeek, ErrorKind::OffsetOutOfRange]);
+    /// ```
+    pub fn violations(&self) -> Vec<ErrorKind> {
+        let mut violations = self.date.violations();
+        violations.extend(self.time.violations());
+        violations
+    }
+
+    /// Whether the date of this datetime exists in the calendar, see [`Date::is_valid()`].
+    pub fn is_valid(&self) -> bool {
+        self.date.is_valid()
+    }
+
+    /// Checks that the date of this datetime exists in the calendar, and marks it as such.
+    ///
+    /// ```
+    /// let dt = iso8601::datetime("2024-02-29T23:40:00Z").unwrap().validate().unwrap();
+    /// assert_eq!(dt.time().hour(), 23);
+    /// assert!(iso8601::datetime("2023-02-29T23:40:00Z").unwrap().validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<ValidDateTime, DateError> {
+        self.date.validate()?;
+        Ok(ValidDateTime(*self))
+    }
+
+    /// The smallest component this datetime was written with, which is that of its time,
+    /// like [`Precision::Minute`] for `2023-02-08T23:40` or [`Precision::Fraction`]
+    /// for `2023-02-08T23:40:00.5`.
+    pub fn precision(&self) -> Precision {
+        self.time.precision
+    }
+
+    /// The offset from UTC as written, which tells `Z`, a numeric zero offset like `+00:00`
+    /// and a missing offset apart.
+    ///
+    /// ```
+    /// use iso8601::UtcOffset;
+    ///
+    /// let offset = |s| iso8601::datetime(s).unwrap().offset();
+    /// assert_eq!(offset("2023-02-08T23:40:00Z"), UtcOffset::Utc);
+    /// assert_eq!(offset("2023-02-08T23:40:00+00:00"), UtcOffset::Fixed { seconds: 0 });
+    /// assert_eq!(offset("2023-02-08T23:40:00"), UtcOffset::Unspecified);
+    /// ```
+    pub fn offset(&self) -> UtcOffset {
+        self.time.offset
+    }
+
+    /// The offset in seconds east of UTC, or `None` if the datetime was written without one,
+    /// see [`Time::offset_seconds()`].
+    pub fn offset_seconds(&self) -> Option<i32> {
+        self.time.offset_seconds()
+    }
+
+    /// Rolls the end of a day `24:00:00` over to the start of the next day `00:00:00`.
+    ///
+    /// The date is advanced by one day if needed, keeping its format.
+    /// All other datetimes are returned unchanged,
+    /// as is the end of [`Date::MAX`], which has no next day.
+    ///
+    /// ```
+    /// let dt = iso8601::datetime("2023-02-28T24:00:00Z").unwrap();
+    /// assert_eq!(dt.normalize_midnight(), iso8601::datetime("2023-03-01T00:00:00Z").unwrap());
+    /// ```
+    pub fn normalize_midnight(&self) -> DateTime {
+        match self.time.normalize_midnight() {
+            (time, 0) => DateTime {
+                date: self.date,
+                time,
+            },
+            (time, _) => match self.date.succ() {
+                Some(date) => DateTime { date, time },
+                None => *self,
+            },
+        }
+    }
+
+    /// This datetime with the components smaller than `unit` set to zero,
+    /// like the start of its hour or month, keeping its format and offset.
+    ///
+    /// The date goes back to the first day of its week, month or year for those units,
+    /// where the year of a week date is its week-numbering year,
+    /// and the time is truncated like [`Time::truncate_to()`].
+    /// The end of a day `24:00:00` is the start of the next one for days and larger units,
+    /// see [`DateTime::normalize_midnight()`].
+    ///
+    /// ```
+    /// use iso8601::Unit;
+    ///
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// let truncated = |s, unit| dt(s).truncate_to(unit).to_string();
+    /// assert_eq!(truncated("2023-02-08T23:40:12Z", Unit::Hour), "2023-02-08T23Z");
+    /// assert_eq!(truncated("2023-02-08T23:40:12Z", Unit::Week), "2023-02-06T00Z");
+    /// assert_eq!(truncated("2023-02-08T23:40:12Z", Unit::Month), "2023-02-01T00Z");
+    /// assert_eq!(truncated("2023-W06-3T23:40:12Z", Unit::Year), "2023-W01-1T00Z");
+    /// ```
+    pub fn truncate_to(&self, unit: Unit) -> DateTime {
+        let dt = match unit {
+            Unit::Hour | Unit::Minute | Unit::Second => *self,
+            Unit::Day | Unit::Week | Unit::Month | Unit::Year => self.normalize_midnight(),
+        };
+        DateTime {
+            date: dt.date.truncate_to(unit),
+            time: dt.time.truncate_to(unit),
+        }
+    }
+
+    /// This datetime rounded to a whole `unit` by `mode`, keeping its format and offset,
+    /// like to the nearest hour or up to the next day.
+    ///
+    /// Rounding to seconds rounds away the fraction of a second.
+    /// Weeks start on Monday, and the years of week dates are their week-numbering years,
+    /// like for [`DateTime::truncate_to()`].
+    /// A leap second counts as the first second of the next minute,
+    /// and the end of a day `24:00:00` as the start of the next.
+    ///
+    /// Returns `None` for datetimes that do not exist or have a time out of range,
+    /// see [`DateTime::violations()`], and if rounding goes past [`Date::MAX`].
+    ///
+    /// ```
+    /// use iso8601::{RoundingMode, Unit};
+    ///
+    /// let dt = iso8601::datetime("2023-02-08T23:40:12.5Z").unwrap();
+    /// let rounded = |unit, mode| dt.round_to(unit, mode).map(|dt| dt.to_string());
+    /// assert_eq!(rounded(Unit::Second, RoundingMode::Nearest), Some("2023-02-08T23:40:13Z".into()));
+    /// assert_eq!(rounded(Unit::Hour, RoundingMode::Floor), Some("2023-02-08T23Z".into()));
+    /// assert_eq!(rounded(Unit::Day, RoundingMode::Nearest), Some("2023-02-09T00Z".into()));
+    /// assert_eq!(rounded(Unit::Month, RoundingMode::Ceil), Some("2023-03-01T00Z".into()));
+    /// ```
+    pub fn round_to(&self, unit: Unit, mode: RoundingMode) -> Option<DateTime> {
+        self.round_to_increment(1, unit, mode)
+    }
+
+    /// Like [`DateTime::round_to()`], to a multiple of `increment` units,
+    /// like to a quarter of an hour.
+    ///
+    /// The multiples are counted from the start of the next larger unit,
+    /// which `increment` has to divide: 60 seconds or minutes, 24 hours and 12 months.
+    /// Years are counted from the year `0` and can have any increment but `0`,
+    /// while days and weeks can only have an increment of `1`.
+    /// Returns `None` for other increments as well.
+    ///
+    /// ```
+    /// use iso8601::{RoundingMode, Unit};
+    ///
+    /// let dt = iso8601::datetime("2023-02-08T23:40:12Z").unwrap();
+    /// let rounded = |increment, unit| dt.round_to_increment(increment, unit, RoundingMode::Nearest);
+    /// assert_eq!(rounded(15, Unit::Minute), Some(iso8601::datetime("2023-02-08T23:45Z").unwrap()));
+    /// assert_eq!(rounded(3, Unit::Month), Some(iso8601::datetime("2023-01-01T00Z").unwrap()));
+    /// assert_eq!(rounded(7, Unit::Minute), None);
+    /// ```
+    pub fn round_to_increment(
+        &self,
+        increment: u32,
+        unit: Unit,
+        mode: RoundingMode,
+    ) -> Option<DateTime> {
+        const SECOND: i128 = 1_000_000_000;
+        const DAY: i128 = 86_400 * SECOND;
+        if self.validate().is_err() || !self.time.violations().is_empty() {
+            return None;
+        }
+        let (hour, minute, second) = (self.time.hour, self.time.minute, self.time.second);
+        // the nanoseconds since 1970-01-01T00:00:00 on the clock of this datetime
+        let local = i128::from(self.date.days_since_epoch()) * DAY
+            + (i128::from(hour) * 3600 + i128::from(minute) * 60 + i128::from(second)) * SECOND
+            + i128::from(self.time.nanosecond);
+
+        // the multiples before and after this datetime, where the one after
+        // is `None` if it would have a year that an `i32` does not hold
+        let (floor, ceil) = match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Day | Unit::Week => {
+                let (length, within, origin) = match unit {
+                    Unit::Second => (SECOND, 60, 0),
+                    Unit::Minute => (60 * SECOND, 60, 0),
+                    Unit::Hour => (3600 * SECOND, 24, 0),
+                    Unit::Day => (DAY, 1, 0),
+                    // 1969-12-29 was a Monday
+                    _ => (7 * DAY, 1, -3 * DAY),
+                };
+                if increment == 0 || within % increment != 0 {
+                    return None;
+                }
+                let length = length * i128::from(increment);
+                let floor = origin + (local - origin).div_euclid(length) * length;
+                (floor, Some(floor + length))
+            }
+            Unit::Month | Unit::Year => {
+                let months = match (unit, self.date) {
+                    (Unit::Year, Date::Week { year, .. }) => i64::from(year) * 12,
+                    (Unit::Year, date) => i64::from(date.year()) * 12,
+                    (_, date) => i64::from(date.year()) * 12 + i64::from(date.month()) - 1,
+                };
+                let length = match unit {
+                    Unit::Month if increment != 0 && 12 % increment == 0 => i64::from(increment),
+                    Unit::Year if increment != 0 => i64::from(increment) * 12,
+                    _ => return None,
+                };
+                // the first day of a number of months since the year 0,
+                // or of a week-numbering year for week dates
+                let first = |months: i64| {
+                    let year = i32::try_from(months.div_euclid(12)).ok()?;
+                    let month = months.rem_euclid(12) as u32 + 1;
+                    let date = match self.date {
+                        Date::Week { .. } if unit == Unit::Year => Date::Week { year, ww: 1, d: 1 },
+                        _ => Date::YMD {
+                            year,
+                            month,
+                            day: 1,
+                        },
+                    };
+                    Some(i128::from(date.days_since_epoch()) * DAY)
+                };
+                let months = months.div_euclid(length) * length;
+                (first(months)?, first(months + length))
+            }
+        };
+
+        let rounded = match mode {
+            _ if local == floor => floor,
+            RoundingMode::Floor => floor,
+            RoundingMode::Ceil => ceil?,
+            RoundingMode::Nearest => {
+                let ceil = ceil?;
+                if local - floor < ceil - local {
+                    floor
+                } else {
+                    ceil
+                }
+            }
+        };
+        let (time, days) = self.time.add_nanoseconds(rounded - local);
+        let date = self.date.add_days(days.try_into().ok()?).ok()?;
+        // a multiple of `unit` is written like one truncated to it
+        let time = time.truncate_to(unit);
+        Some(DateTime { date, time })
+    }
+}
+
+/// How [`DateTime::round_to()`] rounds a datetime between two multiples of a unit.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum RoundingMode {
+    /// Round to the nearer one, and to the later one halfway between them.
+    #[default]
+    Nearest,
+    /// Round down to the earlier one.
+    Floor,
+    /// Round up to the later one.
+    Ceil,
+}
+
+impl DateTime {
+    /// Applies `policy` to a leap second, see [`LeapSecondPolicy`] for the available choices.
+    ///
+    /// Carrying a leap second at the end of a day advances the date, keeping its format.
+    /// Returns `None` if the policy rejects this datetime,
+    /// or if carrying it would go past [`Date::MAX`].
+    /// Datetimes without a leap second are returned unchanged.
+    ///
+    /// ```
+    /// use iso8601::LeapSecondPolicy;
+    ///
+    /// let dt = iso8601::datetime("2016-12-31T23:59:60Z").unwrap();
+    /// assert_eq!(
+    ///     dt.resolve_leap_second(LeapSecondPolicy::Carry),
+    ///     Some(iso8601::datetime("2017-01-01T00:00:00Z").unwrap())
+    /// );
+    /// ```
+    pub fn resolve_leap_second(&self, policy: LeapSecondPolicy) -> Option<DateTime> {
+        match self.time.resolve_leap_second(policy)? {
+            (time, 0) => Some(DateTime {
+                date: self.date,
+                time,
+            }),
+            (time, _) => Some(DateTime {
+                date: self.date.succ()?,
+                time,
+            }),
+        }
+    }
+}
+
+impl DateTime {
+    /// This datetime moved by a duration, or `None` if that is not possible.
+    ///
+    /// Years and months are added to the date by the calendar first,
+    /// taking the last day of the month for days it does not have,
+    /// like [`OverflowPolicy::Constrain`], so `2023-01-31T12:00` plus `P1M` is `2023-02-28T12:00`.
+    /// Weeks, days, hours, minutes, seconds and their fractions are added exactly after that,
+    /// with each day 24 hours long, as the offset stays the same.
+    /// The date keeps its format, and the time shows at least the components it needs.
+    ///
+    /// Returns `None` for dates that do not exist, times with [`Time::violations()`],
+    /// fractions of years or months, which have no fixed length,
+    /// and results before [`Date::MIN`] or after [`Date::MAX`].
+    /// A leap second counts as the first second of the next minute, like in
+    /// [`DateTime::cmp_instant()`], and the end of a day `24:00:00` as the start of the next.
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(
+    ///     dt("2023-01-31T12:00:00+01:00").checked_add(&duration("P1M")),
+    ///     Some(dt("2023-02-28T12:00:00+01:00"))
+    /// );
+    /// assert_eq!(
+    ///     dt("2023-02-28T23:40:00Z").checked_add(&duration("PT1H30M")),
+    ///     Some(dt("2023-03-01T01:10:00Z"))
+    /// );
+    /// assert_eq!(dt("2023-02-08T23:40:00Z").checked_add(&duration("P0.5M")), None);
+    /// ```
+    pub fn checked_add(&self, duration: &Duration) -> Option<DateTime> {
+        self.add_duration(duration, false)
+    }
+
+    /// This datetime moved back by a duration, like [`DateTime::checked_add()`]
+    /// with the negated duration.
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// assert_eq!(
+    ///     dt("2024-03-31T00:30:00Z").checked_sub(&iso8601::duration("P1MT1H").unwrap()),
+    ///     Some(dt("2024-02-28T23:30:00Z"))
+    /// );
+    /// ```
+    pub fn checked_sub(&self, duration: &Duration) -> Option<DateTime> {
+        self.checked_add(&-*duration)
+    }
+
+    /// Like [`DateTime::checked_add()`], but stopping at the start of the first day
+    /// or the end of the last day that can be written in the format of the date,
+    /// keeping the offset, instead of going out of range.
+    ///
+    /// Datetimes that [`DateTime::checked_add()`] fails for otherwise
+    /// are returned unchanged.
+    ///
+    /// ```
+    /// use iso8601::Date;
+    ///
+    /// let dt = iso8601::datetime("2023-02-08T23:40:00Z").unwrap();
+    /// let end = dt.saturating_add(&iso8601::duration("P9223372036854775807D").unwrap());
+    /// assert_eq!(end.date(), Date::MAX);
+    /// assert_eq!(end.to_string(), "+2147483647-12-31T23:59:59.999999999Z");
+    /// ```
+    pub fn saturating_add(&self, duration: &Duration) -> DateTime {
+        self.add_duration(duration, true).unwrap_or(*self)
+    }
+
+    /// Like [`DateTime::checked_sub()`], but stopping at the bounds like
+    /// [`DateTime::saturating_add()`].
+    pub fn saturating_sub(&self, duration: &Duration) -> DateTime {
+        self.saturating_add(&-*duration)
+    }
+
+    // This datetime moved by a duration, or `None` if that is not possible,
+    // where going out of range ends at the first or last day with `saturate`
+    fn add_duration(&self, duration: &Duration, saturate: bool) -> Option<DateTime> {
+        self.validate().ok()?;
+        if !self.time.violations().is_empty() {
+            return None;
+        }
+        let edge = |backwards: bool| {
+            let bound = if backwards {
+                DateTime::MIN
+            } else {
+                DateTime::MAX
+            };
+            saturate.then(|| DateTime {
+                date: self.date.edge(!backwards),
+                time: bound.time.set_offset(self.time.offset),
+            })
+        };
+
+        let (years, months, fraction) = duration.calendar_units();
+        if fraction.is_some() {
+            return None;
+        }
+        // an overflow needs years and months of the same sign
+        let months = match years.checked_mul(12).and_then(|y| y.checked_add(months)) {
+            Some(months) => months,
+            None => return edge(years < 0),
+        };
+        let date = match self.date.add_months(months, OverflowPolicy::Constrain) {
+            Ok(date) => date,
+            Err(_) => return edge(months < 0),
+        };
+
+        let (time, days) = self.time.add_nanoseconds(duration.exact_nanoseconds());
+        match i64::try_from(days).map(|days| date.add_days(days)) {
+            Ok(Ok(date)) => Some(DateTime { date, time }),
+            _ => edge(days < 0),
+        }
+    }
+}
+
+/// Orders datetimes by the instant they denote, taking those without an offset to be in UTC
+/// like [`MissingOffsetPolicy::AssumeUtc`](crate::MissingOffsetPolicy::AssumeUtc).
+///
+/// Datetimes of the same instant, like `2023-02-08T23:40:00Z` and `2023-02-09T00:40:00+01:00`,
+/// are further ordered by their dates and times, as they are not equal.
+/// See [`DateTime::cmp_instant()`] to compare only their instants.
+///
 /// ```
-/// # use std::str::FromStr;
-/// assert_eq!(
-///     iso8601::DateTime::from_str("2023-02-18T17:08:08.793Z"),
-///     Ok(iso8601::DateTime {
-///         date: iso8601::Date::YMD{ year: 2023, month: 2, day: 18},
-///         time: iso8601::Time{ hour: 17, minute: 8, second: 8, millisecond: 793, tz_offset_hours: 0, tz_offset_minutes: 00 }
-///     })
-/// )
+/// use std::collections::BTreeMap;
+///
+/// let mut events = BTreeMap::new();
+/// events.insert(iso8601::datetime("2023-02-09T00:30:00+01:00").unwrap(), "second");
+/// events.insert(iso8601::datetime("2023-02-08T23:40:00Z").unwrap(), "third");
+/// events.insert(iso8601::datetime("2023-02-08T18:00:00-05:00").unwrap(), "first");
+/// assert_eq!(events.into_values().collect::<Vec<_>>(), ["first", "second", "third"]);
 /// ```
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.instant(), &self.date, &self.time).cmp(&(other.instant(), &other.date, &other.time))
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl DateTime {
+    /// Compares the instants of both datetimes, whatever their offsets and date formats,
+    /// taking those without an offset to be in UTC.
+    ///
+    /// Unlike [`Ord`], this finds the same instant written differently equal.
+    /// As there is no table of leap seconds, a leap second like `23:59:60Z`
+    /// is the same instant as the start of the next day.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// assert_eq!(
+    ///     dt("2023-02-08T23:40:00+01:00").cmp_instant(&dt("2023-02-08T22:40:00Z")),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(
+    ///     dt("2023-02-08T23:40:00+01:00").cmp_instant(&dt("2023-02-08T23:00:00Z")),
+    ///     Ordering::Less
+    /// );
+    /// ```
+    pub fn cmp_instant(&self, other: &DateTime) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+
+    /// Whether both datetimes are the same existing instant, whatever their offsets and
+    /// date formats, see [`DateTime::cmp_instant()`].
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// assert!(dt("2023-02-08T23:40:00+01:00").same_instant(&dt("2023-02-08T22:40:00Z")));
+    /// assert!(dt("2023-W06-3T24:00:00Z").same_instant(&dt("2023-02-09T00:00:00Z")));
+    /// assert!(!dt("2023-02-30T00:00:00Z").same_instant(&dt("2023-03-02T00:00:00Z")));
+    /// ```
+    pub fn same_instant(&self, other: &DateTime) -> bool {
+        self.is_valid() && other.is_valid() && self.cmp_instant(other) == Ordering::Equal
+    }
+
+    /// The time elapsed from `other` to this datetime, negative if `other` is later.
+    ///
+    /// The duration is exact, in hours, minutes, seconds and milliseconds,
+    /// as days, months and years differ in length.
+    /// It is measured between the instants of both datetimes like [`DateTime::cmp_instant()`],
+    /// so across their offsets, and truncated to whole milliseconds.
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// let elapsed = dt("2023-02-09T01:10:00.25+01:00").since(&dt("2023-02-08T22:40:00Z"));
+    /// assert_eq!(elapsed, iso8601::duration("PT1H30M0.25S").unwrap());
+    /// assert_eq!(dt("2023-02-08T22:40:00Z").since(&dt("2023-02-10T00:00:00Z")).to_string(), "-PT25H20M");
+    /// ```
+    pub fn since(&self, other: &DateTime) -> Duration {
+        // the difference of two instants is less than 2^48 hours, so each unit fits an `i64`
+        let milliseconds = (self.instant() - other.instant()) / 1_000_000;
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: (milliseconds / 3_600_000) as i64,
+            minute: (milliseconds / 60_000 % 60) as i64,
+            second: (milliseconds / 1000 % 60) as i64,
+            millisecond: (milliseconds % 1000) as i64,
+            fraction: None,
+        }
+    }
+
+    /// The same instant as this datetime at another offset,
+    /// with the date and time moved to its clock and in the format of this one.
+    ///
+    /// Datetimes without an offset are taken to be in UTC, like in [`DateTime::cmp_instant()`],
+    /// and `offset` has the clock of UTC if it is not [`UtcOffset::Fixed`].
+    /// A leap second stays one for offsets in whole minutes,
+    /// and is the first second of the next minute otherwise.
+    ///
+    /// Returns `None` for datetimes that do not exist or have a time out of range,
+    /// see [`DateTime::violations()`], and if the date would go past [`Date::MIN`] or [`Date::MAX`].
+    ///
+    /// ```
+    /// use iso8601::UtcOffset;
+    ///
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// let ist = UtcOffset::Fixed { seconds: 5 * 3600 + 1800 };
+    /// assert_eq!(dt("2023-02-08T23:40:00Z").with_offset(ist), Some(dt("2023-02-09T05:10:00+05:30")));
+    /// assert_eq!(dt("2023-02-09T05:10:00+05:30").with_offset(UtcOffset::Utc), Some(dt("2023-02-08T23:40:00Z")));
+    /// assert_eq!(dt("2016-12-31T23:59:60Z").with_offset(ist), Some(dt("2017-01-01T05:29:60+05:30")));
+    /// ```
+    pub fn with_offset(&self, offset: UtcOffset) -> Option<DateTime> {
+        if self.validate().is_err() || !self.time.violations().is_empty() {
+            return None;
+        }
+        let seconds =
+            i128::from(offset.offset_seconds()) - i128::from(self.time.offset.offset_seconds());
+        let leap_second = self.time.second == 60 && seconds % 60 == 0;
+
+        let mut time = self.time;
+        if leap_second {
+            time.second = 59;
+        }
+        let (mut time, days) = time.add_nanoseconds(seconds * 1_000_000_000);
+        if leap_second {
+            time.second = 60;
+        }
+        time.offset = offset;
+        let date = self.date.add_days(days.try_into().ok()?).ok()?;
+        Some(DateTime { date, time })
+    }
+
+    /// The Unix timestamp of this datetime, in whole seconds since `1970-01-01T00:00:00Z`
+    /// and the nanoseconds after those.
+    ///
+    /// The seconds are rounded down, so times before 1970 have negative seconds
+    /// and still positive nanoseconds, like `(-1, 500_000_000)` for half a second before.
+    /// Datetimes without an offset are taken to be in UTC, like in [`DateTime::cmp_instant()`],
+    /// and a leap second is the first second of the next minute, as Unix time has none.
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// assert_eq!(dt("2023-02-08T23:40:00.5+01:00").to_unix(), (1_675_896_000, 500_000_000));
+    /// assert_eq!(dt("1969-12-31T23:59:59.5Z").to_unix(), (-1, 500_000_000));
+    /// ```
+    pub fn to_unix(&self) -> (i64, u32) {
+        let instant = self.instant();
+        let seconds = instant.div_euclid(1_000_000_000);
+        // the nanoseconds are less than a second, and the seconds of any datetime fit an `i64`,
+        // but for those built with components out of range
+        (
+            seconds.clamp(i64::MIN.into(), i64::MAX.into()) as i64,
+            instant.rem_euclid(1_000_000_000) as u32,
+        )
+    }
+
+    /// The datetime of a Unix timestamp, in seconds since `1970-01-01T00:00:00Z`
+    /// and the nanoseconds after those, written in the calendar format at `offset`.
+    ///
+    /// Returns `None` for a billion nanoseconds or more,
+    /// and for timestamps before [`Date::MIN`] or after [`Date::MAX`] at that offset.
+    ///
+    /// ```
+    /// use iso8601::{DateTime, UtcOffset};
+    ///
+    /// let dt = DateTime::from_unix(1_675_896_000, 500_000_000, UtcOffset::Fixed { seconds: 3600 });
+    /// assert_eq!(dt, Some(iso8601::datetime("2023-02-08T23:40:00.5+01:00").unwrap()));
+    /// let dt = DateTime::from_unix(-1, 0, UtcOffset::Utc);
+    /// assert_eq!(dt, Some(iso8601::datetime("1969-12-31T23:59:59Z").unwrap()));
+    /// ```
+    pub fn from_unix(seconds: i64, nanoseconds: u32, offset: UtcOffset) -> Option<DateTime> {
+        if nanoseconds >= 1_000_000_000 {
+            return None;
+        }
+        let midnight = Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset,
+            precision: Precision::Second,
+        };
+        let local = i128::from(seconds) + i128::from(offset.offset_seconds());
+        let (time, days) =
+            midnight.add_nanoseconds(local * 1_000_000_000 + i128::from(nanoseconds));
+
+        let epoch = Date::YMD {
+            year: 1970,
+            month: 1,
+            day: 1,
+        };
+        let date = epoch.add_days(i64::try_from(days).ok()?).ok()?;
+        Some(DateTime { date, time })
+    }
+
+    /// The Modified Julian Date of this datetime, the days since `1858-11-17T00:00:00Z`
+    /// with the time of day as a fraction of a day.
+    ///
+    /// Datetimes without an offset are taken to be in UTC, and a leap second is
+    /// the first second of the next minute, like in [`DateTime::to_unix()`].
+    /// An `f64` holds the time of day to about a microsecond for dates of these centuries.
+    ///
+    /// ```
+    /// let dt = |s| iso8601::datetime(s).unwrap();
+    /// assert_eq!(dt("2023-02-08T18:00:00Z").to_modified_julian_date(), 59_983.75);
+    /// assert_eq!(dt("2023-02-08T18:00:00+06:00").to_modified_julian_date(), 59_983.5);
+    /// ```
+    pub fn to_modified_julian_date(&self) -> f64 {
+        const DAY: i128 = 86_400 * 1_000_000_000;
+        let instant = self.instant();
+        let days = instant.div_euclid(DAY) + i128::from(calendar::MODIFIED_JULIAN_DAY_OF_EPOCH);
+        days as f64 + instant.rem_euclid(DAY) as f64 / DAY as f64
+    }
+
+    /// The datetime of a Modified Julian Date, undoing [`DateTime::to_modified_julian_date()`],
+    /// written in the calendar format at `offset`.
+    ///
+    /// The time of day is rounded to whole microseconds, about what an `f64` holds.
+    /// Returns `None` for dates that are not finite,
+    /// and for those before [`Date::MIN`] or after [`Date::MAX`] at that offset.
+    ///
+    /// ```
+    /// use iso8601::{DateTime, UtcOffset};
+    ///
+    /// let dt = DateTime::from_modified_julian_date(59_983.75, UtcOffset::Utc);
+    /// assert_eq!(dt, Some(iso8601::datetime("2023-02-08T18:00:00Z").unwrap()));
+    /// ```
+    pub fn from_modified_julian_date(date: f64, offset: UtcOffset) -> Option<DateTime> {
+        const MICROSECONDS: f64 = 86_400_000_000.0;
+        // far beyond the range of dates, and of the integers below
+        if !date.is_finite() || !(-1e15..=1e15).contains(&date) {
+            return None;
+        }
+
+        // rounded down, as casting rounds towards zero
+        let mut days = date as i64;
+        if days as f64 > date {
+            days -= 1;
+        }
+        let microseconds = ((date - days as f64) * MICROSECONDS + 0.5) as i128;
+        let days = i128::from(days - calendar::MODIFIED_JULIAN_DAY_OF_EPOCH);
+        let unix = days * 86_400_000_000 + microseconds;
+        let nanoseconds = (unix.rem_euclid(1_000_000) * 1000) as u32;
+        let seconds = i64::try_from(unix.div_euclid(1_000_000)).ok()?;
+        DateTime::from_unix(seconds, nanoseconds, offset)
+    }
+
+    // The nanoseconds since 1970-01-01T00:00:00Z, where a leap second is the next day's first
+    fn instant(&self) -> i128 {
+        let seconds = i128::from(self.date.days_since_epoch()) * 86_400
+            + i128::from(self.time.hour) * 3600
+            + i128::from(self.time.minute) * 60
+            + i128::from(self.time.second)
+            - i128::from(self.time.offset.offset_seconds());
+        seconds * 1_000_000_000 + i128::from(self.time.nanosecond)
+    }
+}
+
+/// Why a [`DateTime`] could not be built by [`DateTimeBuilder::build()`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum DateTimeError {
+    /// No date was set
+    MissingDate,
+    /// The date does not exist
+    Date(DateError),
+    /// The time is out of range
+    Time(TimeError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateTimeError {}
+
+impl From<DateError> for DateTimeError {
+    fn from(e: DateError) -> Self {
+        DateTimeError::Date(e)
+    }
+}
+
+impl From<TimeError> for DateTimeError {
+    fn from(e: TimeError) -> Self {
+        DateTimeError::Time(e)
+    }
+}
+
+/// Builds a [`DateTime`] one component at a time, see [`DateTime::builder()`].
+///
+/// The date has to be set, the time is built like with a [`TimeBuilder`].
+/// Nothing is checked before [`DateTimeBuilder::build()`].
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
-pub struct DateTime {
-    /// The date part
-    pub date: Date,
-    /// The time part
-    pub time: Time,
+pub struct DateTimeBuilder {
+    date: Option<Date>,
+    time: TimeBuilder,
+}
+
+impl DateTimeBuilder {
+    /// Sets the date.
+    pub fn date(self, date: Date) -> DateTimeBuilder {
+        DateTimeBuilder {
+            date: Some(date),
+            ..self
+        }
+    }
+
+    /// Sets the date to a calendar date, see [`Date::from_ymd()`].
+    pub fn ymd(self, year: i32, month: u32, day: u32) -> DateTimeBuilder {
+        self.date(Date::YMD { year, month, day })
+    }
+
+    /// Sets the date to a week date, see [`Date::from_iso_week()`].
+    pub fn iso_week(self, year: i32, ww: u32, d: u32) -> DateTimeBuilder {
+        self.date(Date::Week { year, ww, d })
+    }
+
+    /// Sets the date to an ordinal date, see [`Date::from_ordinal()`].
+    pub fn ordinal(self, year: i32, ddd: u32) -> DateTimeBuilder {
+        self.date(Date::Ordinal { year, ddd })
+    }
+
+    /// Sets the hour, see [`TimeBuilder::hour()`].
+    pub fn hour(self, hour: u32) -> DateTimeBuilder {
+        self.with_time(|time| time.hour(hour))
+    }
+
+    /// Sets the minute, see [`TimeBuilder::minute()`].
+    pub fn minute(self, minute: u32) -> DateTimeBuilder {
+        self.with_time(|time| time.minute(minute))
+    }
+
+    /// Sets the second, see [`TimeBuilder::second()`].
+    pub fn second(self, second: u32) -> DateTimeBuilder {
+        self.with_time(|time| time.second(second))
+    }
+
+    /// Sets the fraction of a second, see [`TimeBuilder::nanosecond()`].
+    pub fn nanosecond(self, nanosecond: u32) -> DateTimeBuilder {
+        self.with_time(|time| time.nanosecond(nanosecond))
+    }
+
+    /// Sets the fraction of a second, see [`TimeBuilder::millisecond()`].
+    pub fn millisecond(self, millisecond: u32) -> DateTimeBuilder {
+        self.with_time(|time| time.millisecond(millisecond))
+    }
+
+    /// Sets the offset from UTC, see [`TimeBuilder::offset()`].
+    pub fn offset(self, offset: UtcOffset) -> DateTimeBuilder {
+        self.with_time(|time| time.offset(offset))
+    }
+
+    /// Sets the offset from UTC to `Z`, see [`TimeBuilder::utc()`].
+    pub fn utc(self) -> DateTimeBuilder {
+        self.with_time(TimeBuilder::utc)
+    }
+
+    /// Sets the offset from UTC, see [`TimeBuilder::offset_hm()`].
+    pub fn offset_hm(self, hours: i32, minutes: i32) -> DateTimeBuilder {
+        self.with_time(|time| time.offset_hm(hours, minutes))
+    }
+
+    /// Sets the offset from UTC, see [`TimeBuilder::offset_hms()`].
+    pub fn offset_hms(self, hours: i32, minutes: i32, seconds: i32) -> DateTimeBuilder {
+        self.with_time(|time| time.offset_hms(hours, minutes, seconds))
+    }
+
+    fn with_time(self, set: impl FnOnce(TimeBuilder) -> TimeBuilder) -> DateTimeBuilder {
+        DateTimeBuilder {
+            time: set(self.time),
+            ..self
+        }
+    }
+
+    /// Builds the datetime, checking that the date exists, see [`Date::validate()`],
+    /// and the time like [`TimeBuilder::build()`].
+    ///
+    /// ```
+    /// use iso8601::{DateError, DateTime, DateTimeError, TimeError};
+    ///
+    /// let builder = DateTime::builder().hour(23).minute(40);
+    /// assert_eq!(builder.build(), Err(DateTimeError::MissingDate));
+    /// assert_eq!(builder.ymd(2023, 2, 29).build(), Err(DateTimeError::Date(DateError::Day)));
+    /// assert_eq!(
+    ///     builder.iso_week(2023, 6, 3).second(61).build(),
+    ///     Err(DateTimeError::Time(TimeError::Second))
+    /// );
+    /// ```
+    pub fn build(self) -> Result<DateTime, DateTimeError> {
+        let date = self.date.ok_or(DateTimeError::MissingDate)?;
+        Ok(DateTime {
+            date: *date.validate()?,
+            time: self.time.build()?,
+        })
+    }
+}
+
+impl From<(Date, Time)> for DateTime {
+    fn from(parts: (Date, Time)) -> Self {
+        DateTime::from_parts(parts)
+    }
+}
+
+impl From<DateTime> for (Date, Time) {
+    fn from(dt: DateTime) -> Self {
+        dt.into_parts()
+    }
 }
 
 impl FromStr for DateTime {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         datetime(s)
     }
 }
 
+/// A [`DateTime`] whose date exists in the calendar, from [`DateTime::validate()`] or by parsing it.
+///
+/// It dereferences to the [`DateTime`] it wraps, which can be taken out with [`DateTime::from()`].
+/// ```
+/// let dt: iso8601::ValidDateTime = "2023-02-08T23:40:00Z".parse().unwrap();
+/// assert_eq!(dt.date().year(), 2023);
+/// assert!("2023-02-30T23:40:00Z".parse::<iso8601::ValidDateTime>().is_err());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct ValidDateTime(DateTime);
+
+impl ValidDateTime {
+    /// The date of this datetime, which is known to exist.
+    pub fn date(&self) -> ValidDate {
+        // checked when this was built
+        ValidDate(self.0.date)
+    }
+}
+
+impl Deref for ValidDateTime {
+    type Target = DateTime;
+
+    fn deref(&self) -> &DateTime {
+        &self.0
+    }
+}
+
+impl From<ValidDateTime> for DateTime {
+    fn from(datetime: ValidDateTime) -> DateTime {
+        datetime.0
+    }
+}
+
+impl TryFrom<DateTime> for ValidDateTime {
+    type Error = DateError;
+
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        datetime.validate()
+    }
+}
+
+impl FromStr for ValidDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        datetime(s)?.validate().map_err(|_| {
+            ParseError::new(
+                Component::DateTime,
+                s.as_bytes(),
+                s.as_bytes(),
+                Expected::ExistingDate,
+            )
+        })
+    }
+}
+
 /// Parses a datetime string.
 ///
 /// A datetime string is a combination of the valid formats for the date and time,
@@ -41,10 +1002,161 @@ impl FromStr for DateTime {
 /// ```rust
 /// let dt = iso8601::datetime("2015-11-03T21:56").unwrap();
 /// ```
-pub fn datetime(string: &str) -> Result<DateTime, String> {
-    if let Ok((_left_overs, parsed)) = parsers::parse_datetime(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse datetime: {}", string))
-    }
+pub fn datetime(string: &str) -> Result<DateTime, ParseError> {
+    datetime_bytes(string.as_bytes())
+}
+
+/// Parses a datetime from bytes, like [`datetime()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let datetime = iso8601::datetime_bytes(b"2015-11-03T21:56").unwrap();
+/// ```
+pub fn datetime_bytes(bytes: &[u8]) -> Result<DateTime, ParseError> {
+    error::prefix(Component::DateTime, bytes, parsers::parse_datetime(bytes))
+        .map(|(parsed, _)| parsed)
+}
+
+/// Parses a datetime at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`datetime()`], the datetime may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (datetime, rest) = iso8601::datetime_prefix("2023-02-08T23:40:00Z-retry1").unwrap();
+/// assert_eq!(rest, "-retry1");
+/// ```
+pub fn datetime_prefix(string: &str) -> Result<(DateTime, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::DateTime,
+        string.as_bytes(),
+        parsers::parse_datetime(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
+}
+
+/// Parses a datetime string, like [`datetime()`],
+/// but also accepts the variations commonly found in logs and databases.
+///
+/// The date and time may be separated by a lowercase `t` or a space instead of a `T`,
+/// and UTC may be marked with a lowercase `z` instead of a `Z`.
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_lenient("2015-11-03 21:56:00z").unwrap();
+/// assert_eq!(dt, iso8601::datetime("2015-11-03T21:56:00Z").unwrap());
+/// ```
+pub fn datetime_lenient(string: &str) -> Result<DateTime, ParseError> {
+    error::prefix(
+        Component::DateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_lenient(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Parses a datetime string, only accepting the RFC 3339 profile of ISO 8601.
+///
+/// The string has to consist of exactly:
+///
+/// * a full date in the extended format with an unsigned four digit year
+///   and a day that exists in that month, like `2015-11-03`
+/// * a `T`, `t` or space separator
+/// * a time with hours, minutes and seconds in the extended format,
+///   optionally followed by a fraction of a second after a `.`, like `21:56:00.123`
+/// * a mandatory offset of either `Z`, `z` or hours and minutes, like `+01:00`
+///
+/// Week and ordinal dates, the basic format, reduced precision, the hour 24
+/// and trailing input are all rejected,
+/// and a day the month does not have with [`ErrorKind::NonexistentDate`](crate::ErrorKind::NonexistentDate).
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_rfc3339("2015-11-03T21:56:00Z").unwrap();
+/// assert!(iso8601::datetime_rfc3339("2015-11-03T21:56").is_err());
+///
+/// let error = iso8601::datetime_rfc3339("2023-02-30T23:40:00Z").unwrap_err();
+/// assert_eq!(error.kind, iso8601::ErrorKind::NonexistentDate);
+/// ```
+pub fn datetime_rfc3339(string: &str) -> Result<DateTime, ParseError> {
+    error::complete(
+        Component::Rfc3339DateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_rfc3339(string.as_bytes()),
+    )
+}
+
+/// Parses a datetime string in the format of the HTML `datetime` attribute,
+/// as used by `<input type="datetime-local">` and `<time datetime="...">`.
+///
+/// The string has to consist of exactly:
+///
+/// * a date in the extended format with a year of at least four digits after `0000`,
+///   and a day that exists in that month, like `2015-11-03`
+/// * a `T` or space separator
+/// * a time with hours and minutes in the extended format, optionally followed by seconds
+///   and one to three digits of a fraction of a second after a `.`, like `21:56` or `21:56:00.123`
+/// * an optional offset of either `Z` or hours and minutes, like `+01:00` or `+0100`
+///
+/// Week and ordinal dates, the basic format, signed years, the hour 24, leap seconds
+/// and trailing input are all rejected,
+/// and a day the month does not have with [`ErrorKind::NonexistentDate`](crate::ErrorKind::NonexistentDate).
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_html("2015-11-03T21:56").unwrap();
+/// assert!(iso8601::datetime_html("2015-W45-2T21:56").is_err());
+/// ```
+pub fn datetime_html(string: &str) -> Result<DateTime, ParseError> {
+    error::complete(
+        Component::HtmlDateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_html(string.as_bytes()),
+    )
+}
+
+/// Parses a datetime string in the format of Python's `datetime.isoformat()`,
+/// which `datetime.fromisoformat()` reads back.
+///
+/// The string has to consist of exactly:
+///
+/// * a date in the extended format with a four digit year after `0000`,
+///   and a day that exists in that month, like `2015-11-03`
+/// * optionally, a `T` or space separator followed by a time; a date on its own
+///   is midnight at the start of that day, with [`Precision::Hour`]
+/// * hours, optionally followed by minutes and seconds in the extended format,
+///   like `21`, `21:56` or `21:56:00`
+/// * after the seconds, an optional fraction of either three or six digits after a `.`,
+///   like `21:56:00.123` or `21:56:00.123456`
+/// * an optional offset of either `Z` or hours and minutes, optionally followed by seconds,
+///   like `+01:00` or `+00:19:32`
+///
+/// Offsets with a fraction of a second, which `isoformat()` writes like `+00:19:32.500000`,
+/// are not supported and rejected, as a [`UtcOffset`](crate::UtcOffset) only keeps
+/// whole seconds. Neither are the basic format, the hour 24, leap seconds
+/// and trailing input, which `datetime.fromisoformat()` partly reads.
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_python("2015-11-03T21:56:00.123456+01:00").unwrap();
+/// assert_eq!(dt.time().nanosecond(), 123_456_000);
+/// assert_eq!(
+///     iso8601::datetime_python("2015-11-03"),
+///     iso8601::datetime_python("2015-11-03T00")
+/// );
+/// assert!(iso8601::datetime_python("2015-11-03T21:56:00.1234").is_err());
+/// ```
+pub fn datetime_python(string: &str) -> Result<DateTime, ParseError> {
+    error::complete(
+        Component::PythonDateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_python(string.as_bytes()),
+    )
 }
@@ -1,45 +1,360 @@
 use core::fmt::{self, Display};
 
-use super::{Date, DateTime, Duration, Time};
+use crate::Unit;
+#[cfg(feature = "edtf")]
+use crate::{
+    DateSet, EdtfDate, LongYear, Qualification, QualifiedDate, SetKind, SetMember, SubYearGrouping,
+    UnspecifiedDate,
+};
+
+use super::{
+    Annotation, Bound, Component, ConversionError, Date, DateError, DateTime, DateTimeError,
+    Diagnostic, Duration, ErrorKind, Expected, Interval, Iso8601, IxdtfDateTime, ParseError,
+    Precision, ReducedDate, RepeatingInterval, Snippet, Time, TimeError, TimeZoneAnnotation,
+    TruncatedDate, UtcOffset, Week, Weekday,
+};
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `Failed to parse date: 2023-x06-2, expected the date format at byte 5`
+        write!(
+            f,
+            "Failed to parse {}: {}, expected ",
+            self.component, self.input
+        )?;
+        match self.expected {
+            Expected::Format => write!(f, "the {} format", self.component)?,
+            Expected::End => write!(f, "the end of the input")?,
+            Expected::ExistingDate => write!(f, "a date that exists in the calendar")?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Display for Snippet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like
+        // 2023-13-01
+        //      ^^ month out of range
+        let input = &self.0.input;
+        let span = self.0.span();
+        // columns are counted in characters, the span is in bytes
+        let columns = |range: core::ops::Range<usize>| {
+            input
+                .get(range.clone())
+                .map_or(range.len(), |s| s.chars().count())
+        };
+
+        writeln!(f, "{}", input)?;
+        for _ in 0..columns(0..span.start) {
+            write!(f, " ")?;
+        }
+        for _ in 0..columns(span).max(1) {
+            write!(f, "^")?;
+        }
+        write!(f, " {}", self.0.kind)
+    }
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Component::Date => "date",
+            Component::ExpandedDate => "expanded date",
+            Component::ReducedDate => "reduced date",
+            Component::TruncatedDate => "truncated date",
+            Component::Week => "week",
+            Component::Time => "time",
+            Component::Offset => "offset",
+            Component::DateTime => "datetime",
+            Component::Rfc3339DateTime => "RFC 3339 datetime",
+            Component::HtmlDateTime => "HTML datetime",
+            Component::PythonDateTime => "Python datetime",
+            Component::IxdtfDateTime => "IXDTF datetime",
+            Component::TemporalDateTime => "Temporal datetime",
+            Component::Duration => "duration",
+            Component::Interval => "interval",
+            Component::RepeatingInterval => "repeating interval",
+            Component::Any => "ISO 8601",
+            Component::QualifiedDate => "qualified date",
+            Component::SubYearGrouping => "sub-year grouping",
+            Component::UnspecifiedDate => "unspecified date",
+            Component::DateSet => "date set",
+            Component::LongYear => "long year",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ErrorKind::Syntax => "invalid syntax",
+            ErrorKind::InvalidMonth => "month out of range",
+            ErrorKind::InvalidDay => "day out of range",
+            ErrorKind::InvalidWeek => "week out of range",
+            ErrorKind::InvalidWeekday => "day of the week out of range",
+            ErrorKind::InvalidOrdinal => "day of the year out of range",
+            ErrorKind::InvalidHour => "hour out of range",
+            ErrorKind::InvalidMinute => "minute out of range",
+            ErrorKind::InvalidSecond => "second out of range",
+            ErrorKind::InvalidFraction => "fraction out of range",
+            ErrorKind::ExcessFractionDigits => "too many fraction digits",
+            ErrorKind::MixedFormat => "basic and extended format mixed",
+            ErrorKind::InvalidGrouping => "sub-year grouping out of range",
+            ErrorKind::OffsetOutOfRange => "offset out of range",
+            ErrorKind::NumberTooLarge => "number too large",
+            ErrorKind::TrailingInput => "unexpected trailing input",
+            ErrorKind::NonexistentDate => "date does not exist in the calendar",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `month out of range at bytes 5..7`
+        write!(
+            f,
+            "{} at bytes {}..{}",
+            self.kind, self.span.start, self.span.end
+        )
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::Invalid => write!(f, "value does not exist in the calendar"),
+            ConversionError::OutOfRange => write!(f, "value out of range"),
+            ConversionError::MissingOffset => write!(f, "datetime without an offset"),
+            ConversionError::AmbiguousLocalTime => {
+                write!(f, "datetime skipped or repeated in the local time zone")
+            }
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::Month => write!(f, "month out of range"),
+            DateError::Day => write!(f, "day out of range for the month"),
+            DateError::Week => write!(f, "week out of range for the year"),
+            DateError::Weekday => write!(f, "day of the week out of range"),
+            DateError::Ordinal => write!(f, "day out of range for the year"),
+            DateError::OutOfRange => write!(f, "date out of range"),
+        }
+    }
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeError::Hour => write!(f, "hour out of range"),
+            TimeError::Minute => write!(f, "minute out of range"),
+            TimeError::Second => write!(f, "second out of range"),
+            TimeError::Nanosecond => write!(f, "nanosecond out of range"),
+            TimeError::Offset => write!(f, "offset out of range"),
+        }
+    }
+}
+
+impl Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateTimeError::MissingDate => write!(f, "no date set"),
+            DateTimeError::Date(e) => write!(f, "{}", e),
+            DateTimeError::Time(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// A year with at least four digits, like `0333` or `-0333`,
+/// as `{:04}` would count the sign as one of them.
+///
+/// Years after 9999 need more digits, which ISO 8601 only allows in its expanded
+/// representation that always has a sign, like `+12345`.
+struct Year(i32);
+
+impl Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            year if year < 0 => write!(f, "-{:04}", year.unsigned_abs()),
+            year if year > 9999 => write!(f, "+{}", year),
+            year => write!(f, "{:04}", year),
+        }
+    }
+}
 
 impl Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             // like `2015-11-02`
-            Date::YMD { year, month, day } => write!(f, "{:04}-{:02}-{:02}", year, month, day),
-            // like `2015-W45-01`
-            Date::Week { year, ww, d } => write!(f, "{:04}-{:02}-{:02}", year, ww, d),
+            Date::YMD { year, month, day } => {
+                write!(f, "{}-{:02}-{:02}", Year(year), month, day)
+            }
+            // like `2015-W45-1`
+            Date::Week { year, ww, d } => write!(f, "{}-W{:02}-{}", Year(year), ww, d),
             // like `2015-306`
-            Date::Ordinal { year, ddd } => write!(f, "{:04}-{:03}", year, ddd),
+            Date::Ordinal { year, ddd } => write!(f, "{}-{:03}", Year(year), ddd),
+        }
+    }
+}
+
+impl Display for ReducedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            // like `2015`
+            ReducedDate::Year { year } => write!(f, "{}", Year(year)),
+            // like `2015-11`
+            ReducedDate::YearMonth { year, month } => write!(f, "{}-{:02}", Year(year), month),
+        }
+    }
+}
+
+impl Display for TruncatedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            // like `--02-08`
+            TruncatedDate::MonthDay { month, day } => write!(f, "--{:02}-{:02}", month, day),
+            // like `--02`
+            TruncatedDate::Month { month } => write!(f, "--{:02}", month),
+            // like `---08`
+            TruncatedDate::Day { day } => write!(f, "---{:02}", day),
         }
     }
 }
 
+impl Display for Week {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `2015-W45`
+        write!(f, "{}-W{:02}", Year(self.year), self.ww)
+    }
+}
+
 impl Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // like `16:43:16.123+00:00`
+        // a time of only hours needs its designator, like `T23`, to be read back
+        write_time(f, self, true)
+    }
+}
+
+// Writes `time` up to its precision, like `16:43`, `16:43:16.123+00:00` or `T16`
+// with `designator`, but never drops non-zero components.
+fn write_time(f: &mut fmt::Formatter, time: &Time, designator: bool) -> fmt::Result {
+    // the fraction keeps the digits it was written with, but never drops non-zero ones
+    let (mut significant, mut rest) = (9, time.nanosecond);
+    while significant > 0 && rest % 10 == 0 {
+        rest /= 10;
+        significant -= 1;
+    }
+    let width = significant.max(u32::from(time.fraction_digits).min(9));
+
+    let seconds = time.precision >= Precision::Second || time.second != 0 || width > 0;
+    if seconds || time.precision == Precision::Minute || time.minute != 0 {
+        write!(f, "{:02}:{:02}", time.hour, time.minute)?;
+    } else {
+        if designator {
+            write!(f, "T")?;
+        }
+        write!(f, "{:02}", time.hour)?;
+    }
+    if seconds {
+        write!(f, ":{:02}", time.second)?;
+    }
+    if width > 0 {
         write!(
             f,
-            "{:02}:{:02}:{:02}.{}+{:02}:{:02}",
-            self.hour,
-            self.minute,
-            self.second,
-            self.millisecond,
-            self.tz_offset_hours,
-            self.tz_offset_minutes
-        )
+            ".{:0width$}",
+            time.nanosecond / 10u32.pow(9 - width),
+            width = width as usize
+        )?;
+    }
+
+    write!(f, "{}", time.offset)
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UtcOffset::Utc => write!(f, "Z"),
+            UtcOffset::UnknownLocal => write!(f, "-00:00"),
+            UtcOffset::Unspecified => Ok(()),
+            // like `+05:30`, or `+00:19:32` with seconds
+            UtcOffset::Fixed { seconds } => {
+                let sign = if seconds < 0 { '-' } else { '+' };
+                let seconds = seconds.unsigned_abs();
+                write!(f, "{}{:02}:{:02}", sign, seconds / 3600, seconds / 60 % 60)?;
+                if seconds % 60 != 0 {
+                    write!(f, ":{:02}", seconds % 60)?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
 impl Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // like `16:43:16.123+00:00`
-        write!(f, "{}T{}", self.date, self.time)
+        // like `2015-06-26T16:43:16.123+00:00`
+        write!(f, "{}T", self.date)?;
+        write_time(f, &self.time, false)
+    }
+}
+
+impl Display for TimeZoneAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `[Europe/Berlin]` or `[!Europe/Berlin]`
+        let critical = if self.critical { "!" } else { "" };
+        write!(f, "[{}{}]", critical, self.name)
+    }
+}
+
+impl Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `[u-ca=gregory]` or `[!u-ca=gregory]`
+        let critical = if self.critical { "!" } else { "" };
+        write!(f, "[{}{}={}]", critical, self.key, self.value)
+    }
+}
+
+impl Display for IxdtfDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `2023-02-08T23:40:00.0+01:00[Europe/Berlin][u-ca=gregory]`
+        write!(f, "{}", self.datetime)?;
+        if let Some(time_zone) = &self.time_zone {
+            write!(f, "{}", time_zone)?;
+        }
+        for annotation in &self.annotations {
+            write!(f, "{}", annotation)?;
+        }
+        Ok(())
     }
 }
 
 impl Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_negative() {
+            // like `-P1DT2H`
+            return write!(f, "-{}", -*self);
+        }
+
         match self {
             Duration::YMDHMS {
                 year,
@@ -49,39 +364,72 @@ impl Display for Duration {
                 minute,
                 second,
                 millisecond,
+                fraction,
             } => {
                 if self.is_zero() {
                     write!(f, "P0D")?;
                     return Ok(());
                 }
 
-                write!(f, "P")?;
+                // like `-0.5S`, with the sign of both parts in front
+                let sign = |value: i64, fraction: i64| {
+                    if value < 0 || fraction < 0 {
+                        "-"
+                    } else {
+                        ""
+                    }
+                };
 
-                if *year > 0 {
-                    write!(f, "{}Y", year)?
-                }
+                // like `1Y`, `1.5Y` or `-3D` in a duration with mixed signs,
+                // skipped if zero without a fraction
+                let component = |f: &mut fmt::Formatter, value: i64, unit: Unit, designator| {
+                    match fraction {
+                        Some(fraction) if fraction.unit == unit => {
+                            // at most nine digits, without trailing zeros
+                            let (mut digits, mut width) = (fraction.billionths.unsigned_abs(), 9);
+                            while width > 1 && digits % 10 == 0 {
+                                digits /= 10;
+                                width -= 1;
+                            }
+                            write!(
+                                f,
+                                "{}{}.{:0width$}{}",
+                                sign(value, fraction.billionths),
+                                value.unsigned_abs(),
+                                digits,
+                                designator
+                            )
+                        }
+                        _ if value != 0 => write!(f, "{}{}", value, designator),
+                        _ => Ok(()),
+                    }
+                };
 
-                if *month > 0 {
-                    write!(f, "{}M", month)?
-                }
+                write!(f, "P")?;
 
-                if *day > 0 {
-                    write!(f, "{}D", day)?
-                }
+                component(f, *year, Unit::Year, 'Y')?;
+                component(f, *month, Unit::Month, 'M')?;
+                component(f, *day, Unit::Day, 'D')?;
 
-                if *hour > 0 || *minute > 0 || *second > 0 || *millisecond > 0 {
+                let time_fraction = matches!(fraction, Some(fraction) if matches!(fraction.unit, Unit::Hour | Unit::Minute));
+                if *hour != 0 || *minute != 0 || *second != 0 || *millisecond != 0 || time_fraction
+                {
                     write!(f, "T")?
                 }
-                if *hour > 0 {
-                    write!(f, "{}H", hour)?
-                }
-                if *minute > 0 {
-                    write!(f, "{}M", minute)?
-                }
+                component(f, *hour, Unit::Hour, 'H')?;
+                component(f, *minute, Unit::Minute, 'M')?;
 
-                if *millisecond > 0 {
-                    write!(f, "{}.{}S", second, millisecond)?
-                } else if *second > 0 {
+                if *millisecond != 0 {
+                    // the exact sum, should the seconds and milliseconds differ in sign
+                    let total = i128::from(*second) * 1000 + i128::from(*millisecond);
+                    write!(
+                        f,
+                        "{}{}.{:03}S",
+                        if total < 0 { "-" } else { "" },
+                        total.unsigned_abs() / 1000,
+                        total.unsigned_abs() % 1000
+                    )?
+                } else if *second != 0 {
                     write!(f, "{}S", second)?
                 }
                 Ok(())
@@ -91,9 +439,176 @@ impl Display for Duration {
     }
 }
 
+impl Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Iso8601::Date(date) => write!(f, "{}", date),
+            Iso8601::Time(time) => write!(f, "{}", time),
+            Iso8601::DateTime(datetime) => write!(f, "{}", datetime),
+            Iso8601::Duration(duration) => write!(f, "{}", duration),
+            Iso8601::Interval(interval) => write!(f, "{}", interval),
+        }
+    }
+}
+
+impl Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bound::Date(date) => write!(f, "{}", date),
+            Bound::DateTime(datetime) => write!(f, "{}", datetime),
+            Bound::Open => write!(f, ".."),
+            Bound::Unknown => Ok(()),
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // like `2015-11-02/2015-11-03`
+            Interval::StartEnd { start, end } => write!(f, "{}/{}", start, end),
+            // like `2015-11-02/P1D`
+            Interval::StartDuration { start, duration } => write!(f, "{}/{}", start, duration),
+            // like `P1D/2015-11-03`
+            Interval::DurationEnd { duration, end } => write!(f, "{}/{}", duration, end),
+            // like `P1D`
+            Interval::Duration(duration) => write!(f, "{}", duration),
+        }
+    }
+}
+
+impl Display for RepeatingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `R5/2015-11-02/P1D` or `R/2015-11-02/P1D`
+        match self.count {
+            Some(count) => write!(f, "R{}/{}", count, self.interval),
+            None => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for SubYearGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `2015-21`
+        write!(f, "{}-{:02}", Year(self.year), self.grouping)
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for EdtfDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EdtfDate::Date(date) => write!(f, "{}", date),
+            EdtfDate::Reduced(date) => write!(f, "{}", date),
+            EdtfDate::Grouping(grouping) => write!(f, "{}", grouping),
+        }
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for QualifiedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `2023-02-08?`
+        write!(f, "{}", self.date)?;
+        match self.qualification {
+            Qualification {
+                uncertain: true,
+                approximate: true,
+            } => write!(f, "%"),
+            Qualification {
+                uncertain: true, ..
+            } => write!(f, "?"),
+            Qualification {
+                approximate: true, ..
+            } => write!(f, "~"),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for UnspecifiedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `201X-02-XX`
+        let digits = |f: &mut fmt::Formatter, digits: &[Option<u8>]| {
+            digits.iter().try_for_each(|digit| match digit {
+                Some(digit) => write!(f, "{}", digit),
+                None => write!(f, "X"),
+            })
+        };
+
+        digits(f, &self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-")?;
+            digits(f, &month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-")?;
+            digits(f, &day)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for SetMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // like `1667`
+            SetMember::Date(date) => write!(f, "{}", date),
+            // like `1670..1672`
+            SetMember::Range { start, end } => write!(f, "{}..{}", start, end),
+            // like `..1760-12`
+            SetMember::OnOrBefore(end) => write!(f, "..{}", end),
+            // like `1760-12..`
+            SetMember::OnOrAfter(start) => write!(f, "{}..", start),
+        }
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for DateSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `[1667, 1668]` or `{1667, 1668}`
+        let (open, close) = match self.kind {
+            SetKind::OneOf => ('[', ']'),
+            SetKind::AllOf => ('{', '}'),
+        };
+
+        write!(f, "{}", open)?;
+        for (n, member) in self.members.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", member)?;
+        }
+        write!(f, "{}", close)
+    }
+}
+
+#[cfg(feature = "edtf")]
+impl Display for LongYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `Y170000002` or `Y17E7`
+        match self
+            .exponent
+            .and_then(|exponent| Some((exponent, 10i64.checked_pow(exponent)?)))
+        {
+            Some((exponent, power)) => write!(f, "Y{}E{}", self.year / power, exponent)?,
+            None => write!(f, "Y{}", self.year)?,
+        }
+        // like `S3`
+        match self.significant_digits {
+            Some(significant) => write!(f, "S{}", significant),
+            None => Ok(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parsers::parse_duration;
+    use crate::{parsers::parse_duration, Fraction};
 
     use super::*;
 
@@ -113,6 +628,7 @@ mod tests {
             minute: 26,
             second: 59,
             millisecond: 0,
+            fraction: None,
         };
         test_duration_reparse(duration);
     }
@@ -127,6 +643,7 @@ mod tests {
             minute: 26,
             second: 59,
             millisecond: 123,
+            fraction: None,
         };
         test_duration_reparse(duration);
     }
@@ -136,4 +653,79 @@ mod tests {
         let duration = Duration::Weeks(50);
         test_duration_reparse(duration);
     }
+
+    #[test]
+    fn display_duration_negative() {
+        let duration = Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: -1,
+            hour: -2,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        };
+        assert_eq!(format!("{}", duration), "-P1DT2H");
+        test_duration_reparse(duration);
+        test_duration_reparse(Duration::Weeks(-3));
+    }
+
+    #[test]
+    fn display_duration_mixed_signs() {
+        let duration = Duration::YMDHMS {
+            year: 0,
+            month: 1,
+            day: -3,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: -5,
+            fraction: None,
+        };
+        assert_eq!(format!("{}", duration), "P1M-3DT-0.005S");
+        test_duration_reparse(duration);
+
+        let duration = Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: Some(Fraction {
+                unit: Unit::Hour,
+                billionths: -500_000_000,
+            }),
+        };
+        assert_eq!(format!("{}", duration), "P1DT-0.5H");
+        test_duration_reparse(duration);
+    }
+
+    #[test]
+    fn display_snippet() {
+        fn snippet<T: fmt::Debug>(result: Result<T, ParseError>) -> alloc::string::String {
+            format!("{}", result.unwrap_err().snippet())
+        }
+
+        // columns are characters, not bytes
+        assert_eq!(
+            snippet(crate::offset("\u{2212}25:00")),
+            "\u{2212}25:00\n ^^ offset out of range"
+        );
+        assert_eq!(
+            snippet(crate::datetime_rfc3339("2023-02-08T23:40:00Z later")),
+            "2023-02-08T23:40:00Z later\n                    ^^^^^^ unexpected trailing input"
+        );
+        assert_eq!(
+            snippet(crate::datetime("2023-02-08X")),
+            "2023-02-08X\n          ^ invalid syntax"
+        );
+        // the end of the input is underlined after it
+        assert_eq!(
+            snippet(crate::datetime("2023-02-08T")),
+            "2023-02-08T\n           ^ invalid syntax"
+        );
+    }
 }
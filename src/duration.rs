@@ -1,8 +1,11 @@
-use core::str::FromStr;
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
 
-use alloc::string::String;
-
-use crate::parsers;
+use crate::{error, parsers, Component, ConversionError, ErrorKind, ParseError, Unit};
 
 /// A time duration.
 /// Durations:
@@ -19,33 +22,78 @@ use crate::parsers;
 ///    duration          = "P" (dur-date / dur-time / dur-week)
 /// ```
 ///# use std::str::FromStr;
-/// assert_eq!(iso8601::Duration::from_str("P2021Y11M16DT23H26M59.123S"), Ok(iso8601::Duration::YMDHMS{ year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }))
+/// assert_eq!(iso8601::Duration::from_str("P2021Y11M16DT23H26M59.123S"), Ok(iso8601::Duration::YMDHMS{ year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123, fraction: None }))
 /// ```
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
 pub enum Duration {
     /// A duration specified by year, month, day, hour, minute and second units
     YMDHMS {
         /// Number of calendar years
-        year: u32,
+        year: i64,
         /// Number of months
-        month: u32,
+        month: i64,
         /// Number of days
-        day: u32,
+        day: i64,
         /// Number of hours
-        hour: u32,
+        hour: i64,
         /// Number of minutes
-        minute: u32,
+        minute: i64,
         /// Number of seconds
-        second: u32,
+        second: i64,
         /// Number of milliseconds
-        millisecond: u32,
+        millisecond: i64,
+        /// Decimal fraction of the smallest unit other than seconds, like the `.5` in `PT1.5H`
+        fraction: Option<Fraction>,
     },
     /// consists of week units
-    Weeks(u32),
+    Weeks(i64),
+}
+
+/// A decimal fraction of the smallest unit of a [`Duration`], like the `.5` in `PT1.5H`.
+///
+/// Fractions of seconds are kept in the milliseconds of a duration instead.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::Duration::from_str("PT1,5H"),
+///     Ok(iso8601::Duration::YMDHMS {
+///         year: 0, month: 0, day: 0, hour: 1, minute: 0, second: 0, millisecond: 0,
+///         fraction: Some(iso8601::Fraction { unit: iso8601::Unit::Hour, billionths: 500_000_000 }),
+///     })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct Fraction {
+    /// The unit this is a fraction of, one of years, months, days, hours or minutes
+    pub unit: Unit,
+    /// The fraction in billionths of the unit, negative for negative durations
+    pub billionths: i64,
+}
+
+impl Fraction {
+    /// The number of seconds in the unit of this fraction,
+    /// with a year counting as 365 days and a month as 30 days.
+    fn unit_seconds(&self) -> i64 {
+        match self.unit {
+            Unit::Year => 365 * 86_400,
+            Unit::Month => 30 * 86_400,
+            Unit::Week => 7 * 86_400,
+            Unit::Day => 86_400,
+            Unit::Hour => 3600,
+            Unit::Minute => 60,
+            Unit::Second => 1,
+        }
+    }
+
+    /// The length of this fraction in nanoseconds.
+    pub(crate) fn nanoseconds(&self) -> i128 {
+        // a billionth of a second is a nanosecond
+        i128::from(self.billionths) * i128::from(self.unit_seconds())
+    }
 }
 
 impl Duration {
-    /// Whether this duration represents a zero duration.
+    /// Whether this duration represents a zero duration, like `PT0S` or `P0W`.
     pub fn is_zero(&self) -> bool {
         *self
             == Duration::YMDHMS {
@@ -56,9 +104,392 @@ impl Duration {
                 minute: 0,
                 second: 0,
                 millisecond: 0,
+                fraction: None,
             }
             || *self == Duration::Weeks(0)
     }
+
+    /// Whether this duration points backwards in time, like `-P1DT2H`.
+    ///
+    /// A duration is negative if none of its units is positive and at least one is negative.
+    pub fn is_negative(&self) -> bool {
+        match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+            } => {
+                let fraction = fraction.map_or(0, |f| f.billionths);
+                let units = [
+                    year,
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    millisecond,
+                    fraction,
+                ];
+                units.iter().all(|&u| u <= 0) && units.iter().any(|&u| u < 0)
+            }
+            Duration::Weeks(w) => w < 0,
+        }
+    }
+
+    /// All reasons why this duration could not be written, empty if there are none.
+    ///
+    /// These are [`ErrorKind::InvalidFraction`] for milliseconds outside of `-999` to `999`,
+    /// fractions of a billion billionths or more, fractions of weeks or seconds,
+    /// and fractions of a unit followed by other non-zero units, like half an hour and a minute.
+    ///
+    /// ```
+    /// use iso8601::{Duration, ErrorKind, Fraction, Unit};
+    ///
+    /// assert_eq!(iso8601::duration("PT1.5H").unwrap().violations(), vec![]);
+    /// let duration = Duration::YMDHMS {
+    ///     year: 0, month: 0, day: 0, hour: 1, minute: 1, second: 0, millisecond: 1000,
+    ///     fraction: Some(Fraction { unit: Unit::Hour, billionths: 500_000_000 }),
+    /// };
+    /// assert_eq!(duration.violations(), vec![ErrorKind::InvalidFraction, ErrorKind::InvalidFraction]);
+    /// ```
+    pub fn violations(&self) -> Vec<ErrorKind> {
+        let mut violations = Vec::new();
+        if let Duration::YMDHMS {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            fraction,
+        } = *self
+        {
+            if !(-999..=999).contains(&millisecond) {
+                violations.push(ErrorKind::InvalidFraction);
+            }
+            if let Some(fraction) = fraction {
+                let units = [
+                    (Unit::Year, year),
+                    (Unit::Month, month),
+                    (Unit::Day, day),
+                    (Unit::Hour, hour),
+                    (Unit::Minute, minute),
+                ];
+                // only the smallest unit can have a fraction, weeks and seconds never do
+                let smallest = match units.iter().position(|&(unit, _)| unit == fraction.unit) {
+                    Some(i) => {
+                        units[i + 1..].iter().all(|&(_, value)| value == 0)
+                            && second == 0
+                            && millisecond == 0
+                    }
+                    None => false,
+                };
+                if !smallest || fraction.billionths.unsigned_abs() > 999_999_999 {
+                    violations.push(ErrorKind::InvalidFraction);
+                }
+            }
+        }
+        violations
+    }
+
+    /// Balances the units of this duration up to `largest`,
+    /// carrying overflowing units into larger ones and breaking larger units down,
+    /// like `PT90M` into `PT1H30M` or `P1D` into `PT24H` with [`Unit::Hour`].
+    ///
+    /// Only units of a fixed length are balanced: weeks of seven days,
+    /// days of 24 hours and the smaller units, and years of twelve months.
+    /// Days are never carried into months, so `P400D` stays as it is,
+    /// and years and months are only balanced with [`Unit::Year`] or [`Unit::Month`].
+    /// Durations in weeks stay in weeks with [`Unit::Week`] or larger.
+    /// The balanced units all have the same sign, apart from days and months.
+    ///
+    /// Fractions of days, hours and minutes are counted into the smaller units.
+    /// Returns `None` if they are not whole milliseconds, or if a unit overflows.
+    ///
+    /// ```
+    /// use iso8601::Unit;
+    ///
+    /// let normalize = |s, largest| iso8601::duration(s).unwrap().normalize(largest).unwrap().to_string();
+    /// assert_eq!(normalize("PT90M", Unit::Hour), "PT1H30M");
+    /// assert_eq!(normalize("PT36H", Unit::Day), "P1DT12H");
+    /// assert_eq!(normalize("P1DT1H", Unit::Minute), "PT1500M");
+    /// assert_eq!(normalize("P400D", Unit::Year), "P400D");
+    /// assert_eq!(normalize("P14MT1,5H", Unit::Year), "P1Y2MT1H30M");
+    /// assert_eq!(normalize("P1W", Unit::Day), "P7D");
+    /// ```
+    pub fn normalize(&self, largest: Unit) -> Option<Duration> {
+        // everything of a fixed length is counted in milliseconds, and split up again below
+        let (years, months, fraction, milliseconds) = match *self {
+            Duration::Weeks(_) if matches!(largest, Unit::Year | Unit::Month | Unit::Week) => {
+                return Some(*self)
+            }
+            Duration::Weeks(w) => (0, 0, None, i128::from(w) * 7 * 86_400_000),
+            Duration::YMDHMS {
+                year,
+                month,
+                fraction,
+                ..
+            } => {
+                let nanoseconds = self.exact_nanoseconds();
+                if nanoseconds % 1_000_000 != 0 {
+                    return None;
+                }
+                let fraction = fraction.filter(|f| matches!(f.unit, Unit::Year | Unit::Month));
+                (year, month, fraction, nanoseconds / 1_000_000)
+            }
+        };
+
+        let (years, months) = match largest {
+            Unit::Year => {
+                let months = i128::from(years) * 12 + i128::from(months);
+                (months / 12, months % 12)
+            }
+            // a fraction of a year is no whole number of months
+            Unit::Month if fraction.is_none() => (0, i128::from(years) * 12 + i128::from(months)),
+            _ => (years.into(), months.into()),
+        };
+
+        Duration::balanced(years, months, fraction, milliseconds, largest, checked)
+    }
+
+    /// The sum of both durations, unit by unit, or `None` if a unit overflows.
+    ///
+    /// Units are added up with their signs, so `P1D` and `-PT1H` make `P1DT-1H`.
+    /// Weeks are added up as weeks, or as seven days each to a duration of other units.
+    /// Milliseconds of a second or more are carried into the seconds,
+    /// and fractions of the same unit are added up, with a whole unit carried into that unit.
+    ///
+    /// As only the smallest unit may have a fraction, one that ends up followed by other units
+    /// is counted into them, a fraction of a year as twelve months
+    /// and one of a day, an hour or a minute into the milliseconds.
+    /// Returns `None` as well if that is not a whole number of milliseconds,
+    /// or for a fraction of a month followed by other units.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT1H").checked_add(&duration("PT30M")), Some(duration("PT1H30M")));
+    /// assert_eq!(duration("PT0.600S").checked_add(&duration("PT0.600S")), Some(duration("PT1.200S")));
+    /// assert_eq!(duration("P1W").checked_add(&duration("P1D")), Some(duration("P8D")));
+    /// assert_eq!(duration("PT9223372036854775807S").checked_add(&duration("PT1S")), None);
+    /// ```
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        match self.combine(other, 1, checked)? {
+            (duration, true) => Some(duration),
+            (_, false) => None,
+        }
+    }
+
+    /// The difference of both durations, unit by unit,
+    /// like [`Duration::checked_add()`] with `other` negated.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT1H30M").checked_sub(&duration("PT30M")), Some(duration("PT1H")));
+    /// assert_eq!(duration("P1D").checked_sub(&duration("P2D")), Some(duration("-P1D")));
+    /// ```
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        match self.combine(other, -1, checked)? {
+            (duration, true) => Some(duration),
+            (_, false) => None,
+        }
+    }
+
+    /// Like [`Duration::checked_add()`], but saturating at the bounds of `i64`.
+    /// This is what `+` does.
+    ///
+    /// A fraction of a day, an hour or a minute that is counted into the milliseconds
+    /// is truncated to whole milliseconds, and a fraction of a month followed by other units
+    /// is kept in place of any smaller fraction, though it cannot be written.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// let max = duration("PT9223372036854775807S");
+    /// assert_eq!(max.saturating_add(&duration("PT1S")), max);
+    /// assert_eq!(duration("PT1H").saturating_add(&duration("PT30M")), duration("PT1H30M"));
+    /// ```
+    pub fn saturating_add(&self, other: &Duration) -> Duration {
+        // saturating never fails
+        self.combine(other, 1, saturating)
+            .map_or_else(Duration::default, |(duration, _)| duration)
+    }
+
+    /// Like [`Duration::checked_sub()`], but saturating like [`Duration::saturating_add()`].
+    /// This is what `-` does.
+    pub fn saturating_sub(&self, other: &Duration) -> Duration {
+        // saturating never fails
+        self.combine(other, -1, saturating)
+            .map_or_else(Duration::default, |(duration, _)| duration)
+    }
+
+    /// Each unit of this duration times `factor`, or `None` if one overflows.
+    ///
+    /// Milliseconds of a second or more are carried into the seconds,
+    /// and a fraction of a unit of one or more into that unit, like `PT0.5H` times 3 into `PT1.5H`.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT20S").checked_mul(3), Some(duration("PT60S")));
+    /// assert_eq!(duration("PT0.4S").checked_mul(3), Some(duration("PT1.2S")));
+    /// assert_eq!(duration("P1Y1M").checked_mul(12), Some(duration("P12Y12M")));
+    /// assert_eq!(duration("P9223372036854775807D").checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(&self, factor: u32) -> Option<Duration> {
+        self.scale(factor, checked)
+    }
+
+    /// Like [`Duration::checked_mul()`], but saturating at the bounds of `i64`.
+    /// This is what `*` does.
+    pub fn saturating_mul(&self, factor: u32) -> Duration {
+        // saturating never fails
+        self.scale(factor, saturating).unwrap_or_default()
+    }
+
+    /// This duration divided by `divisor`, or `None` if that is not exact or `divisor` is zero.
+    ///
+    /// The years and months are divided as months, twelve to a year,
+    /// and everything else as milliseconds, with days of 24 hours,
+    /// which are split up again from the largest unit of this duration,
+    /// like `PT1M` divided by 3 into `PT20S` or `P1D` by 2 into `PT12H`.
+    /// Months that do not divide evenly keep a fraction of a month,
+    /// but only if nothing follows them.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT1M").checked_div(3), Some(duration("PT20S")));
+    /// assert_eq!(duration("P1Y").checked_div(4), Some(duration("P3M")));
+    /// assert_eq!(duration("P1M").checked_div(2), Some(duration("P0.5M")));
+    /// assert_eq!(duration("PT1S").checked_div(3), None);
+    /// ```
+    pub fn checked_div(&self, divisor: u32) -> Option<Duration> {
+        match self.divide(divisor, checked)? {
+            (duration, true) => Some(duration),
+            (_, false) => None,
+        }
+    }
+
+    /// Like [`Duration::checked_div()`], but truncating what does not divide evenly
+    /// towards zero and saturating at the bounds of `i64`.
+    /// Dividing by zero saturates every unit that is not zero.
+    /// This is what `/` does.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT1S").saturating_div(3), duration("PT0.333S"));
+    /// assert_eq!(duration("-PT1S").saturating_div(0), duration("-PT9223372036854775807S"));
+    /// ```
+    pub fn saturating_div(&self, divisor: u32) -> Duration {
+        if let Some((duration, _)) = self.divide(divisor, saturating) {
+            return duration;
+        }
+
+        let infinite = |value: i64| value.signum() * i64::MAX;
+        match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+            } => Duration::YMDHMS {
+                year: infinite(year),
+                month: infinite(month),
+                day: infinite(day),
+                hour: infinite(hour),
+                minute: infinite(minute),
+                second: infinite(second),
+                millisecond: infinite(millisecond),
+                fraction: fraction.map(|f| Fraction {
+                    unit: f.unit,
+                    billionths: infinite(f.billionths),
+                }),
+            },
+            Duration::Weeks(w) => Duration::Weeks(infinite(w)),
+        }
+    }
+
+    /// How many times `other` fits into this duration, or `None` where that depends on
+    /// when they start.
+    ///
+    /// That is when only one of them has years or months, or both have them
+    /// along with other units, as months differ in length.
+    /// Years count as twelve months and days as 24 hours.
+    /// It is also `None` if `other` is zero.
+    ///
+    /// ```
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(duration("PT1M").div_duration(&duration("PT20S")), Some(3.0));
+    /// assert_eq!(duration("P1Y6M").div_duration(&duration("P1Y")), Some(1.5));
+    /// assert_eq!(duration("P1W").div_duration(&duration("PT12H")), Some(14.0));
+    /// assert_eq!(duration("P1M").div_duration(&duration("P30D")), None);
+    /// ```
+    pub fn div_duration(&self, other: &Duration) -> Option<f64> {
+        let (calendar, other_calendar) = (self.calendar_billionths(), other.calendar_billionths());
+        let (exact, other_exact) = (self.exact_nanoseconds(), other.exact_nanoseconds());
+        match (calendar, other_calendar, exact, other_exact) {
+            (_, 0, _, 0) => None,
+            (0, 0, exact, other) => Some(exact as f64 / other as f64),
+            (calendar, other, 0, 0) => Some(calendar as f64 / other as f64),
+            _ => None,
+        }
+    }
+
+    /// The total length of this duration in whole seconds,
+    /// with a year counting as 365 days and a month as 30 days.
+    ///
+    /// The units are summed up with 128-bit integers, so this cannot overflow.
+    /// Fractions of a second are truncated towards zero.
+    ///
+    /// ```
+    /// let duration = iso8601::duration("P1DT2H3M4.5S").unwrap();
+    /// assert_eq!(duration.total_seconds(), 93_784);
+    /// let duration = iso8601::duration("-PT9223372036854775807S").unwrap();
+    /// assert_eq!(-duration.total_seconds(), i64::MAX.into());
+    /// ```
+    pub fn total_seconds(&self) -> i128 {
+        self.total_nanoseconds() / 1_000_000_000
+    }
+
+    /// The total length of this duration in nanoseconds, like [`Duration::total_seconds()`].
+    pub(crate) fn total_nanoseconds(&self) -> i128 {
+        match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+            } => {
+                let secs: i128 = [
+                    (year, 365 * 86_400),
+                    (month, 30 * 86_400),
+                    (day, 86_400),
+                    (hour, 3600),
+                    (minute, 60),
+                    (second, 1),
+                ]
+                .iter()
+                .map(|&(value, unit)| i128::from(value) * unit)
+                .sum();
+                let nanos =
+                    i128::from(millisecond) * 1_000_000 + fraction.map_or(0, |f| f.nanoseconds());
+
+                secs * 1_000_000_000 + nanos
+            }
+            Duration::Weeks(w) => i128::from(w) * 7 * 86_400 * 1_000_000_000,
+        }
+    }
 }
 
 impl Default for Duration {
@@ -71,20 +502,501 @@ impl Default for Duration {
             minute: 0,
             second: 0,
             millisecond: 0,
+            fraction: None,
+        }
+    }
+}
+
+/// Orders durations by their length where that does not depend on when they start,
+/// counting days as 24 hours.
+///
+/// Their years and months, counted in months, and the rest of them are compared apart,
+/// and a duration is shorter if neither of them is longer, as in `P1Y` and `P2Y`
+/// or `P1M` and `P1M1D`.
+/// Durations of the same length written differently, like `P1D` and `PT24H`,
+/// are not ordered as they are not equal.
+///
+/// ```
+/// let duration = |s| iso8601::duration(s).unwrap();
+/// assert!(duration("PT90M") < duration("PT2H"));
+/// assert!(duration("P1W") > duration("P6DT23H"));
+/// assert!(duration("P1M1D") < duration("P1M2D"));
+/// assert!(duration("P1Y") < duration("P1Y1M"));
+/// // a month may be shorter or longer than 30 days
+/// assert_eq!(duration("P1M").partial_cmp(&duration("P30D")), None);
+/// assert_eq!(duration("P1D").partial_cmp(&duration("PT24H")), None);
+/// ```
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        let calendar = self.calendar_billionths().cmp(&other.calendar_billionths());
+        let exact = self.exact_nanoseconds().cmp(&other.exact_nanoseconds());
+        match (calendar, exact) {
+            (Ordering::Equal, Ordering::Equal) => None,
+            (Ordering::Equal, ordering) | (ordering, Ordering::Equal) => Some(ordering),
+            (calendar, exact) if calendar == exact => Some(calendar),
+            _ => None,
+        }
+    }
+}
+
+impl Duration {
+    // The years and months, and a fraction of either, which differ in length by when they start
+    pub(crate) fn calendar_units(&self) -> (i64, i64, Option<Fraction>) {
+        match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                fraction,
+                ..
+            } => (
+                year,
+                month,
+                fraction.filter(|f| matches!(f.unit, Unit::Year | Unit::Month)),
+            ),
+            Duration::Weeks(_) => (0, 0, None),
+        }
+    }
+
+    // A duration of the years and months as they are, and of the milliseconds split
+    // into days, hours, minutes and seconds from `largest` down, each converted with `convert`
+    fn balanced(
+        years: i128,
+        months: i128,
+        fraction: Option<Fraction>,
+        milliseconds: i128,
+        largest: Unit,
+        convert: impl Fn(i128) -> Option<i64>,
+    ) -> Option<Duration> {
+        let mut units = [0i128; 4];
+        let mut rest = milliseconds;
+        let lengths = [
+            (Unit::Day, 86_400_000),
+            (Unit::Hour, 3_600_000),
+            (Unit::Minute, 60_000),
+            (Unit::Second, 1000),
+        ];
+        let first = lengths
+            .iter()
+            .position(|&(unit, _)| unit == largest)
+            .unwrap_or(0);
+        for (value, &(_, length)) in units.iter_mut().zip(&lengths).skip(first) {
+            *value = rest / length;
+            rest %= length;
+        }
+        let [day, hour, minute, second] = units;
+
+        Some(Duration::YMDHMS {
+            year: convert(years)?,
+            month: convert(months)?,
+            day: convert(day)?,
+            hour: convert(hour)?,
+            minute: convert(minute)?,
+            second: convert(second)?,
+            millisecond: convert(rest)?,
+            fraction,
+        })
+    }
+
+    // The nanoseconds of everything but the years and months, which all have a fixed length
+    pub(crate) fn exact_nanoseconds(&self) -> i128 {
+        const SECOND: i128 = 1_000_000_000;
+        match *self {
+            Duration::YMDHMS {
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+                ..
+            } => {
+                let fraction = fraction.filter(|f| !matches!(f.unit, Unit::Year | Unit::Month));
+                (i128::from(day) * 86_400
+                    + i128::from(hour) * 3600
+                    + i128::from(minute) * 60
+                    + i128::from(second))
+                    * SECOND
+                    + i128::from(millisecond) * 1_000_000
+                    + fraction.map_or(0, |f| f.nanoseconds())
+            }
+            Duration::Weeks(w) => i128::from(w) * 7 * 86_400 * SECOND,
+        }
+    }
+
+    // The years and months, and a fraction of either, in billionths of a month
+    fn calendar_billionths(&self) -> i128 {
+        let (years, months, fraction) = self.calendar_units();
+        let fraction = fraction.map_or(0, |f| match f.unit {
+            Unit::Year => i128::from(f.billionths) * 12,
+            _ => i128::from(f.billionths),
+        });
+        (i128::from(years) * 12 + i128::from(months)) * 1_000_000_000 + fraction
+    }
+
+    // Each unit of this duration plus `sign` times that of `other`, each converted with
+    // `convert`, and whether the fractions were added up without truncating them
+    fn combine(
+        &self,
+        other: &Duration,
+        sign: i128,
+        convert: impl Fn(i128) -> Option<i64>,
+    ) -> Option<(Duration, bool)> {
+        // the seconds in a day, hour, minute and second
+        const SECONDS: [i128; 4] = [86_400, 3600, 60, 1];
+        if let (Duration::Weeks(a), Duration::Weeks(b)) = (*self, *other) {
+            let weeks = convert(i128::from(a) + sign * i128::from(b))?;
+            return Some((Duration::Weeks(weeks), true));
+        }
+
+        // years, months, days, hours, minutes, seconds and milliseconds,
+        // and the billionths of a fraction of each of the units but milliseconds
+        let mut units = [0i128; 7];
+        let mut fractions = [0i128; 6];
+        for (duration, sign) in [(self, 1), (other, sign)] {
+            match *duration {
+                Duration::YMDHMS {
+                    year,
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    millisecond,
+                    fraction,
+                } => {
+                    let values = [year, month, day, hour, minute, second, millisecond];
+                    for (unit, value) in units.iter_mut().zip(values) {
+                        *unit += sign * i128::from(value);
+                    }
+                    if let Some(f) = fraction {
+                        let (i, factor) = match f.unit {
+                            Unit::Year => (0, 1),
+                            Unit::Month => (1, 1),
+                            Unit::Week => (2, 7),
+                            Unit::Day => (2, 1),
+                            Unit::Hour => (3, 1),
+                            Unit::Minute => (4, 1),
+                            Unit::Second => (5, 1),
+                        };
+                        fractions[i] += sign * factor * i128::from(f.billionths);
+                    }
+                }
+                Duration::Weeks(w) => units[2] += sign * 7 * i128::from(w),
+            }
+        }
+
+        // whole units of a fraction are carried into the unit
+        let carry = |units: &mut [i128; 7], fractions: &mut [i128; 6], i: usize| {
+            units[i] += fractions[i] / 1_000_000_000;
+            fractions[i] %= 1_000_000_000;
+        };
+        // whether anything follows a unit, as only the smallest unit may have a fraction
+        let followed = |units: &[i128; 7], fractions: &[i128; 6], i: usize| {
+            units[i + 1..].iter().any(|&u| u != 0) || fractions[i + 1..].iter().any(|&f| f != 0)
+        };
+        for i in 0..6 {
+            carry(&mut units, &mut fractions, i);
+        }
+
+        // a fraction of a year is one of twelve months, and one of a day, an hour or a minute
+        // is counted into the milliseconds
+        let mut exact = true;
+        if fractions[0] != 0 && followed(&units, &fractions, 0) {
+            fractions[1] += fractions[0] * 12;
+            fractions[0] = 0;
+            carry(&mut units, &mut fractions, 1);
+        }
+        for (i, seconds) in (2..6).zip(SECONDS) {
+            if fractions[i] != 0 && followed(&units, &fractions, i) {
+                let nanoseconds = fractions[i] * seconds;
+                units[6] += nanoseconds / 1_000_000;
+                exact &= nanoseconds % 1_000_000 == 0;
+                fractions[i] = 0;
+            }
+        }
+        units[5] += units[6] / 1000;
+        units[6] %= 1000;
+        // the seconds and milliseconds are written as one number, so they take the same sign
+        if units[5] > 0 && units[6] < 0 {
+            units[5] -= 1;
+            units[6] += 1000;
+        } else if units[5] < 0 && units[6] > 0 {
+            units[5] += 1;
+            units[6] -= 1000;
+        }
+
+        // a fraction of a month followed by other units cannot be written
+        let mut remaining = fractions.iter().enumerate().filter(|&(_, &f)| f != 0);
+        let fraction = match remaining.next() {
+            Some((i, &billionths)) => {
+                exact &= remaining.next().is_none() && !followed(&units, &fractions, i);
+                let unit = [
+                    Unit::Year,
+                    Unit::Month,
+                    Unit::Day,
+                    Unit::Hour,
+                    Unit::Minute,
+                    Unit::Second,
+                ][i];
+                Some(Fraction {
+                    unit,
+                    billionths: convert(billionths)?,
+                })
+            }
+            None => None,
+        };
+
+        let [year, month, day, hour, minute, second, millisecond] = units;
+        let duration = Duration::YMDHMS {
+            year: convert(year)?,
+            month: convert(month)?,
+            day: convert(day)?,
+            hour: convert(hour)?,
+            minute: convert(minute)?,
+            second: convert(second)?,
+            millisecond: convert(millisecond)?,
+            fraction,
+        };
+        Some((duration, exact))
+    }
+
+    // Each unit times `factor`, carrying milliseconds into the seconds
+    // and a fraction into its unit, each converted with `convert`
+    fn scale(&self, factor: u32, convert: impl Fn(i128) -> Option<i64>) -> Option<Duration> {
+        const UNITS: [Unit; 6] = [
+            Unit::Year,
+            Unit::Month,
+            Unit::Day,
+            Unit::Hour,
+            Unit::Minute,
+            Unit::Second,
+        ];
+        let factor = i128::from(factor);
+        match *self {
+            Duration::Weeks(w) => Some(Duration::Weeks(convert(i128::from(w) * factor)?)),
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+            } => {
+                let mut units =
+                    [year, month, day, hour, minute, second].map(|u| i128::from(u) * factor);
+                let millisecond = i128::from(millisecond) * factor;
+                units[5] += millisecond / 1000;
+
+                let fraction = match fraction {
+                    Some(f) => {
+                        let mut billionths = i128::from(f.billionths) * factor;
+                        if let Some(i) = UNITS.iter().position(|&unit| unit == f.unit) {
+                            units[i] += billionths / 1_000_000_000;
+                            billionths %= 1_000_000_000;
+                        }
+                        match billionths {
+                            0 => None,
+                            billionths => Some(Fraction {
+                                unit: f.unit,
+                                billionths: convert(billionths)?,
+                            }),
+                        }
+                    }
+                    None => None,
+                };
+
+                let [year, month, day, hour, minute, second] = units;
+                Some(Duration::YMDHMS {
+                    year: convert(year)?,
+                    month: convert(month)?,
+                    day: convert(day)?,
+                    hour: convert(hour)?,
+                    minute: convert(minute)?,
+                    second: convert(second)?,
+                    millisecond: convert(millisecond % 1000)?,
+                    fraction,
+                })
+            }
         }
     }
+
+    // This duration divided by `divisor` and whether that is exact, with each unit converted
+    // with `convert`, or `None` for a divisor of zero
+    fn divide(
+        &self,
+        divisor: u32,
+        convert: impl Fn(i128) -> Option<i64>,
+    ) -> Option<(Duration, bool)> {
+        const MILLISECOND: i128 = 1_000_000;
+        if divisor == 0 {
+            return None;
+        }
+        if let Duration::Weeks(w) = *self {
+            if w % i64::from(divisor) == 0 {
+                return Some((Duration::Weeks(w / i64::from(divisor)), true));
+            }
+        }
+
+        let divisor = i128::from(divisor);
+        let (calendar, nanoseconds) = (self.calendar_billionths(), self.exact_nanoseconds());
+        let (quotient, exact) = (nanoseconds / divisor, nanoseconds % divisor == 0);
+        let (months, fraction) = (
+            calendar / divisor / 1_000_000_000,
+            calendar / divisor % 1_000_000_000,
+        );
+        let mut exact = exact && calendar % divisor == 0 && quotient % MILLISECOND == 0;
+
+        // a fraction of a month only if nothing follows it
+        let fraction = match fraction {
+            0 => None,
+            billionths if quotient == 0 => Some(Fraction {
+                unit: Unit::Month,
+                billionths: convert(billionths)?,
+            }),
+            _ => {
+                exact = false;
+                None
+            }
+        };
+
+        let largest = match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                fraction,
+                ..
+            } => {
+                let unit = fraction.map(|f| f.unit);
+                match () {
+                    _ if year != 0 || unit == Some(Unit::Year) => Unit::Year,
+                    _ if month != 0 || unit == Some(Unit::Month) => Unit::Month,
+                    _ if day != 0 || unit == Some(Unit::Day) => Unit::Day,
+                    _ if hour != 0 || unit == Some(Unit::Hour) => Unit::Hour,
+                    _ if minute != 0 || unit == Some(Unit::Minute) => Unit::Minute,
+                    _ => Unit::Second,
+                }
+            }
+            Duration::Weeks(_) => Unit::Day,
+        };
+        let (years, months) = match largest {
+            Unit::Year => (months / 12, months % 12),
+            _ => (0, months),
+        };
+        let duration = Duration::balanced(
+            years,
+            months,
+            fraction,
+            quotient / MILLISECOND,
+            largest,
+            convert,
+        )?;
+        Some((duration, exact))
+    }
+}
+
+/// Negates each unit of a duration, with `i64::MIN` turning into `i64::MAX`.
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        match self {
+            Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+                fraction,
+            } => Duration::YMDHMS {
+                year: year.saturating_neg(),
+                month: month.saturating_neg(),
+                day: day.saturating_neg(),
+                hour: hour.saturating_neg(),
+                minute: minute.saturating_neg(),
+                second: second.saturating_neg(),
+                millisecond: millisecond.saturating_neg(),
+                fraction: fraction.map(|f| Fraction {
+                    unit: f.unit,
+                    billionths: f.billionths.saturating_neg(),
+                }),
+            },
+            Duration::Weeks(w) => Duration::Weeks(w.saturating_neg()),
+        }
+    }
+}
+
+/// Adds up durations with [`Duration::saturating_add()`].
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.saturating_add(&other)
+    }
+}
+
+/// Subtracts durations with [`Duration::saturating_sub()`].
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.saturating_sub(&other)
+    }
+}
+
+/// Multiplies each unit of a duration with [`Duration::saturating_mul()`].
+impl Mul<u32> for Duration {
+    type Output = Duration;
+
+    fn mul(self, factor: u32) -> Duration {
+        self.saturating_mul(factor)
+    }
+}
+
+/// Divides a duration with [`Duration::saturating_div()`].
+impl Div<u32> for Duration {
+    type Output = Duration;
+
+    fn div(self, divisor: u32) -> Duration {
+        self.saturating_div(divisor)
+    }
+}
+
+// Converts a unit, failing if it overflows an `i64`
+fn checked(value: i128) -> Option<i64> {
+    value.try_into().ok()
+}
+
+// Converts a unit, saturating at the bounds of an `i64`
+fn saturating(value: i128) -> Option<i64> {
+    Some(value.clamp(i64::MIN.into(), i64::MAX.into()) as i64)
 }
 
 impl FromStr for Duration {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         duration(s)
     }
 }
 
-impl From<Duration> for ::core::time::Duration {
-    fn from(duration: Duration) -> Self {
+/// Converts a duration into a [`core::time::Duration`],
+/// with a year counting as 365 days and a month as 30 days.
+///
+/// Fails for negative durations and durations too long to be represented.
+impl TryFrom<Duration> for ::core::time::Duration {
+    type Error = ConversionError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
         match duration {
             Duration::YMDHMS {
                 year,
@@ -94,19 +1006,43 @@ impl From<Duration> for ::core::time::Duration {
                 minute,
                 second,
                 millisecond,
+                fraction,
             } => {
-                let secs = u64::from(year) * 365 * 86_400
-                    + u64::from(month) * 30 * 86_400
-                    + u64::from(day) * 86_400
-                    + u64::from(hour) * 3600
-                    + u64::from(minute) * 60
-                    + u64::from(second);
-                let nanos = millisecond * 1_000_000;
-                Self::new(secs, nanos)
+                let secs = [
+                    (year, 365 * 86_400),
+                    (month, 30 * 86_400),
+                    (day, 86_400),
+                    (hour, 3600),
+                    (minute, 60),
+                    (second, 1),
+                ]
+                .iter()
+                .try_fold(0u64, |secs, &(value, unit)| {
+                    u64::try_from(value)
+                        .ok()?
+                        .checked_mul(unit)?
+                        .checked_add(secs)
+                })
+                .ok_or(ConversionError::OutOfRange)?;
+                let millis = u64::try_from(millisecond).map_err(|_| ConversionError::OutOfRange)?;
+                let fraction = match fraction {
+                    Some(f) => {
+                        u64::try_from(f.nanoseconds()).map_err(|_| ConversionError::OutOfRange)?
+                    }
+                    None => 0,
+                };
+
+                Self::from_secs(secs)
+                    .checked_add(Self::from_millis(millis))
+                    .and_then(|d| d.checked_add(Self::from_nanos(fraction)))
+                    .ok_or(ConversionError::OutOfRange)
             }
             Duration::Weeks(week) => {
-                let secs = u64::from(week) * 7 * 86_400;
-                Self::from_secs(secs)
+                let secs = u64::try_from(week)
+                    .ok()
+                    .and_then(|w| w.checked_mul(7 * 86_400))
+                    .ok_or(ConversionError::OutOfRange)?;
+                Ok(Self::from_secs(secs))
             }
         }
     }
@@ -118,7 +1054,19 @@ impl From<Duration> for ::core::time::Duration {
 ///
 /// * Fully-specified duration: `P1Y2M3DT4H5M6S`
 /// * Duration in weekly intervals: `P1W`
-/// * Fully-specified duration in [`DateTime`](`crate::DateTime`) format: `P<datetime>`
+/// * Fully-specified duration in [`DateTime`](`crate::DateTime`) format: `P<datetime>`,
+///   where months and days may be zero, like `P0003-05-00T00:00:00` or `P00030500T000000`
+///
+/// The smallest unit may carry a decimal fraction, separated by either a `.` or a `,`,
+/// like `PT6,5S` or `P0.25Y`. Fractions of weeks are not supported.
+/// Seconds are kept to the millisecond, so any digits of a second after the third,
+/// like in `PT0.0001S`, are dropped. [`ParserConfig::duration()`](crate::ParserConfig::duration)
+/// rejects them instead with [`ExcessDigits::Reject`](crate::ExcessDigits::Reject).
+///
+/// Each format may be preceded by a sign, where a `-` negates all units
+/// of the duration, like `-P1DT2H`.
+/// Following ISO 8601-2, single units of the YMDHMS and weeks formats may also be negative,
+/// like `P1M-3D`, as produced by differences between calendar dates.
 ///
 /// Both fully-specified formats get parsed into the YMDHMS Duration variant.
 /// The weekly interval format gets parsed into the Weeks Duration variant.
@@ -128,7 +1076,7 @@ impl From<Duration> for ::core::time::Duration {
 ///
 /// These ranges (inclusive) are as follows:
 ///
-/// * Year (any valid u32)
+/// * Year (any number up to `i64::MAX`)
 /// * Month 0 - 12
 /// * Week 0 - 52
 /// * Day 0 - 31
@@ -136,17 +1084,83 @@ impl From<Duration> for ::core::time::Duration {
 /// * Minute 0 - 60
 /// * Second 0 - 60
 ///
+/// Machine-generated durations often exceed these, like `PT300000000S`,
+/// so every unit is accepted up to `i64::MAX`.
+///
+/// At least one unit has to be given, so `P` and `PT` are rejected,
+/// unless [`ParserConfig::empty_durations`](crate::ParserConfig::empty_durations) is set.
+/// A zero duration is written like `PT0S`, see [`Duration::is_zero()`].
+///
 /// ## Examples
 ///
 /// ```rust
 /// let duration = iso8601::duration("P1Y2M3DT4H5M6S").unwrap();
 /// let duration = iso8601::duration("P1W").unwrap();
 /// let duration = iso8601::duration("P2015-11-03T21:56").unwrap();
+/// let duration = iso8601::duration("P0003-00-02T00:00:00").unwrap();
+/// let duration = iso8601::duration("-P1DT2H").unwrap();
+/// let duration = iso8601::duration("PT1,5H").unwrap();
+/// let duration = iso8601::duration("P1M-3D").unwrap();
 /// ```
-pub fn duration(string: &str) -> Result<Duration, String> {
-    if let Ok((_left_overs, parsed)) = parsers::parse_duration(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse duration: {}", string))
-    }
+pub fn duration(string: &str) -> Result<Duration, ParseError> {
+    duration_bytes(string.as_bytes())
+}
+
+/// Parses a duration from bytes, like [`duration()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let duration = iso8601::duration_bytes(b"P1Y2M3DT4H5M6S").unwrap();
+/// ```
+pub fn duration_bytes(bytes: &[u8]) -> Result<Duration, ParseError> {
+    error::prefix(Component::Duration, bytes, parsers::parse_duration(bytes))
+        .map(|(parsed, _)| parsed)
+}
+
+/// Parses a duration at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`duration()`], the duration may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (duration, rest) = iso8601::duration_prefix("PT5M, then retry").unwrap();
+/// assert_eq!(rest, ", then retry");
+/// ```
+pub fn duration_prefix(string: &str) -> Result<(Duration, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::Duration,
+        string.as_bytes(),
+        parsers::parse_duration(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
+}
+
+/// Parses a duration string, like [`duration()`],
+/// but also accepts weeks combined with other units like `P1W2D`.
+///
+/// The strict grammar only allows weeks on their own,
+/// but some producers mix them with other units anyway.
+/// Mixed weeks get folded into the days of a YMDHMS duration,
+/// while weeks on their own still get parsed into the Weeks Duration variant.
+///
+/// ## Examples
+///
+/// ```rust
+/// let duration = iso8601::duration_lenient("P1W2D").unwrap();
+/// assert_eq!(duration, iso8601::duration("P9D").unwrap());
+/// let duration = iso8601::duration_lenient("P1Y2WT12H").unwrap();
+/// assert_eq!(duration, iso8601::duration("P1Y14DT12H").unwrap());
+/// let duration = iso8601::duration_lenient("P2W").unwrap();
+/// assert_eq!(duration, iso8601::Duration::Weeks(2));
+/// ```
+pub fn duration_lenient(string: &str) -> Result<Duration, ParseError> {
+    error::prefix(
+        Component::Duration,
+        string.as_bytes(),
+        parsers::parse_duration_lenient(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
 }
@@ -0,0 +1,539 @@
+//! Extended Date/Time Format (EDTF) extensions, as specified by ISO 8601-2.
+
+use alloc::vec::Vec;
+use core::{slice, str::FromStr};
+
+use crate::{calendar, error, parsers, Component, Date, ParseError, ReducedDate};
+
+/// A date of the precision supported by EDTF, either a complete calendar date or a reduced one.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum EdtfDate {
+    /// a complete calendar date like `2023-02-08`
+    Date(Date),
+    /// a year or a month like `2023` or `2023-02`
+    Reduced(ReducedDate),
+    /// a season, quarter or other grouping of months like `2023-21`
+    Grouping(SubYearGrouping),
+}
+
+impl EdtfDate {
+    /// The first day covered by this date.
+    pub fn first_day(&self) -> Date {
+        match *self {
+            EdtfDate::Date(date) => date,
+            EdtfDate::Reduced(ReducedDate::Year { year }) => Date::YMD {
+                year,
+                month: 1,
+                day: 1,
+            },
+            EdtfDate::Reduced(ReducedDate::YearMonth { year, month }) => Date::YMD {
+                year,
+                month,
+                day: 1,
+            },
+            EdtfDate::Grouping(grouping) => grouping.first_day(),
+        }
+    }
+
+    /// The last day covered by this date.
+    pub fn last_day(&self) -> Date {
+        match *self {
+            EdtfDate::Date(date) => date,
+            EdtfDate::Reduced(ReducedDate::Year { year }) => Date::YMD {
+                year,
+                month: 12,
+                day: 31,
+            },
+            EdtfDate::Reduced(ReducedDate::YearMonth { year, month }) => Date::YMD {
+                year,
+                month,
+                day: calendar::days_in_month(year, month),
+            },
+            EdtfDate::Grouping(grouping) => grouping.last_day(),
+        }
+    }
+}
+
+/// A season, quarter, quadrimester or semester of a year, like `2023-21` for spring 2023.
+///
+/// The groupings are numbered as follows:
+///
+/// * `21` to `24`: spring, summer, autumn and winter
+/// * `25` to `28`: spring, summer, autumn and winter in the northern hemisphere
+/// * `29` to `32`: spring, summer, autumn and winter in the southern hemisphere
+/// * `33` to `36`: the four quarters
+/// * `37` to `39`: the three quadrimesters of four months
+/// * `40` and `41`: the two semesters of six months
+///
+/// Seasons are taken to be meteorological seasons of three full months,
+/// with seasons independent of location following the northern hemisphere.
+/// The winter of a year starts in its December and ends in the February of the following year.
+/// ```
+/// # use std::str::FromStr;
+/// let grouping = iso8601::SubYearGrouping::from_str("2023-34").unwrap();
+/// assert_eq!(grouping, iso8601::SubYearGrouping { year: 2023, grouping: 34 });
+/// assert_eq!(grouping.first_day(), iso8601::Date::YMD { year: 2023, month: 4, day: 1 });
+/// assert_eq!(grouping.last_day(), iso8601::Date::YMD { year: 2023, month: 6, day: 30 });
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct SubYearGrouping {
+    /// the year
+    pub year: i32,
+    /// the number of the grouping, from 21 to 41
+    pub grouping: u32,
+}
+
+impl SubYearGrouping {
+    /// The first and last month of this grouping,
+    /// where a last month before the first one lies in the following year.
+    fn months(&self) -> (u32, u32) {
+        match self.grouping {
+            // spring, summer, autumn and winter, in the northern hemisphere by default
+            21..=28 => {
+                let first = (self.grouping - 21) % 4 * 3 + 3;
+                (first, (first + 1) % 12 + 1)
+            }
+            // spring, summer, autumn and winter in the southern hemisphere
+            29..=32 => {
+                let first = ((self.grouping - 29) * 3 + 8) % 12 + 1;
+                (first, (first + 1) % 12 + 1)
+            }
+            // quarters
+            33..=36 => ((self.grouping - 33) * 3 + 1, (self.grouping - 33) * 3 + 3),
+            // quadrimesters
+            37..=39 => ((self.grouping - 37) * 4 + 1, (self.grouping - 37) * 4 + 4),
+            // semesters
+            _ => {
+                let semester = u32::from(self.grouping > 40);
+                (semester * 6 + 1, semester * 6 + 6)
+            }
+        }
+    }
+
+    /// The first day of this grouping.
+    pub fn first_day(&self) -> Date {
+        Date::YMD {
+            year: self.year,
+            month: self.months().0,
+            day: 1,
+        }
+    }
+
+    /// The last day of this grouping, which is in the following year for winters ending in February.
+    pub fn last_day(&self) -> Date {
+        let (first, last) = self.months();
+        let year = if last < first {
+            self.year.saturating_add(1)
+        } else {
+            self.year
+        };
+
+        Date::YMD {
+            year,
+            month: last,
+            day: calendar::days_in_month(year, last),
+        }
+    }
+}
+
+impl FromStr for SubYearGrouping {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        sub_year_grouping(s)
+    }
+}
+
+/// How certain the value of a [`QualifiedDate`] is.
+///
+/// A `?` marks a date as uncertain, a `~` as approximate and a `%` as both.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct Qualification {
+    /// The date might be wrong, marked by `?` or `%`
+    pub uncertain: bool,
+    /// The date is an estimate, marked by `~` or `%`
+    pub approximate: bool,
+}
+
+/// A date with an optional EDTF level 1 qualification.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::QualifiedDate::from_str("2023-02~"),
+///     Ok(iso8601::QualifiedDate {
+///         date: iso8601::EdtfDate::Reduced(iso8601::ReducedDate::YearMonth { year: 2023, month: 2 }),
+///         qualification: iso8601::Qualification { uncertain: false, approximate: true },
+///     })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct QualifiedDate {
+    /// The qualified date
+    pub date: EdtfDate,
+    /// How certain the date is, neither uncertain nor approximate if unqualified
+    pub qualification: Qualification,
+}
+
+impl FromStr for QualifiedDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        qualified_date(s)
+    }
+}
+
+/// Parses a date string with an optional EDTF qualifier.
+///
+/// A string can have one of the following formats,
+/// always in the extended format with `-` separators:
+///
+/// * `2015-11-02`, a specific day
+/// * `2015-11`, a specific month
+/// * `2015`, a specific year
+/// * `2015-21`, a season or other [`SubYearGrouping`] of a year
+///
+/// Each may be followed by one of the qualifiers
+/// `?` (uncertain), `~` (approximate) or `%` (uncertain and approximate).
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::qualified_date("2015-11-02?").unwrap();
+/// assert!(date.qualification.uncertain);
+/// ```
+pub fn qualified_date(string: &str) -> Result<QualifiedDate, ParseError> {
+    error::prefix(
+        Component::QualifiedDate,
+        string.as_bytes(),
+        parsers::parse_qualified_date(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Parses a season or other sub-year grouping string like `2015-21`.
+///
+/// See [`SubYearGrouping`] for the meaning of the groupings.
+///
+/// ## Example
+///
+/// ```rust
+/// let spring = iso8601::sub_year_grouping("2015-21").unwrap();
+/// ```
+pub fn sub_year_grouping(string: &str) -> Result<SubYearGrouping, ParseError> {
+    error::prefix(
+        Component::SubYearGrouping,
+        string.as_bytes(),
+        parsers::parse_sub_year_grouping(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// A date with unspecified digits, marked by `X`, like `201X`, `2023-XX` or `2023-02-XX`.
+///
+/// Each digit is either known or `None` if unspecified, which may be any digit.
+/// Unspecified digits may appear in any position of the year, month and day.
+/// ```
+/// # use std::str::FromStr;
+/// let date = iso8601::UnspecifiedDate::from_str("201X-02").unwrap();
+/// assert_eq!(date.year, [Some(2), Some(0), Some(1), None]);
+/// assert_eq!(date.earliest(), Some(iso8601::Date::YMD { year: 2010, month: 2, day: 1 }));
+/// assert_eq!(date.latest(), Some(iso8601::Date::YMD { year: 2019, month: 2, day: 28 }));
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct UnspecifiedDate {
+    /// the four digits of the year
+    pub year: [Option<u8>; 4],
+    /// the two digits of the month, if the date has a month
+    pub month: Option<[Option<u8>; 2]>,
+    /// the two digits of the day, if the date has a day
+    pub day: Option<[Option<u8>; 2]>,
+}
+
+/// Whether the known digits of `mask` match the digits of `value`.
+fn matches_digits<const N: usize>(mask: &[Option<u8>; N], mut value: u32) -> bool {
+    let mut matches = true;
+    for digit in mask.iter().rev() {
+        matches &= digit.is_none_or(|d| u32::from(d) == value % 10);
+        value /= 10;
+    }
+    matches && value == 0
+}
+
+impl UnspecifiedDate {
+    /// The earliest valid date covered by this date, `None` if it covers no valid date.
+    pub fn earliest(&self) -> Option<Date> {
+        self.bound(false)
+    }
+
+    /// The latest valid date covered by this date, `None` if it covers no valid date.
+    pub fn latest(&self) -> Option<Date> {
+        self.bound(true)
+    }
+
+    // Searches all matching dates in ascending or descending order for the first valid one.
+    fn bound(&self, latest: bool) -> Option<Date> {
+        let order = |value: u32, max: u32, min: u32| {
+            if latest {
+                max - (value - min)
+            } else {
+                value
+            }
+        };
+
+        for year in (0..=9999).map(|y| order(y, 9999, 0)) {
+            if !matches_digits(&self.year, year) {
+                continue;
+            }
+            for month in (1..=12).map(|m| order(m, 12, 1)) {
+                if !self.month.is_none_or(|mask| matches_digits(&mask, month)) {
+                    continue;
+                }
+                let year = year as i32;
+                let days_in_month = calendar::days_in_month(year, month);
+                for day in (1..=days_in_month).map(|d| order(d, days_in_month, 1)) {
+                    if self.day.is_none_or(|mask| matches_digits(&mask, day)) {
+                        return Some(Date::YMD { year, month, day });
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl FromStr for UnspecifiedDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        unspecified_date(s)
+    }
+}
+
+/// Parses a date string with unspecified digits.
+///
+/// A string can have one of the following formats,
+/// where any digit may be replaced by an `X`:
+///
+/// * `2015-11-02`, a specific day
+/// * `2015-11`, a specific month
+/// * `2015`, a specific year
+///
+/// Only dates covering at least one valid date are accepted.
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::unspecified_date("2015-11-XX").unwrap();
+/// ```
+pub fn unspecified_date(string: &str) -> Result<UnspecifiedDate, ParseError> {
+    error::prefix(
+        Component::UnspecifiedDate,
+        string.as_bytes(),
+        parsers::parse_unspecified_date(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// Whether a [`DateSet`] stands for one of its members or for all of them.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum SetKind {
+    /// exactly one of the members, written in square brackets like `[1667, 1668]`
+    OneOf,
+    /// all of the members, written in curly brackets like `{1667, 1668}`
+    AllOf,
+}
+
+/// A member of a [`DateSet`], either a single date or a range of dates.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum SetMember {
+    /// a single date like `1667`
+    Date(EdtfDate),
+    /// all dates from a start up to and including an end, like `1670..1672`
+    Range {
+        /// The first date of the range
+        start: EdtfDate,
+        /// The last date of the range
+        end: EdtfDate,
+    },
+    /// a date or any earlier one, like `..1760-12`
+    OnOrBefore(EdtfDate),
+    /// a date or any later one, like `1760-12..`
+    OnOrAfter(EdtfDate),
+}
+
+impl SetMember {
+    /// Whether `date` lies within this member.
+    pub fn contains(&self, date: &Date) -> bool {
+        let days = date.days_since_epoch();
+        match self {
+            SetMember::Date(member) => {
+                member.first_day().days_since_epoch() <= days
+                    && days <= member.last_day().days_since_epoch()
+            }
+            SetMember::Range { start, end } => {
+                start.first_day().days_since_epoch() <= days
+                    && days <= end.last_day().days_since_epoch()
+            }
+            SetMember::OnOrBefore(end) => days <= end.last_day().days_since_epoch(),
+            SetMember::OnOrAfter(start) => start.first_day().days_since_epoch() <= days,
+        }
+    }
+}
+
+/// An EDTF set of dates, like `[1760-01, 1760-02, 1760-12..]` or `{1667, 1668, 1670..1672}`.
+/// ```
+/// # use std::str::FromStr;
+/// let set = iso8601::DateSet::from_str("{1667, 1670..1672}").unwrap();
+/// assert_eq!(set.kind, iso8601::SetKind::AllOf);
+/// assert_eq!(set.iter().count(), 2);
+/// assert!(set.contains(&iso8601::Date::YMD { year: 1671, month: 6, day: 1 }));
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct DateSet {
+    /// Whether the set stands for one of its members or for all of them
+    pub kind: SetKind,
+    /// The members of the set, in the order they were written
+    pub members: Vec<SetMember>,
+}
+
+impl DateSet {
+    /// Whether `date` lies within any member of this set.
+    pub fn contains(&self, date: &Date) -> bool {
+        self.members.iter().any(|member| member.contains(date))
+    }
+
+    /// An iterator over the members of this set.
+    pub fn iter(&self) -> slice::Iter<'_, SetMember> {
+        self.members.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a DateSet {
+    type Item = &'a SetMember;
+    type IntoIter = slice::Iter<'a, SetMember>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromStr for DateSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        date_set(s)
+    }
+}
+
+/// Parses an EDTF set of dates.
+///
+/// A set is a comma-separated list of members in brackets,
+/// where square brackets stand for one of the members
+/// and curly brackets for all of them.
+/// A member can have one of the following formats:
+///
+/// * `1760-12`, a single date, see [`qualified_date()`] for the supported formats
+/// * `1670..1672`, a range of dates
+/// * `..1760-12`, a date or any earlier one
+/// * `1760-12..`, a date or any later one
+///
+/// ## Example
+///
+/// ```rust
+/// let set = iso8601::date_set("[1760-01, 1760-02, 1760-12..]").unwrap();
+/// ```
+pub fn date_set(string: &str) -> Result<DateSet, ParseError> {
+    error::prefix(
+        Component::DateSet,
+        string.as_bytes(),
+        parsers::parse_date_set(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
+
+/// A year that may be too large for a [`Date`], written with a `Y` prefix like `Y170000002`.
+///
+/// The year may be given with an exponent like `Y17E7`,
+/// and with a number of significant digits like `Y17E7S3`,
+/// where only the first few digits of the year are to be taken as exact.
+/// ```
+/// # use std::str::FromStr;
+/// let year = iso8601::LongYear::from_str("Y17E7S3").unwrap();
+/// assert_eq!(year.year, 170_000_000);
+/// assert_eq!(year.exponent, Some(7));
+/// assert_eq!(year.significant_digits, Some(3));
+/// assert_eq!(year.earliest(), 170_000_000);
+/// assert_eq!(year.latest(), 170_999_999);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct LongYear {
+    /// the year, with the exponent already applied
+    pub year: i64,
+    /// the exponent of ten the year was written with, like the `7` in `Y17E7`
+    pub exponent: Option<u32>,
+    /// how many of the leading digits of the year are exact, like the `3` in `Y17E7S3`
+    pub significant_digits: Option<u32>,
+}
+
+impl LongYear {
+    /// The number of years covered by each of the possible values of this year,
+    /// one unless only some of its digits are significant.
+    fn precision(&self) -> i64 {
+        let digits = self.year.unsigned_abs().checked_ilog10().unwrap_or(0) + 1;
+        match self.significant_digits {
+            Some(significant) if significant < digits => {
+                10i64.checked_pow(digits - significant).unwrap_or(i64::MAX)
+            }
+            _ => 1,
+        }
+    }
+
+    /// The earliest year this year may stand for, given its significant digits.
+    pub fn earliest(&self) -> i64 {
+        let precision = self.precision();
+        if self.year < 0 {
+            self.year
+                .saturating_sub(precision - 1 - (self.year % precision).abs())
+        } else {
+            self.year - self.year % precision
+        }
+    }
+
+    /// The latest year this year may stand for, given its significant digits.
+    pub fn latest(&self) -> i64 {
+        self.earliest().saturating_add(self.precision() - 1)
+    }
+}
+
+impl FromStr for LongYear {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        long_year(s)
+    }
+}
+
+/// Parses a year with a `Y` prefix.
+///
+/// A string can have one of the following formats:
+///
+/// * `Y170000002` or `Y-170000002`, a year with more than four digits
+/// * `Y17E7`, a year with an exponent, standing for 170000000
+///
+/// Each may be followed by a number of significant digits, like `Y17E7S3`.
+///
+/// ## Example
+///
+/// ```rust
+/// let year = iso8601::long_year("Y-17E7").unwrap();
+/// assert_eq!(year.year, -170_000_000);
+/// ```
+pub fn long_year(string: &str) -> Result<LongYear, ParseError> {
+    error::prefix(
+        Component::LongYear,
+        string.as_bytes(),
+        parsers::parse_long_year(string.as_bytes()),
+    )
+    .map(|(parsed, _)| parsed)
+}
@@ -0,0 +1,265 @@
+use alloc::string::String;
+use core::ops::Range;
+
+use crate::parsers::IResult;
+
+/// Why a string could not be parsed.
+///
+/// ```
+/// use iso8601::{Component, ErrorKind, Expected, ParseError};
+///
+/// let error = iso8601::datetime_rfc3339("2023-02-08T23:40:00Z trailing").unwrap_err();
+/// assert_eq!(error.component, Component::Rfc3339DateTime);
+/// assert_eq!(error.expected, Expected::End);
+/// assert_eq!(error.kind, ErrorKind::TrailingInput);
+/// assert_eq!(error.offset, 20);
+///
+/// let error = iso8601::date("2023-13-01").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::InvalidMonth);
+/// assert_eq!(error.offset, 5);
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ParseError {
+    /// What was being parsed
+    pub component: Component,
+    /// The position in bytes at which the input could not be parsed any further
+    pub offset: usize,
+    /// What was expected at that position
+    pub expected: Expected,
+    /// Why the input was rejected at that position
+    pub kind: ErrorKind,
+    /// The whole input, with invalid UTF-8 replaced
+    pub input: String,
+}
+
+/// What kind of value a [`ParseError`] happened in, named after the function parsing it.
+#[allow(missing_docs)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum Component {
+    Date,
+    ExpandedDate,
+    ReducedDate,
+    TruncatedDate,
+    Week,
+    Time,
+    Offset,
+    DateTime,
+    Rfc3339DateTime,
+    HtmlDateTime,
+    PythonDateTime,
+    IxdtfDateTime,
+    TemporalDateTime,
+    Duration,
+    Interval,
+    RepeatingInterval,
+    /// Any of a datetime, date, time, duration or interval, see [`parse_any()`](crate::parse_any)
+    Any,
+    QualifiedDate,
+    SubYearGrouping,
+    UnspecifiedDate,
+    DateSet,
+    LongYear,
+}
+
+/// What a [`ParseError`] expected at its offset.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum Expected {
+    /// Input following the format of the component
+    Format,
+    /// The end of the input, as the component was complete
+    End,
+    /// A date that exists in the calendar, see [`Date::is_valid()`](crate::Date::is_valid)
+    ExistingDate,
+}
+
+/// Why a [`ParseError`] happened, for callers to branch on.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input does not follow the format, like a missing separator or digit
+    Syntax,
+    /// A month outside of `01` to `12`
+    InvalidMonth,
+    /// A day of the month outside of `01` to `31`
+    InvalidDay,
+    /// A week outside of `01` to `53`
+    InvalidWeek,
+    /// A day of the week outside of `1` to `7`
+    InvalidWeekday,
+    /// A day of the year outside of `001` to `366`
+    InvalidOrdinal,
+    /// An hour outside of `00` to `24`, or `23` in formats without the end of a day
+    InvalidHour,
+    /// A minute outside of `00` to `59`
+    InvalidMinute,
+    /// A second outside of `00` to `60`
+    InvalidSecond,
+    /// A fraction that cannot be written, like a billion nanoseconds,
+    /// or a fraction of a duration's unit that is not its smallest
+    InvalidFraction,
+    /// A fraction of a second with more digits than
+    /// [`ParserConfig::max_fraction_digits`](crate::ParserConfig::max_fraction_digits),
+    /// or of a duration's seconds with non-zero digits beyond milliseconds,
+    /// when [`ExcessDigits::Reject`](crate::ExcessDigits::Reject) is set
+    ExcessFractionDigits,
+    /// A time or datetime mixing the basic and the extended format, like `2023-02-08T234000`,
+    /// see [`ParserConfig::consistent_format`](crate::ParserConfig::consistent_format)
+    MixedFormat,
+    /// A sub-year grouping outside of `21` to `41`
+    InvalidGrouping,
+    /// An offset hour or minute outside of its range
+    OffsetOutOfRange,
+    /// A number too large for its type
+    NumberTooLarge,
+    /// Input following a complete value, see [`Expected::End`]
+    TrailingInput,
+    /// A date that does not exist in the calendar, see [`Expected::ExistingDate`]
+    NonexistentDate,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// An error at the start of `rest`, which is the end of `input`.
+    pub(crate) fn new(
+        component: Component,
+        input: &[u8],
+        rest: &[u8],
+        expected: Expected,
+    ) -> ParseError {
+        ParseError {
+            component,
+            offset: input.len() - rest.len(),
+            expected,
+            kind: match expected {
+                Expected::Format => ErrorKind::Syntax,
+                Expected::End => ErrorKind::TrailingInput,
+                Expected::ExistingDate => ErrorKind::NonexistentDate,
+            },
+            input: String::from_utf8_lossy(input).into_owned(),
+        }
+    }
+
+    /// The bytes of the input the error is about, like the two digits of an invalid month,
+    /// or everything following a complete value.
+    pub fn span(&self) -> Range<usize> {
+        let start = self.offset.min(self.input.len());
+        let rest = &self.input.as_bytes()[start..];
+        let width = match self.kind {
+            ErrorKind::Syntax
+            | ErrorKind::InvalidWeekday
+            | ErrorKind::InvalidFraction
+            | ErrorKind::MixedFormat => 1,
+            ErrorKind::InvalidMonth
+            | ErrorKind::InvalidDay
+            | ErrorKind::InvalidWeek
+            | ErrorKind::InvalidHour
+            | ErrorKind::InvalidMinute
+            | ErrorKind::InvalidSecond
+            | ErrorKind::InvalidGrouping
+            | ErrorKind::OffsetOutOfRange => 2,
+            ErrorKind::InvalidOrdinal => 3,
+            ErrorKind::NumberTooLarge | ErrorKind::ExcessFractionDigits => {
+                rest.iter().take_while(|b| b.is_ascii_digit()).count()
+            }
+            ErrorKind::TrailingInput | ErrorKind::NonexistentDate => rest.len(),
+        };
+        start..start + width.min(rest.len())
+    }
+
+    /// The input with its [`span()`](ParseError::span) underlined, for showing to users.
+    ///
+    /// ```
+    /// let error = iso8601::date("2023-13-01").unwrap_err();
+    /// assert_eq!(
+    ///     error.snippet().to_string(),
+    ///     "2023-13-01\n     ^^ month out of range"
+    /// );
+    /// ```
+    pub fn snippet(&self) -> Snippet<'_> {
+        Snippet(self)
+    }
+}
+
+/// A [`ParseError`] shown as its input with a caret under the problem,
+/// see [`ParseError::snippet()`].
+#[derive(Debug, Copy, Clone)]
+pub struct Snippet<'a>(pub(crate) &'a ParseError);
+
+/// The value parsed from the start of `input`, along with the rest following it.
+pub(crate) fn prefix<'a, T>(
+    component: Component,
+    input: &'a [u8],
+    result: IResult<&'a [u8], T>,
+) -> Result<(T, &'a [u8]), ParseError> {
+    match result {
+        Ok((rest, parsed)) => Ok((parsed, rest)),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let expected = match e.kind {
+                Some(ErrorKind::NonexistentDate) => Expected::ExistingDate,
+                _ => Expected::Format,
+            };
+            Err(ParseError {
+                kind: e.kind.unwrap_or(ErrorKind::Syntax),
+                ..ParseError::new(component, input, e.input, expected)
+            })
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            Err(ParseError::new(component, input, &[], Expected::Format))
+        }
+    }
+}
+
+/// The value parsed from all of `input`.
+pub(crate) fn complete<'a, T>(
+    component: Component,
+    input: &'a [u8],
+    result: IResult<&'a [u8], T>,
+) -> Result<T, ParseError> {
+    match prefix(component, input, result)? {
+        (parsed, []) => Ok(parsed),
+        (_, rest) => Err(ParseError::new(component, input, rest, Expected::End)),
+    }
+}
+
+/// One of all problems found in a string by the `diagnose_*` methods of
+/// [`ParserConfig`](crate::ParserConfig).
+///
+/// ```
+/// use iso8601::{Diagnostic, ErrorKind, ParserConfig, Profile};
+///
+/// let config = ParserConfig::from(Profile::Rfc3339);
+/// assert_eq!(
+///     config.diagnose_datetime("2023-13-08T23:40:00+25:00"),
+///     vec![
+///         Diagnostic { kind: ErrorKind::InvalidMonth, span: 5..7 },
+///         Diagnostic { kind: ErrorKind::OffsetOutOfRange, span: 20..22 },
+///     ]
+/// );
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct Diagnostic {
+    /// What is wrong
+    pub kind: ErrorKind,
+    /// The bytes of the input that are wrong
+    pub span: Range<usize>,
+}
+
+/// Why a value could not be converted into another type,
+/// like a [`Duration`](crate::Duration) into a [`core::time::Duration`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum ConversionError {
+    /// The value does not exist in the calendar, like `2023-02-30`
+    Invalid,
+    /// The value is negative or too large for the other type
+    OutOfRange,
+    /// The datetime has no offset, see [`MissingOffsetPolicy::Error`](crate::MissingOffsetPolicy::Error)
+    MissingOffset,
+    /// The datetime is skipped or repeated in the local time zone,
+    /// see [`MissingOffsetPolicy::AssumeLocal`](crate::MissingOffsetPolicy::AssumeLocal)
+    AmbiguousLocalTime,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
@@ -0,0 +1,336 @@
+//! A fuzz target for checking that parsing never panics and that written values parse again.
+
+use alloc::string::ToString;
+use core::fmt::Display;
+use core::str;
+
+use crate::{ParseError, ParserConfig, Profile, Strictness};
+
+/// Parses `data` with every parser of this crate, writes whatever parses back into a string
+/// and checks that it parses again into a value that is written the same.
+///
+/// It panics if the written value does not parse like that,
+/// and everything the crate does with `data` on the way is meant not to panic either,
+/// so any panic is a bug. Use it as the body of a fuzz target, like with `cargo fuzz`:
+///
+/// ```ignore
+/// libfuzzer_sys::fuzz_target!(|data: &[u8]| iso8601::fuzz_roundtrip(data));
+/// ```
+///
+/// Only available with the `fuzzing` feature.
+pub fn fuzz_roundtrip(data: &[u8]) {
+    // the parsers of bytes have to agree with those of strings
+    let string = match str::from_utf8(data) {
+        Ok(string) => string,
+        Err(_) => {
+            check_error(&crate::date_bytes(data));
+            check_error(&crate::time_bytes(data));
+            check_error(&crate::datetime_bytes(data));
+            check_error(&crate::offset_bytes(data));
+            check_error(&crate::duration_bytes(data));
+            check_error(&crate::interval_bytes(data));
+            check_error(&crate::repeating_interval_bytes(data));
+            return;
+        }
+    };
+    assert_eq!(crate::date_bytes(data), crate::date(string));
+    assert_eq!(crate::time_bytes(data), crate::time(string));
+    assert_eq!(crate::datetime_bytes(data), crate::datetime(string));
+    assert_eq!(crate::duration_bytes(data), crate::duration(string));
+
+    if let Some(date) = roundtrip(string, crate::date) {
+        let _ = (
+            date.validate(),
+            date.violations(),
+            date.canonical(),
+            date.precision(),
+            date.weekday(),
+            date.ordinal(),
+            date.iso_week(),
+            date.to_week_date(),
+            date.to_ordinal(),
+            date.year(),
+            date.month(),
+            date.day(),
+        );
+        let _ = (
+            date.add_days(i64::MIN),
+            date.add_months(-1, crate::OverflowPolicy::Overflow),
+            date.add_years(i64::MAX, crate::OverflowPolicy::Reject),
+            date.age_until(&crate::Date::MIN),
+            date.saturating_add_days(i64::MAX),
+            crate::Date::from_julian_day(date.to_julian_day()),
+            crate::Date::from_modified_julian_day(date.to_modified_julian_day()),
+            crate::Date::from_days(
+                date.to_days_since(crate::Epoch::RataDie),
+                crate::Epoch::Unix,
+            ),
+            date.saturating_add_months(i64::MIN, crate::OverflowPolicy::Constrain),
+        );
+    }
+    if let Some(time) = roundtrip(string, crate::time) {
+        let _ = (time.violations(), time.normalize_midnight());
+        let _ = time.add(&crate::Duration::Weeks(i64::MIN));
+        let _ = time.truncate_to(crate::Unit::Minute);
+        let _ = (
+            time.seconds_of_day(),
+            time.nanos_of_day(),
+            time.day_fraction(),
+        );
+    }
+    if let Some(dt) = roundtrip(string, crate::datetime) {
+        let _ = (dt.validate(), dt.violations(), dt.normalize_midnight());
+        let _ = (
+            dt.checked_add(&crate::Duration::Weeks(i64::MIN)),
+            dt.since(&crate::DateTime::MIN),
+            dt.saturating_sub(&crate::Duration::Weeks(i64::MAX)),
+            dt.truncate_to(crate::Unit::Week),
+            dt.truncate_to(crate::Unit::Month),
+            dt.round_to(crate::Unit::Year, crate::RoundingMode::Nearest),
+            dt.round_to_increment(15, crate::Unit::Minute, crate::RoundingMode::Ceil),
+        );
+        let (seconds, nanoseconds) = dt.to_unix();
+        let _ = crate::DateTime::from_unix(seconds, nanoseconds, dt.offset());
+        let _ = dt.with_offset(crate::UtcOffset::Fixed { seconds: -86_399 });
+        let _ =
+            crate::DateTime::from_modified_julian_date(dt.to_modified_julian_date(), dt.offset());
+        let _ = (
+            dt.cmp(&crate::DateTime::MAX),
+            dt.cmp_instant(&crate::DateTime::MIN),
+        );
+    }
+    reparse(string, crate::datetime_lenient, crate::datetime);
+    reparse(string, crate::datetime_rfc3339, crate::datetime);
+    reparse(string, crate::datetime_html, crate::datetime);
+    reparse(string, crate::datetime_python, crate::datetime);
+    roundtrip(string, crate::datetime_ixdtf);
+    #[cfg(feature = "temporal")]
+    roundtrip(string, crate::datetime_temporal);
+    roundtrip(string, crate::offset);
+    if let Some(duration) = roundtrip(string, crate::duration) {
+        let _ = (duration.violations(), duration.total_seconds(), -duration);
+        let _ = (
+            crate::DateTime::MIN.checked_sub(&duration),
+            crate::DateTime::MAX.checked_add(&duration),
+        );
+        let _ = ::core::time::Duration::try_from(duration);
+        let _ = (
+            duration.normalize(crate::Unit::Year),
+            duration.normalize(crate::Unit::Second),
+        );
+        let _ = (duration * u32::MAX, duration / 0, duration / 7);
+        let _ = (duration.checked_div(3), duration.div_duration(&duration));
+        let _ = (
+            duration + duration,
+            duration - -duration,
+            duration.checked_sub(&duration),
+        );
+    }
+    roundtrip(string, crate::duration_lenient);
+    roundtrip(string, crate::interval);
+    roundtrip(string, crate::repeating_interval);
+    roundtrip(string, crate::reduced_date);
+    roundtrip(string, crate::week);
+    roundtrip(string, crate::truncated_date);
+    roundtrip(string, crate::parse_any);
+    for extra_digits in 0..3 {
+        check_error(&crate::date_expanded(string, extra_digits));
+    }
+
+    #[cfg(feature = "edtf")]
+    {
+        roundtrip(string, crate::qualified_date);
+        roundtrip(string, crate::sub_year_grouping);
+        roundtrip(string, crate::unspecified_date);
+        roundtrip(string, crate::date_set);
+        roundtrip(string, crate::long_year);
+    }
+
+    let profiles = [
+        Profile::Strict8601,
+        Profile::Rfc3339,
+        Profile::Html,
+        Profile::Sql,
+        Profile::Python,
+        Profile::Lenient,
+    ]
+    .map(ParserConfig::from);
+    let strictness = [Strictness::Pedantic, Strictness::Standard].map(ParserConfig::from);
+    for config in profiles.into_iter().chain(strictness) {
+        check_error(&config.date(string));
+        check_error(&config.time(string));
+        check_error(&config.datetime(string));
+        check_error(&config.duration(string));
+        check_error(&config.interval(string));
+        check_error(&config.parse_any(string));
+        check_error(&config.date_expanded(string, 2));
+        check_error(&config.reduced_date(string));
+        check_error(&config.week(string));
+        check_error(&config.truncated_date(string));
+        check_error(&config.datetime_ixdtf(string));
+        assert_eq!(
+            crate::DateTime::parse_with(string, config),
+            config.datetime(string)
+        );
+        assert_eq!(
+            crate::Iso8601::parse_with(string, config),
+            config.parse_any(string)
+        );
+        #[cfg(feature = "temporal")]
+        check_error(&config.datetime_temporal(string));
+        #[cfg(feature = "edtf")]
+        {
+            check_error(&config.qualified_date(string));
+            check_error(&config.sub_year_grouping(string));
+            check_error(&config.unspecified_date(string));
+            check_error(&config.date_set(string));
+            check_error(&config.long_year(string));
+        }
+        // the diagnostics find nothing exactly when parsing succeeds
+        assert_eq!(
+            config.diagnose_datetime(string).is_empty(),
+            config.datetime(string).is_ok()
+        );
+    }
+}
+
+// Parses `string`, and whatever parsed again from how it is written,
+// which may have more components than the value, like the seconds of `23:40`
+fn roundtrip<T: Display>(string: &str, parse: impl Fn(&str) -> Result<T, ParseError>) -> Option<T> {
+    reparse(string, &parse, &parse)
+}
+
+// Like `roundtrip`, for a dialect that is written in the ISO 8601 format of `reparse`
+fn reparse<T: Display, U: Display>(
+    string: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+    reparse: impl Fn(&str) -> Result<U, ParseError>,
+) -> Option<T> {
+    let parsed = parse(string);
+    check_error(&parsed);
+    let parsed = parsed.ok()?;
+    let written = parsed.to_string();
+    // the number of digits of expanded years has to be agreed on to parse them
+    if has_expanded_year(&written) {
+        return Some(parsed);
+    }
+    match reparse(&written) {
+        Ok(reparsed) => assert_eq!(
+            written,
+            reparsed.to_string(),
+            "{:?} was written as {:?}",
+            string,
+            written
+        ),
+        Err(e) => panic!(
+            "{:?} was written as {:?}, which fails: {}",
+            string, written, e
+        ),
+    }
+    Some(parsed)
+}
+
+// Whether `written` has a year of more than four digits, like `+12345-06-07`
+fn has_expanded_year(written: &str) -> bool {
+    let bytes = written.as_bytes();
+    bytes.iter().enumerate().any(|(i, &b)| {
+        let digits = bytes[i + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        let after = bytes.get(i + 1 + digits);
+        (b == b'+' || b == b'-') && digits > 4 && matches!(after, None | Some(b'-' | b'T' | b'/'))
+    })
+}
+
+// Shows an error the ways users see it
+fn check_error<T>(result: &Result<T, ParseError>) {
+    if let Err(e) = result {
+        let _ = (e.to_string(), e.snippet().to_string(), e.span());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzz_roundtrip;
+    use alloc::vec::Vec;
+
+    // Values of all formats, to be mutated
+    const SEEDS: &[&str] = &[
+        "2015-06-26T16:43:23+0200",
+        "2023-02-08T23:40:00.123456789Z",
+        "-0333-07-11",
+        "+12345-06-07",
+        "2023-W06-3",
+        "2023-039",
+        "20230208T234000-0530",
+        "23:59:60.5+23:59:59",
+        "24:00:00",
+        "T12,5",
+        "P1Y2M3DT4H5M6.789S",
+        "-PT1,5H",
+        "P1M-3D",
+        "P0003-05-00T00:00:00",
+        "P1W2D",
+        "R5/2023-02-08/P1D",
+        "2023-02-08/..",
+        "--02-08",
+        "2023-02",
+        "2023-02-08?",
+        "2023-21",
+        "201X-XX",
+        "[1760-01, 1760-12..]",
+        "Y-17E7S3",
+        "2023-02-08T23:40:00+01:00[Europe/Berlin][u-ca=gregory]",
+        "\u{2212}05:30",
+        "PT99999999999999999999S",
+        "P9223372036854775807Y9223372036854775807M",
+        "-2147483648-01-01",
+        "2147483647-12-31T23:59:59.999999999",
+        "Y9223372036854775807E18S0",
+    ];
+
+    // A tiny xorshift generator, to mutate the same way on every run
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn seeds_roundtrip() {
+        for seed in SEEDS {
+            fuzz_roundtrip(seed.as_bytes());
+        }
+        fuzz_roundtrip(b"");
+        fuzz_roundtrip(b"\xff2023-02-08");
+    }
+
+    #[test]
+    fn mutations_roundtrip() {
+        const BYTES: &[u8] = b"0123456789990-+:.,/TZWPYMDHSRXE~?%[]{} tz\xe2\x88\x92";
+        let mut rng = Rng(0x2023_0208_2340);
+
+        for _ in 0..5_000 {
+            let mut data: Vec<u8> = SEEDS[rng.next(SEEDS.len())].as_bytes().to_vec();
+            for _ in 0..=rng.next(4) {
+                let at = rng.next(data.len() + 1);
+                let byte = BYTES[rng.next(BYTES.len())];
+                match rng.next(4) {
+                    0 => data.insert(at, byte),
+                    1 if at < data.len() => data[at] = byte,
+                    2 if at < data.len() => {
+                        data.remove(at);
+                    }
+                    _ => data.truncate(at),
+                }
+            }
+            fuzz_roundtrip(&data);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use core::str::FromStr;
+
+use crate::{error, parsers, Component, Date, DateTime, Duration, ParseError};
+
+/// One end of an [`Interval`], either a calendar date or a full datetime.
+///
+/// The start or end of an interval may also be open or unknown, as specified by ISO 8601-2.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Bound {
+    /// A calendar date, like `2023-02-08`
+    Date(Date),
+    /// A date and time, like `2023-02-08T23:40:00Z`
+    DateTime(DateTime),
+    /// An open end without a limit, written as `..` like in `2023-02-08/..`
+    Open,
+    /// An end that exists but is not known, left empty like in `/2023-02-08`
+    Unknown,
+}
+
+impl Bound {
+    /// Whether this bound is a known date or datetime, neither open nor unknown.
+    pub fn is_known(&self) -> bool {
+        matches!(self, Bound::Date(_) | Bound::DateTime(_))
+    }
+
+    /// Whether the date of this bound exists in the calendar, see [`Date::is_valid()`].
+    ///
+    /// Open and unknown bounds are always valid.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Bound::Date(date) => date.is_valid(),
+            Bound::DateTime(datetime) => datetime.is_valid(),
+            Bound::Open | Bound::Unknown => true,
+        }
+    }
+}
+
+/// A time interval.
+///
+/// Intervals are written as two parts separated by a `/`,
+/// see [`interval()`][`crate::interval()`] for the supported formats.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::Interval::from_str("2023-02-08/P1D"),
+///     Ok(iso8601::Interval::StartDuration {
+///         start: iso8601::Bound::Date(iso8601::Date::YMD { year: 2023, month: 2, day: 8 }),
+///         duration: iso8601::Duration::YMDHMS { year: 0, month: 0, day: 1, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None },
+///     })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Interval {
+    /// consists of a start and an end, like `2023-02-08/2023-02-10`
+    StartEnd {
+        /// The start of the interval
+        start: Bound,
+        /// The end of the interval
+        end: Bound,
+    },
+    /// consists of a start and a duration, like `2023-02-08/P2D`
+    StartDuration {
+        /// The start of the interval
+        start: Bound,
+        /// The length of the interval
+        duration: Duration,
+    },
+    /// consists of a duration and an end, like `P2D/2023-02-10`
+    DurationEnd {
+        /// The length of the interval
+        duration: Duration,
+        /// The end of the interval
+        end: Bound,
+    },
+    /// consists of a duration only, without any context, like `P2D`
+    Duration(Duration),
+}
+
+impl Interval {
+    /// Whether the dates of the bounds of this interval exist in the calendar,
+    /// see [`Bound::is_valid()`].
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Interval::StartEnd { start, end } => start.is_valid() && end.is_valid(),
+            Interval::StartDuration { start: bound, .. }
+            | Interval::DurationEnd { end: bound, .. } => bound.is_valid(),
+            Interval::Duration(_) => true,
+        }
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        interval(s)
+    }
+}
+
+/// A recurring time interval.
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::RepeatingInterval::from_str("R5/P1D"),
+///     Ok(iso8601::RepeatingInterval {
+///         count: Some(5),
+///         interval: iso8601::Interval::Duration(
+///             iso8601::Duration::YMDHMS { year: 0, month: 0, day: 1, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
+///         ),
+///     })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct RepeatingInterval {
+    /// How many times the interval repeats, `None` for an unbounded number of repetitions
+    pub count: Option<u32>,
+    /// The interval that is repeated
+    pub interval: Interval,
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        repeating_interval(s)
+    }
+}
+
+/// Parses an interval string.
+///
+/// A string can have one of the following formats:
+///
+/// * Start and end: `2023-02-08T23:40:00Z/2023-02-10T08:00:00Z`
+/// * Start and duration: `2023-02-08T23:40:00Z/P1DT8H`
+/// * Duration and end: `P1DT8H/2023-02-10T08:00:00Z`
+/// * Duration only: `P1DT8H`
+///
+/// The start and end can either be a datetime or a date, see
+/// [`datetime()`][`crate::datetime()`] and [`date()`][`crate::date()`] for their formats.
+///
+/// Either the start or the end of a start and end interval may also be
+/// open, like `2023-02-08/..` and `../2023-02-08`,
+/// or unknown, like `2023-02-08/` and `/2023-02-08`.
+/// The other one has to be a datetime or a date.
+///
+/// ## Example
+///
+/// ```rust
+/// let interval = iso8601::interval("2023-02-08/2023-02-10").unwrap();
+/// let interval = iso8601::interval("2023-02-08/..").unwrap();
+///
+/// // the whole string has to be an interval, see `interval_prefix()` otherwise
+/// assert!(iso8601::interval("P1D/P2D").is_err());
+/// ```
+pub fn interval(string: &str) -> Result<Interval, ParseError> {
+    interval_bytes(string.as_bytes())
+}
+
+/// Parses an interval from bytes, like [`interval()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let interval = iso8601::interval_bytes(b"2023-02-08/P1D").unwrap();
+/// ```
+pub fn interval_bytes(bytes: &[u8]) -> Result<Interval, ParseError> {
+    error::complete(Component::Interval, bytes, parsers::parse_interval(bytes))
+}
+
+/// Parses an interval at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`interval()`], the interval may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (interval, rest) = iso8601::interval_prefix("2023-02-08/P1D;2023-02-10/P1D").unwrap();
+/// assert_eq!(rest, ";2023-02-10/P1D");
+/// ```
+pub fn interval_prefix(string: &str) -> Result<(Interval, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::Interval,
+        string.as_bytes(),
+        parsers::parse_interval(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
+}
+
+/// Parses a repeating interval string.
+///
+/// A repeating interval is an interval prefixed by `R`, an optional number of
+/// repetitions and a `/`:
+///
+/// * Repeated a fixed number of times: `R5/2023-02-08T00:00:00Z/P1D`
+/// * Repeated without bounds: `R/2023-02-08T00:00:00Z/P1D`
+///
+/// See [`interval()`][`crate::interval()`] for the supported interval formats.
+///
+/// ## Example
+///
+/// ```rust
+/// let repeating = iso8601::repeating_interval("R5/2023-02-08T00:00:00Z/P1D").unwrap();
+/// ```
+pub fn repeating_interval(string: &str) -> Result<RepeatingInterval, ParseError> {
+    repeating_interval_bytes(string.as_bytes())
+}
+
+/// Parses a repeating interval from bytes, like [`repeating_interval()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let repeating = iso8601::repeating_interval_bytes(b"R5/2023-02-08/P1D").unwrap();
+/// ```
+pub fn repeating_interval_bytes(bytes: &[u8]) -> Result<RepeatingInterval, ParseError> {
+    error::complete(
+        Component::RepeatingInterval,
+        bytes,
+        parsers::parse_repeating_interval(bytes),
+    )
+}
@@ -0,0 +1,104 @@
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+use crate::{error, parsers, Component, DateTime, ParseError};
+
+/// The time zone suffix of an [`IxdtfDateTime`], like `[Europe/Berlin]`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct TimeZoneAnnotation {
+    /// The name of the time zone like `Europe/Berlin`, or a numeric offset like `+01:00`
+    pub name: String,
+    /// Whether the time zone is marked as critical with a `!`, like `[!Europe/Berlin]`
+    pub critical: bool,
+}
+
+/// A key and value suffix of an [`IxdtfDateTime`], like `[u-ca=gregory]`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Annotation {
+    /// The key of the annotation, like `u-ca`
+    pub key: String,
+    /// The value of the annotation, like `gregory`
+    pub value: String,
+    /// Whether the annotation is marked as critical with a `!`, like `[!u-ca=gregory]`
+    pub critical: bool,
+}
+
+/// A datetime in the Internet Extended Date/Time Format (IXDTF) of RFC 9557,
+/// an RFC 3339 datetime followed by suffixes in square brackets.
+/// ```
+/// # use std::str::FromStr;
+/// let datetime =
+///     iso8601::IxdtfDateTime::from_str("2023-02-08T23:40:00+01:00[Europe/Berlin][u-ca=gregory]")
+///         .unwrap();
+/// assert_eq!(datetime.time_zone.unwrap().name, "Europe/Berlin");
+/// assert_eq!(datetime.annotations[0].key, "u-ca");
+/// assert_eq!(datetime.annotations[0].value, "gregory");
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct IxdtfDateTime {
+    /// The datetime without its suffixes
+    pub datetime: DateTime,
+    /// The time zone suffix, if any
+    pub time_zone: Option<TimeZoneAnnotation>,
+    /// The key and value suffixes following the time zone, in the order they were written
+    pub annotations: Vec<Annotation>,
+}
+
+impl FromStr for IxdtfDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        datetime_ixdtf(s)
+    }
+}
+
+/// Parses a datetime string in the Internet Extended Date/Time Format (IXDTF) of RFC 9557.
+///
+/// The string starts with a datetime as accepted by [`datetime_rfc3339()`][`crate::datetime_rfc3339()`],
+/// followed by:
+///
+/// * an optional time zone suffix, either a name like `[Europe/Berlin]` or an offset like `[+01:00]`
+/// * any number of key and value suffixes, like `[u-ca=gregory]`
+///
+/// Each suffix may be marked as critical with a `!`, like `[!u-ca=gregory]`.
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_ixdtf("2023-02-08T23:40:00+01:00[Europe/Berlin][u-ca=gregory]").unwrap();
+/// ```
+pub fn datetime_ixdtf(string: &str) -> Result<IxdtfDateTime, ParseError> {
+    error::complete(
+        Component::IxdtfDateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_ixdtf(string.as_bytes()),
+    )
+}
+
+/// Parses a datetime string in the grammar of the ECMAScript Temporal API,
+/// like the output of `Temporal.ZonedDateTime.prototype.toString()`.
+///
+/// Temporal strings are a superset of [`datetime_ixdtf()`]:
+///
+/// * the year may also be expanded to a sign and six digits, like `+002023-02-08` or `-000001-02-08`
+/// * the date and time may also be in the basic format, like `20230208T234000`
+/// * the time may leave out the seconds or minutes, like `T23:40` or `T23`
+/// * the offset may be left out or carry seconds, like `+00:19:32`
+///
+/// It is followed by the same time zone and key and value suffixes, like `[!UTC]` or `[u-ca=hebrew]`.
+/// The hour 24 and trailing input are rejected.
+///
+/// ## Example
+///
+/// ```rust
+/// let dt = iso8601::datetime_temporal("2023-02-08T23:40:00.123456789+01:00[!Europe/Berlin][u-ca=iso8601]").unwrap();
+/// assert!(dt.time_zone.unwrap().critical);
+/// ```
+#[cfg(feature = "temporal")]
+pub fn datetime_temporal(string: &str) -> Result<IxdtfDateTime, ParseError> {
+    error::complete(
+        Component::TemporalDateTime,
+        string.as_bytes(),
+        parsers::parse_datetime_temporal(string.as_bytes()),
+    )
+}
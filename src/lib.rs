@@ -14,7 +14,40 @@
 //! let date = "2015-02-29".parse::<iso8601::Date>().unwrap();
 //! let datetime = "2015-06-26T16:43:23+0200".parse::<iso8601::DateTime>().unwrap();
 //! let duration = "P2021Y11M16DT23H26M59.123S".parse::<iso8601::Duration>().unwrap();
+//! let interval = "2015-06-26T16:43:23+0200/P1D".parse::<iso8601::Interval>().unwrap();
 //! ```
+//!
+//! # Features
+//!
+//! * `std` (default): implements `std::error::Error` for the error types
+//!   and lets the chrono conversions use the local time zone
+//! * `legacy-fields` (default): makes the fields of [`Time`] and [`DateTime`] public
+//! * `chrono`: converts into and from the types of [chrono](https://docs.rs/chrono)
+//! * `serde`: serializes and deserializes as strings with [serde](https://docs.rs/serde)
+//! * `edtf`: parses the Extended Date/Time Format of ISO 8601-2
+//! * `temporal`: parses the strings of the ECMAScript Temporal API
+//! * `arbitrary`: implements [arbitrary](https://docs.rs/arbitrary)'s `Arbitrary`
+//!   for [`Date`], [`Time`], [`DateTime`] and [`Duration`], for structured fuzzing
+//! * `proptest`: adds the `strategies` module of [proptest](https://docs.rs/proptest) strategies
+//!   for generating values and their strings, which needs `std`
+//! * `fuzzing`: adds `fuzz_roundtrip()` as a fuzz target for checking the parsers
+//!
+//! Without the default features, the crate only depends on nom and works without `std`.
+//!
+//! # Allocations
+//!
+//! Parsing does not allocate on the heap unless it fails,
+//! when the [`ParseError`] keeps a copy of the input.
+//! The exceptions are values that hold strings or lists themselves,
+//! like the annotations of an [`IxdtfDateTime`] or the EDTF `DateSet`,
+//! and [`ParserConfig::case_insensitive`] with lowercase input, which is uppercased first.
+//!
+//! # Panics
+//!
+//! No function of this crate panics, whatever it is given,
+//! including values built by hand with components out of range.
+//! Arithmetic on such values saturates, and conversions fail instead.
+//! The `fuzzing` feature provides a fuzz target to check the parsers with.
 
 #![allow(clippy::uninlined_format_args)]
 #![deny(
@@ -33,23 +66,70 @@
 #[macro_use]
 extern crate std;
 
-#[macro_use]
 extern crate alloc;
 
+pub mod calendar;
 mod display;
+mod error;
+pub use error::{Component, ConversionError, Diagnostic, ErrorKind, Expected, ParseError, Snippet};
 pub mod parsers;
 
 mod date;
-pub use date::{date, Date};
+pub use date::{
+    date, date_bytes, date_expanded, date_prefix, reduced_date, truncated_date, week, Date,
+    DateError, DateRange, Epoch, OverflowPolicy, ReducedDate, TruncatedDate, ValidDate, Week,
+    Weekday,
+};
 
+#[macro_use]
 mod time;
-pub use time::{time, Time};
+pub use time::{
+    time, time_bytes, time_prefix, LeapSecondPolicy, Precision, Time, TimeBuilder, TimeError,
+};
+
+mod offset;
+pub use offset::{offset, offset_bytes, offset_prefix, MissingOffsetPolicy, UtcOffset};
 
 mod datetime;
-pub use datetime::{datetime, DateTime};
+pub use datetime::{
+    datetime, datetime_bytes, datetime_html, datetime_lenient, datetime_prefix, datetime_python,
+    datetime_rfc3339, DateTime, DateTimeBuilder, DateTimeError, RoundingMode, ValidDateTime,
+};
+
+mod ixdtf;
+#[cfg(feature = "temporal")]
+pub use ixdtf::datetime_temporal;
+pub use ixdtf::{datetime_ixdtf, Annotation, IxdtfDateTime, TimeZoneAnnotation};
 
 mod duration;
-pub use duration::{duration, Duration};
+pub use duration::{
+    duration, duration_bytes, duration_lenient, duration_prefix, Duration, Fraction,
+};
+
+mod unit;
+pub use unit::Unit;
+
+mod interval;
+pub use interval::{
+    interval, interval_bytes, interval_prefix, repeating_interval, repeating_interval_bytes, Bound,
+    Interval, RepeatingInterval,
+};
+
+mod config;
+pub use config::{
+    DateTimeSyntax, ExcessDigits, OffsetRange, ParserConfig, Profile, Strictness, Trailing,
+};
+
+mod any;
+pub use any::{parse_any, Iso8601};
+
+#[cfg(feature = "edtf")]
+mod edtf;
+#[cfg(feature = "edtf")]
+pub use edtf::{
+    date_set, long_year, qualified_date, sub_year_grouping, unspecified_date, DateSet, EdtfDate,
+    LongYear, Qualification, QualifiedDate, SetKind, SetMember, SubYearGrouping, UnspecifiedDate,
+};
 
 #[cfg(feature = "chrono")]
 mod chrono;
@@ -57,5 +137,16 @@ mod chrono;
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "fuzzing")]
+mod fuzz;
+#[cfg(feature = "fuzzing")]
+pub use fuzz::fuzz_roundtrip;
+
 #[cfg(test)]
 mod assert;
@@ -0,0 +1,150 @@
+use core::str::FromStr;
+
+use crate::{error, parsers, Component, ParseError};
+
+/// An offset from UTC, like the one following a time.
+///
+/// A [`Time`][`crate::Time`] written without an offset has an [`UtcOffset::Unspecified`] one,
+/// which is neither UTC nor `+00:00`.
+///
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::UtcOffset::from_str("-05:30"),
+///     Ok(iso8601::UtcOffset::Fixed { seconds: -19800 })
+/// )
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Default)]
+pub enum UtcOffset {
+    /// UTC itself, written as `Z`
+    Utc,
+    /// A numeric offset like `+05:30`, in seconds east of UTC
+    Fixed {
+        /// The offset in seconds, negative for offsets west of UTC
+        seconds: i32,
+    },
+    /// UTC with an unknown offset to the local time, written as `-00:00`
+    UnknownLocal,
+    /// No offset was written at all, like in `23:40:00`
+    #[default]
+    Unspecified,
+}
+
+impl UtcOffset {
+    /// The offset in seconds east of UTC, which is zero for UTC and unknown local offsets,
+    /// and treats an unspecified offset as UTC.
+    pub fn offset_seconds(&self) -> i32 {
+        match self {
+            UtcOffset::Fixed { seconds } => *seconds,
+            UtcOffset::Utc | UtcOffset::UnknownLocal | UtcOffset::Unspecified => 0,
+        }
+    }
+
+    /// Whether an offset was written at all, which is anything but [`UtcOffset::Unspecified`].
+    pub fn is_specified(&self) -> bool {
+        *self != UtcOffset::Unspecified
+    }
+
+    /// Tells apart all offsets, for ordering otherwise equal times.
+    pub(crate) fn sort_key(&self) -> (u8, i32) {
+        match *self {
+            UtcOffset::Utc => (0, 0),
+            UtcOffset::Fixed { seconds } => (1, seconds),
+            UtcOffset::UnknownLocal => (2, 0),
+            UtcOffset::Unspecified => (3, 0),
+        }
+    }
+
+    /// The offset made of signed hours, minutes and seconds,
+    /// like `(-5, -30, 0)` for `-05:30`.
+    ///
+    /// Offsets too large for an `i32` of seconds saturate.
+    pub fn from_hms(hours: i32, minutes: i32, seconds: i32) -> UtcOffset {
+        UtcOffset::Fixed {
+            seconds: hours
+                .saturating_mul(3600)
+                .saturating_add(minutes.saturating_mul(60))
+                .saturating_add(seconds),
+        }
+    }
+}
+
+/// What to do with a datetime written without an offset, like `2023-02-08T23:40:00`,
+/// when converting it into a point in time.
+///
+/// See `DateTime::into_fixed_offset_with()`, which is only available with the `chrono` feature.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum MissingOffsetPolicy {
+    /// Take the datetime to be in UTC.
+    #[default]
+    AssumeUtc,
+    /// Take the datetime to be in the local time zone of the system.
+    /// This needs the `std` feature and fails for local times skipped or repeated
+    /// by a change of the offset, like at the start or end of daylight saving time.
+    AssumeLocal,
+    /// Refuse datetimes without an offset.
+    Error,
+}
+
+impl FromStr for UtcOffset {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        offset(s)
+    }
+}
+
+/// Parses a UTC offset string on its own.
+///
+/// A string can have one of the following formats:
+///
+/// * UTC: `Z`
+/// * Hours: `+05` or `-05`
+/// * Hours and minutes: `+05:30` or `+0530`
+/// * Hours, minutes and seconds: `+00:19:32` or `+001932`
+///
+/// The sign may also be written as the minus sign `−` (U+2212).
+/// `-00:00` is read as [`UtcOffset::UnknownLocal`].
+/// Offsets have to be less than a day, up to `±23:59:59`,
+/// and can be limited further with [`ParserConfig::offset_range`](crate::ParserConfig::offset_range).
+///
+/// ## Example
+///
+/// ```rust
+/// let offset = iso8601::offset("−05:30").unwrap();
+/// assert_eq!(offset.offset_seconds(), -19800);
+/// ```
+pub fn offset(string: &str) -> Result<UtcOffset, ParseError> {
+    offset_bytes(string.as_bytes())
+}
+
+/// Parses an offset from bytes, like [`offset()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let offset = iso8601::offset_bytes(b"+05:30").unwrap();
+/// ```
+pub fn offset_bytes(bytes: &[u8]) -> Result<UtcOffset, ParseError> {
+    error::prefix(Component::Offset, bytes, parsers::parse_offset(bytes)).map(|(parsed, _)| parsed)
+}
+
+/// Parses an offset at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`offset()`], the offset may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (offset, rest) = iso8601::offset_prefix("+05:30 (IST)").unwrap();
+/// assert_eq!(rest, " (IST)");
+/// ```
+pub fn offset_prefix(string: &str) -> Result<(UtcOffset, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::Offset,
+        string.as_bytes(),
+        parsers::parse_offset(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
+}
@@ -1,70 +1,138 @@
-//! The low-level parsers for date, datetime, duration and time.
+//! The low-level parsers for date, datetime, duration, interval and time.
 //!
 //! The top-level functions [`date()`][`crate::date()`], [`datetime()`][`crate::datetime()`],
-//! [`duration()`][`crate::duration()`] and [`time()`][`crate::time()`]
+//! [`duration()`][`crate::duration()`], [`interval()`][`crate::interval()`],
+//! [`repeating_interval()`][`crate::repeating_interval()`] and [`time()`][`crate::time()`]
 //! provide convenient wrappers around the low-level parsers,
 //! but throw away leftover input on success.
 //!
 //! Using the low-level functions provided here allows to recover leftover input
 //! or to combine these parsers with other parser combinators.
 
-use core::str;
+use alloc::string::String;
+use core::str::{self, FromStr};
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while, take_while_m_n},
+    bytes::complete::{tag, take_while, take_while1, take_while_m_n},
     character::complete::one_of,
     character::is_digit,
-    combinator::{map_res, not, opt},
-    error::Error,
-    sequence::{preceded, separated_pair, terminated, tuple},
-    Err, IResult, Parser,
+    combinator::{all_consuming, consumed, eof, map_res, not, opt, peek, recognize, verify},
+    error::{FromExternalError, ParseError},
+    multi::many0,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Err, Parser,
 };
 
-use crate::{Date, DateTime, Duration, Time};
+use crate::{
+    calendar, Annotation, Bound, Date, DateTime, Duration, ErrorKind, Fraction, Interval, Iso8601,
+    IxdtfDateTime, Precision, ReducedDate, RepeatingInterval, Time, TimeZoneAnnotation,
+    TruncatedDate, Unit, UtcOffset, Week,
+};
+
+#[cfg(feature = "edtf")]
+mod edtf;
+#[cfg(feature = "edtf")]
+pub use edtf::*;
 
 #[cfg(test)]
 mod tests;
 
+// ERRORS
+
+/// The error of all parsers, telling where and if known why the input was rejected.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Error<I> {
+    /// The input at which parsing failed
+    pub input: I,
+    /// Why parsing failed, or `None` if the input did not follow the format
+    pub kind: Option<ErrorKind>,
+}
+
+/// The result of all parsers, like [`nom::IResult`] with an [`Error`].
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+impl<'a> ParseError<&'a [u8]> for Error<&'a [u8]> {
+    fn from_error_kind(input: &'a [u8], _: nom::error::ErrorKind) -> Self {
+        Error { input, kind: None }
+    }
+
+    fn append(_: &'a [u8], _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    // Of two failed alternatives, the one that got further is the most telling,
+    // and between those, the one that knows why it failed.
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            core::cmp::Ordering::Less => self,
+            core::cmp::Ordering::Equal if self.kind.is_some() && other.kind.is_none() => self,
+            _ => other,
+        }
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, _: nom::error::ErrorKind, _: E) -> Self {
+        Error { input, kind: None }
+    }
+}
+
+// A failure at `input` because of `kind`
+fn failure<I, O>(input: I, kind: ErrorKind) -> IResult<I, O> {
+    Err(Err::Error(Error {
+        input,
+        kind: Some(kind),
+    }))
+}
+
 // UTILITY
 
-fn take_digits(i: &[u8]) -> IResult<&[u8], u32> {
-    let (i, digits) = take_while(is_digit)(i)?;
+fn take_digits<T: FromStr>(i: &[u8]) -> IResult<&[u8], T> {
+    let (new_i, digits) = take_while(is_digit)(i)?;
 
     if digits.is_empty() {
-        return Err(Err::Error(Error::new(i, nom::error::ErrorKind::Eof)));
+        return Err(Err::Error(Error {
+            input: new_i,
+            kind: None,
+        }));
     }
 
-    let s = str::from_utf8(digits).expect("Invalid data, expected UTF-8 string");
-    let res = s
-        .parse()
-        .expect("Invalid string, expected ASCII representation of a number");
+    match parse_digits(digits) {
+        Some(res) => Ok((new_i, res)),
+        // only possible if the number does not fit
+        None => failure(i, ErrorKind::NumberTooLarge),
+    }
+}
 
-    Ok((i, res))
+// The number written with `digits`, all of which are ASCII digits
+fn parse_digits<T: FromStr>(digits: &[u8]) -> Option<T> {
+    str::from_utf8(digits).ok()?.parse().ok()
 }
 
 fn take_n_digits(i: &[u8], n: usize) -> IResult<&[u8], u32> {
-    let (i, digits) = take_while_m_n(n, n, is_digit)(i)?;
-
-    let s = str::from_utf8(digits).expect("Invalid data, expected UTF-8 string");
-    let res = s
-        .parse()
-        .expect("Invalid string, expected ASCII representation of a number");
+    let (new_i, digits) = take_while_m_n(n, n, is_digit)(i)?;
 
-    Ok((i, res))
+    match parse_digits(digits) {
+        Some(res) => Ok((new_i, res)),
+        // only possible if the number does not fit
+        None => failure(i, ErrorKind::NumberTooLarge),
+    }
 }
 
+// Exactly `n` digits, failing with `kind` if they are outside of `range`
 fn n_digit_in_range(
     i: &[u8],
     n: usize,
     range: impl core::ops::RangeBounds<u32>,
+    kind: ErrorKind,
 ) -> IResult<&[u8], u32> {
     let (new_i, number) = take_n_digits(i, n)?;
 
     if range.contains(&number) {
         Ok((new_i, number))
     } else {
-        Err(Err::Error(Error::new(i, nom::error::ErrorKind::Eof)))
+        failure(i, kind)
     }
 }
 
@@ -82,69 +150,111 @@ fn sign(i: &[u8]) -> IResult<&[u8], i32> {
 // [+/-]YYYY
 fn date_year(i: &[u8]) -> IResult<&[u8], i32> {
     // The sign is optional, but defaults to `+`
-    tuple((
-        opt(sign),               // [+/-]
-        |i| take_n_digits(i, 4), // year
+    alt((
+        // a signed year of more digits is an expanded one, like in `+0020230-02-08`,
+        // not the start of the basic format
+        terminated(
+            pair(sign, |i| take_n_digits(i, 4)),
+            not(one_of("0123456789")),
+        ),
+        (|i| take_n_digits(i, 4)).map(|year| (1, year)), // year
     ))
-    .map(|(s, year)| s.unwrap_or(1) * year as i32)
+    .map(|(s, year)| s * year as i32)
     .parse(i)
 }
 
 // MM
 fn date_month(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=12)
+    n_digit_in_range(i, 2, 1..=12, ErrorKind::InvalidMonth)
 }
 
 // DD
 fn date_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=31)
+    n_digit_in_range(i, 2, 1..=31, ErrorKind::InvalidDay)
+}
+
+// DD of a calendar date in `year` and `month`, failing with `NonexistentDate` at the day
+// if that month does not have it, for the formats that only allow existing dates
+fn existing_day(i: &[u8], year: i32, month: u32) -> IResult<&[u8], Date> {
+    let (rest, day) = date_day(i)?;
+    if day > calendar::days_in_month(year, month) {
+        return failure(i, ErrorKind::NonexistentDate);
+    }
+    Ok((rest, Date::YMD { year, month, day }))
 }
 
 // WW
 fn date_week(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=52)
+    n_digit_in_range(i, 2, 1..=53, ErrorKind::InvalidWeek)
 }
 
 fn date_week_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 1, 1..=7)
+    n_digit_in_range(i, 1, 1..=7, ErrorKind::InvalidWeekday)
 }
 
 // ordinal DDD
 fn date_ord_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 3, 1..=366)
+    n_digit_in_range(i, 3, 1..=366, ErrorKind::InvalidOrdinal)
 }
 
 // YYYY-MM-DD
 fn date_ymd(i: &[u8]) -> IResult<&[u8], Date> {
-    tuple((
-        date_year,      // YYYY
-        opt(tag(b"-")), // -
-        date_month,     // MM
-        opt(tag(b"-")), // -
-        date_day,       //DD
-    ))
-    .map(|(year, _, month, _, day)| Date::YMD { year, month, day })
-    .parse(i)
+    date_ymd_with(date_year)(i)
+}
+
+fn date_ymd_with<Y>(year: Y) -> impl Fn(&[u8]) -> IResult<&[u8], Date>
+where
+    Y: Fn(&[u8]) -> IResult<&[u8], i32> + Copy,
+{
+    move |i| {
+        tuple((
+            year,           // YYYY
+            opt(tag(b"-")), // -
+            date_month,     // MM
+            opt(tag(b"-")), // -
+            date_day,       //DD
+        ))
+        .map(|(year, _, month, _, day)| Date::YMD { year, month, day })
+        .parse(i)
+    }
 }
 
 // YYYY-DDD
 fn date_ordinal(i: &[u8]) -> IResult<&[u8], Date> {
-    separated_pair(date_year, opt(tag(b"-")), date_ord_day)
-        .map(|(year, ddd)| Date::Ordinal { year, ddd })
-        .parse(i)
+    date_ordinal_with(date_year)(i)
+}
+
+fn date_ordinal_with<Y>(year: Y) -> impl Fn(&[u8]) -> IResult<&[u8], Date>
+where
+    Y: Fn(&[u8]) -> IResult<&[u8], i32> + Copy,
+{
+    move |i| {
+        separated_pair(year, opt(tag(b"-")), date_ord_day)
+            .map(|(year, ddd)| Date::Ordinal { year, ddd })
+            .parse(i)
+    }
 }
 
 // YYYY-"W"WW-D
 fn date_iso_week(i: &[u8]) -> IResult<&[u8], Date> {
-    tuple((
-        date_year,                          // y
-        tuple((opt(tag(b"-")), tag(b"W"))), // [-]W
-        date_week,                          // w
-        opt(tag(b"-")),                     // [-]
-        date_week_day,                      // d
-    ))
-    .map(|(year, _, ww, _, d)| Date::Week { year, ww, d })
-    .parse(i)
+    date_iso_week_with(date_year)(i)
+}
+
+fn date_iso_week_with<Y>(year: Y) -> impl Fn(&[u8]) -> IResult<&[u8], Date>
+where
+    Y: Fn(&[u8]) -> IResult<&[u8], i32> + Copy,
+{
+    move |i| {
+        tuple((
+            year,                               // y
+            tuple((opt(tag(b"-")), tag(b"W"))), // [-]W
+            date_week,                          // w
+            opt(tag(b"-")),                     // [-]
+            date_week_day,                      // d
+        ))
+        .map(|(year, _, ww, _, d)| Date::Week { year, ww, d })
+        .parse(i)
+    }
 }
 
 /// Parses a date string.
@@ -154,80 +264,374 @@ pub fn parse_date(i: &[u8]) -> IResult<&[u8], Date> {
     alt((date_ymd, date_iso_week, date_ordinal))(i)
 }
 
+// YYYY-MM
+fn date_year_month(i: &[u8]) -> IResult<&[u8], ReducedDate> {
+    separated_pair(date_year, tag(b"-"), date_month)
+        .map(|(year, month)| ReducedDate::YearMonth { year, month })
+        .parse(i)
+}
+
+// YYYY
+fn date_year_only(i: &[u8]) -> IResult<&[u8], ReducedDate> {
+    date_year.map(|year| ReducedDate::Year { year }).parse(i)
+}
+
+/// Parses a reduced precision date string.
+///
+/// See [`reduced_date()`][`crate::reduced_date()`] for the supported formats.
+pub fn parse_reduced_date(i: &[u8]) -> IResult<&[u8], ReducedDate> {
+    // a following digit or separator means this is the start of a complete date instead
+    terminated(
+        alt((date_year_month, date_year_only)),
+        not(one_of("-0123456789")),
+    )(i)
+}
+
+/// Parses a week string.
+///
+/// See [`week()`][`crate::week()`] for the supported formats.
+// YYYY-"W"WW
+pub fn parse_week(i: &[u8]) -> IResult<&[u8], Week> {
+    // a following digit or separator means this is the start of a week date instead
+    terminated(
+        separated_pair(date_year, tuple((opt(tag(b"-")), tag(b"W"))), date_week),
+        not(one_of("-0123456789")),
+    )
+    .map(|(year, ww)| Week { year, ww })
+    .parse(i)
+}
+
+/// Parses a truncated date string without a year.
+///
+/// See [`truncated_date()`][`crate::truncated_date()`] for the supported formats.
+// ---DD, --MM[-]DD or --MM
+pub fn parse_truncated_date(i: &[u8]) -> IResult<&[u8], TruncatedDate> {
+    alt((
+        preceded(tag(b"---"), date_day).map(|day| TruncatedDate::Day { day }),
+        preceded(
+            tag(b"--"),
+            separated_pair(date_month, opt(tag(b"-")), date_day),
+        )
+        .map(|(month, day)| TruncatedDate::MonthDay { month, day }),
+        // a following digit or separator means this is the start of a month and day instead
+        terminated(preceded(tag(b"--"), date_month), not(one_of("-0123456789")))
+            .map(|month| TruncatedDate::Month { month }),
+    ))(i)
+}
+
+// (+/-)Y*YYYY, with exactly `extra_digits` additional year digits
+fn date_expanded_year(extra_digits: usize) -> impl Fn(&[u8]) -> IResult<&[u8], i32> + Copy {
+    move |i| {
+        // The sign is mandatory for expanded years
+        let (new_i, (s, year)) = tuple((
+            sign,                                   // +/-
+            |i| take_n_digits(i, 4 + extra_digits), // year
+        ))(i)?;
+
+        // signed before narrowing, as `-2147483648` fits but `2147483648` does not
+        match i32::try_from(i64::from(s) * i64::from(year)) {
+            Ok(year) => Ok((new_i, year)),
+            Err(_) => failure(i, ErrorKind::NumberTooLarge),
+        }
+    }
+}
+
+/// Parses a date string with an expanded year representation.
+///
+/// See [`date_expanded()`][`crate::date_expanded()`] for the supported formats.
+pub fn parse_date_expanded(extra_digits: usize) -> impl Fn(&[u8]) -> IResult<&[u8], Date> {
+    move |i| {
+        let year = date_expanded_year(extra_digits);
+
+        alt((
+            date_ymd_with(year),
+            date_iso_week_with(year),
+            date_ordinal_with(year),
+        ))(i)
+    }
+}
+
 // TIME
 
 // HH
 fn time_hour(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=24)
+    n_digit_in_range(i, 2, 0..=24, ErrorKind::InvalidHour)
 }
 
 // MM
 fn time_minute(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=59)
+    n_digit_in_range(i, 2, 0..=59, ErrorKind::InvalidMinute)
+}
+
+// HH, without the end of a day `24`
+fn time_hour_of_day(i: &[u8]) -> IResult<&[u8], u32> {
+    n_digit_in_range(i, 2, 0..=23, ErrorKind::InvalidHour)
 }
 
 // SS
 fn time_second(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=60)
+    n_digit_in_range(i, 2, 0..=60, ErrorKind::InvalidSecond)
 }
 
 // Converts the fractional part if-any of a number of seconds to milliseconds
 // truncating towards zero if there are more than three digits.
 // e.g. "" -> 0, "1" -> 100, "12" -> 120, "123" -> 123, "1234" -> 123
 fn fraction_millisecond(i: &[u8]) -> IResult<&[u8], u32> {
-    let (i, mut digits) = take_while(is_digit)(i)?;
-    let mut l = digits.len();
-    if l > 3 {
-        digits = digits.get(0..3).unwrap();
-    }
-    let mut result = 0;
-    if l > 0 {
-        let digits = str::from_utf8(digits).unwrap(); // This can't panic, `digits` will only include digits.
-        result = digits.parse().unwrap();
-    }
-    while l < 3 {
-        result *= 10;
-        l += 1;
-    }
+    fraction_places(i, 3)
+}
+
+// Converts the fractional part if-any of a number of seconds to nanoseconds
+// truncating towards zero if there are more than nine digits.
+// e.g. "" -> 0, "1" -> 100_000_000, "123456789" -> 123_456_789, "1234567891" -> 123_456_789
+fn fraction_nanosecond(i: &[u8]) -> IResult<&[u8], u32> {
+    fraction_places(i, 9)
+}
+
+// Like `fraction_nanosecond`, along with the number of digits that were kept
+// e.g. "5" -> (500_000_000, 1), "500" -> (500_000_000, 3), "1234567891" -> (123_456_789, 9)
+fn fraction_nanosecond_digits(i: &[u8]) -> IResult<&[u8], (u32, u8)> {
+    consumed(fraction_nanosecond)
+        .map(|(digits, ns)| (ns, digits.len().min(9) as u8))
+        .parse(i)
+}
+
+// Converts the fractional part if-any of a number to `places` decimal places
+fn fraction_places(i: &[u8], places: usize) -> IResult<&[u8], u32> {
+    let (i, digits) = take_while(is_digit)(i)?;
+
+    // missing digits count as zeros, and `places` digits always fit
+    let result = (0..places).fold(0, |result, place| {
+        let digit = digits.get(place).map_or(0, |digit| digit - b'0');
+        result * 10 + u32::from(digit)
+    });
     Ok((i, result))
 }
 
 /// Parses a time string.
 ///
 /// See [`time()`][`crate::time()`] for the supported formats.
-// HH:MM:[SS][.(m*)][(Z|+...|-...)]
+// [T](HH,hh|HH:MM,mm|HH:MM:SS[.(m*)]|HH:MM)[(Z|+...|-...)], or THH[(Z|+...|-...)]
 pub fn parse_time(i: &[u8]) -> IResult<&[u8], Time> {
+    alt((preceded(tag(b"T"), designated_time_of_day), time_of_day))(i)
+}
+
+// Converts a decimal fraction of a unit spanning `unit_ns` nanoseconds to nanoseconds
+// (or of any unit spanning a billion parts to billionths of it),
+// truncating towards zero. Digits after the ninth do not contribute to the result.
+// e.g. with an hour as unit: "5" -> 1_800_000_000_000, "25" -> 900_000_000_000
+fn fraction_of_unit(i: &[u8], unit_ns: u64) -> IResult<&[u8], u64> {
+    let (i, digits) = take_while1(is_digit)(i)?;
+
+    let (numerator, denominator) =
+        digits
+            .iter()
+            .take(9)
+            .fold((0u64, 1u64), |(numerator, denominator), digit| {
+                (numerator * 10 + u64::from(digit - b'0'), denominator * 10)
+            });
+
+    // an hour has more nanoseconds than a billion of them can be multiplied with in a u64
+    let ns = u128::from(numerator) * u128::from(unit_ns) / u128::from(denominator);
+    Ok((i, ns as u64))
+}
+
+// Splits a number of nanoseconds into (minutes, seconds, nanoseconds)
+fn split_nanosecond(ns: u64) -> (u32, u32, u32) {
+    // `ns` is less than an hour, so all parts fit
+    (
+        (ns / 60_000_000_000) as u32,
+        (ns / 1_000_000_000 % 60) as u32,
+        (ns % 1_000_000_000) as u32,
+    )
+}
+
+// HH,hh
+fn time_fractional_hour(i: &[u8]) -> IResult<&[u8], HmsComponents> {
+    separated_pair(time_hour, one_of(",."), |i| {
+        fraction_of_unit(i, 3_600_000_000_000)
+    })
+    .map(|(h, ns)| {
+        let (m, s, ns) = split_nanosecond(ns);
+        (h, m, s, ns, 0)
+    })
+    .parse(i)
+}
+
+// HH:MM,mm
+fn time_fractional_minute(i: &[u8]) -> IResult<&[u8], HmsComponents> {
     tuple((
-        time_hour,                                         // HH
-        opt(tag(b":")),                                    // :
-        time_minute,                                       // MM
-        opt(preceded(opt(tag(b":")), time_second)),        // [SS]
-        opt(preceded(one_of(",."), fraction_millisecond)), // [.(m*)]
-        opt(alt((timezone_hour, timezone_utc))),           // [(Z|+...|-...)]
+        time_hour,                                                       // HH
+        opt(tag(b":")),                                                  // :
+        time_minute,                                                     // MM
+        preceded(one_of(",."), |i| fraction_of_unit(i, 60_000_000_000)), // ,mm
     ))
-    .map(|(h, _, m, s, ms, z)| {
-        let (tz_offset_hours, tz_offset_minutes) = z.unwrap_or((0, 0));
+    .map(|(h, _, m, ns)| {
+        let (_, s, ns) = split_nanosecond(ns);
+        (h, m, s, ns, 0)
+    })
+    .parse(i)
+}
+
+// HH:MM:SS[.(m*)]
+fn time_hms(i: &[u8]) -> IResult<&[u8], HmsComponents> {
+    tuple((
+        time_hour,                                               // HH
+        opt(tag(b":")),                                          // :
+        time_minute,                                             // MM
+        opt(tag(b":")),                                          // :
+        time_second,                                             // SS
+        opt(preceded(one_of(",."), fraction_nanosecond_digits)), // [.(m*)]
+    ))
+    .map(|(h, _, m, _, s, f)| {
+        let (ns, digits) = f.unwrap_or((0, 0));
+        (h, m, s, ns, digits)
+    })
+    .parse(i)
+}
+
+// HH:MM
+fn time_hm(i: &[u8]) -> IResult<&[u8], HmsComponents> {
+    separated_pair(time_hour, opt(tag(b":")), time_minute)
+        .map(|(h, m)| (h, m, 0, 0, 0))
+        .parse(i)
+}
+
+// (hours, minutes, seconds, nanoseconds, digits of the fraction of a second)
+type HmsComponents = (u32, u32, u32, u32, u8);
+
+// the components of a time along with the smallest component present
+type TimeComponents = (HmsComponents, Precision);
 
-        Time {
+// the precision of a time with seconds, whose fraction was written with `digits` digits
+fn second_precision(digits: u8) -> Precision {
+    if digits > 0 {
+        Precision::Fraction
+    } else {
+        Precision::Second
+    }
+}
+
+// HH,hh|HH:MM,mm|HH:MM:SS[.(m*)]|HH:MM
+fn time_components(i: &[u8]) -> IResult<&[u8], TimeComponents> {
+    alt((
+        time_fractional_hour.map(|t| (t, Precision::Hour)),
+        time_fractional_minute.map(|t| (t, Precision::Minute)),
+        time_hms.map(|t| (t, second_precision(t.4))),
+        time_hm.map(|t| (t, Precision::Minute)),
+    ))(i)
+}
+
+// time components, or HH alone, which is only unambiguous after a `T`
+fn time_components_or_hour(i: &[u8]) -> IResult<&[u8], TimeComponents> {
+    alt((
+        time_components,
+        time_hour.map(|h| ((h, 0, 0, 0, 0), Precision::Hour)),
+    ))(i)
+}
+
+// (HH,hh|HH:MM,mm|HH:MM:SS[.(m*)]|HH:MM)[(Z|+...|-...)]
+fn time_of_day(i: &[u8]) -> IResult<&[u8], Time> {
+    time_of_day_with(time_components, timezone_utc)(i)
+}
+
+// like `time_of_day`, but also accepting HH alone, following a `T`
+fn designated_time_of_day(i: &[u8]) -> IResult<&[u8], Time> {
+    time_of_day_with(time_components_or_hour, timezone_utc)(i)
+}
+
+fn time_of_day_with<C, Z>(components: C, utc: Z) -> impl Fn(&[u8]) -> IResult<&[u8], Time>
+where
+    C: Fn(&[u8]) -> IResult<&[u8], TimeComponents> + Copy,
+    Z: Fn(&[u8]) -> IResult<&[u8], UtcOffset> + Copy,
+{
+    move |i| {
+        tuple((
+            verify(
+                components,
+                // the hour 24 is only allowed as the end of a day
+                |&((h, m, s, ns, _), _)| h < 24 || (m, s, ns) == (0, 0, 0),
+            ),
+            opt(alt((timezone_hour, utc))), // [(Z|+...|-...)]
+        ))
+        .map(|(((h, m, s, ns, digits), precision), z)| Time {
             hour: h,
             minute: m,
-            second: s.unwrap_or(0),
-            millisecond: ms.unwrap_or(0),
-            tz_offset_hours,
-            tz_offset_minutes,
+            second: s,
+            nanosecond: ns,
+            fraction_digits: digits,
+            offset: z.unwrap_or(UtcOffset::Unspecified),
+            precision,
+        })
+        .parse(i)
+    }
+}
+
+// + or -, or the minus sign U+2212 that ISO 8601 prefers for offsets
+fn offset_sign(i: &[u8]) -> IResult<&[u8], i32> {
+    alt((sign, tag("\u{2212}".as_bytes()).map(|_| -1)))(i)
+}
+
+// HH of an offset, below a whole day
+fn offset_hour(i: &[u8]) -> IResult<&[u8], u32> {
+    n_digit_in_range(i, 2, 0..=23, ErrorKind::OffsetOutOfRange)
+}
+
+// MM or SS of an offset
+fn offset_minute(i: &[u8]) -> IResult<&[u8], u32> {
+    n_digit_in_range(i, 2, 0..=59, ErrorKind::OffsetOutOfRange)
+}
+
+// (+|-)HH[[:]MM[[:]SS]], where -00:00 is an unknown local offset
+fn timezone_hour(i: &[u8]) -> IResult<&[u8], UtcOffset> {
+    consumed(tuple((
+        offset_sign,
+        offset_hour,
+        opt(preceded(
+            opt(tag(b":")),
+            tuple((offset_minute, opt(preceded(opt(tag(b":")), offset_minute)))),
+        )),
+    )))
+    .map(|(written, (s, h, ms))| {
+        let (m, sec) = ms.unwrap_or((0, None));
+        if is_unknown_local_offset(written) {
+            UtcOffset::UnknownLocal
+        } else {
+            UtcOffset::from_hms(
+                s * (h as i32),
+                s * (m as i32),
+                s * (sec.unwrap_or(0) as i32),
+            )
         }
     })
     .parse(i)
 }
 
-fn timezone_hour(i: &[u8]) -> IResult<&[u8], (i32, i32)> {
-    tuple((sign, time_hour, opt(preceded(opt(tag(b":")), time_minute))))
-        .map(|(s, h, m)| (s * (h as i32), s * (m.unwrap_or(0) as i32)))
-        .parse(i)
+// -00:00, which RFC 3339 uses for an unknown offset to the local time
+fn is_unknown_local_offset(written: &[u8]) -> bool {
+    match written
+        .strip_prefix(b"-")
+        .or_else(|| written.strip_prefix("\u{2212}".as_bytes()))
+    {
+        Some(zeros) => zeros.iter().all(|&c| c == b'0' || c == b':'),
+        None => false,
+    }
 }
 
-fn timezone_utc(input: &[u8]) -> IResult<&[u8], (i32, i32)> {
-    tag(b"Z").map(|_| (0, 0)).parse(input)
+/// Parses a UTC offset string.
+///
+/// See [`offset()`][`crate::offset()`] for the supported formats.
+// Z|(+|-)HH[[:]MM[[:]SS]]
+pub fn parse_offset(i: &[u8]) -> IResult<&[u8], UtcOffset> {
+    alt((timezone_utc, timezone_hour))(i)
+}
+
+fn timezone_utc(input: &[u8]) -> IResult<&[u8], UtcOffset> {
+    tag(b"Z").map(|_| UtcOffset::Utc).parse(input)
+}
+
+// Z or z
+fn timezone_utc_lenient(input: &[u8]) -> IResult<&[u8], UtcOffset> {
+    one_of("Zz").map(|_| UtcOffset::Utc).parse(input)
 }
 
 /// Parses a datetime string.
@@ -235,74 +639,632 @@ fn timezone_utc(input: &[u8]) -> IResult<&[u8], (i32, i32)> {
 /// See [`datetime()`][`crate::datetime()`] for supported formats.
 // Full ISO8601 datetime
 pub fn parse_datetime(i: &[u8]) -> IResult<&[u8], DateTime> {
-    separated_pair(parse_date, tag(b"T"), parse_time)
+    separated_pair(parse_date, tag(b"T"), designated_time_of_day)
         .map(|(d, t)| DateTime { date: d, time: t })
         .parse(i)
 }
 
+/// Parses a datetime string, also accepting a lowercase `t` or a space as separator
+/// and a lowercase `z` for UTC.
+///
+/// See [`datetime_lenient()`][`crate::datetime_lenient()`] for supported formats.
+pub fn parse_datetime_lenient(i: &[u8]) -> IResult<&[u8], DateTime> {
+    separated_pair(
+        parse_date,
+        one_of("Tt "),
+        time_of_day_with(time_components_or_hour, timezone_utc_lenient),
+    )
+    .map(|(d, t)| DateTime { date: d, time: t })
+    .parse(i)
+}
+
+// RFC 3339
+
+// YYYY-MM-DD, with an unsigned four digit year and a day that exists in that month
+fn rfc3339_full_date(i: &[u8]) -> IResult<&[u8], Date> {
+    let (i, (year, _, month, _)) =
+        tuple((|i| take_n_digits(i, 4), tag(b"-"), date_month, tag(b"-")))(i)?;
+    existing_day(i, year as i32, month)
+}
+
+// HH:MM:SS[.s*], before the hour 24
+fn rfc3339_partial_time(i: &[u8]) -> IResult<&[u8], HmsComponents> {
+    tuple((
+        time_hour_of_day,
+        tag(b":"),
+        time_minute,
+        tag(b":"),
+        time_second,
+        // at least one digit after the decimal point
+        opt(preceded(
+            tuple((tag(b"."), peek(one_of("0123456789")))),
+            fraction_nanosecond_digits,
+        )),
+    ))
+    .map(|(h, _, m, _, s, f)| {
+        let (ns, digits) = f.unwrap_or((0, 0));
+        (h, m, s, ns, digits)
+    })
+    .parse(i)
+}
+
+// Z or +HH:MM or -HH:MM, where -00:00 is an unknown local offset
+fn rfc3339_offset(i: &[u8]) -> IResult<&[u8], UtcOffset> {
+    alt((
+        timezone_utc_lenient,
+        consumed(tuple((sign, offset_hour, tag(b":"), offset_minute))).map(
+            |(written, (s, h, _, m))| {
+                if is_unknown_local_offset(written) {
+                    UtcOffset::UnknownLocal
+                } else {
+                    UtcOffset::from_hms(s * h as i32, s * m as i32, 0)
+                }
+            },
+        ),
+    ))(i)
+}
+
+/// Parses a datetime string in the RFC 3339 profile of ISO 8601.
+///
+/// See [`datetime_rfc3339()`][`crate::datetime_rfc3339()`] for the supported formats.
+// YYYY-MM-DD(T|t| )HH:MM:SS[.s*](Z|z|+HH:MM|-HH:MM)
+pub fn parse_datetime_rfc3339(i: &[u8]) -> IResult<&[u8], DateTime> {
+    tuple((
+        rfc3339_full_date,
+        one_of("Tt "),
+        rfc3339_partial_time,
+        rfc3339_offset,
+    ))
+    .map(
+        |(date, _, (hour, minute, second, nanosecond, fraction_digits), offset)| DateTime {
+            date,
+            time: Time {
+                hour,
+                minute,
+                second,
+                nanosecond,
+                fraction_digits,
+                offset,
+                precision: second_precision(fraction_digits),
+            },
+        },
+    )
+    .parse(i)
+}
+
+// HTML
+
+// 4*DIGIT, greater than zero
+fn html_year(i: &[u8]) -> IResult<&[u8], i32> {
+    verify(
+        map_res(
+            verify(take_while1(is_digit), |digits: &[u8]| digits.len() >= 4),
+            |digits: &[u8]| parse_digits::<i32>(digits).ok_or(()),
+        ),
+        |&year| year > 0,
+    )(i)
+}
+
+// YYYY-MM-DD, with a day that exists in that month
+fn html_date(i: &[u8]) -> IResult<&[u8], Date> {
+    let (i, (year, _, month, _)) = tuple((html_year, tag(b"-"), date_month, tag(b"-")))(i)?;
+    existing_day(i, year, month)
+}
+
+// HH:MM[:SS[.s{1,3}]], before the hour 24 and without a leap second
+fn html_time(i: &[u8]) -> IResult<&[u8], TimeComponents> {
+    tuple((
+        time_hour_of_day,
+        tag(b":"),
+        time_minute,
+        opt(preceded(
+            tag(b":"),
+            tuple((
+                time_minute,
+                opt(preceded(
+                    tuple((
+                        tag(b"."),
+                        peek(verify(take_while1(is_digit), |d: &[u8]| d.len() <= 3)),
+                    )),
+                    fraction_nanosecond_digits,
+                )),
+            )),
+        )),
+    ))
+    .map(|(h, _, m, s)| match s {
+        Some((s, f)) => {
+            let (ns, digits) = f.unwrap_or((0, 0));
+            ((h, m, s, ns, digits), second_precision(digits))
+        }
+        None => ((h, m, 0, 0, 0), Precision::Minute),
+    })
+    .parse(i)
+}
+
+// Z or (+|-)HH[:]MM
+fn html_offset(i: &[u8]) -> IResult<&[u8], UtcOffset> {
+    alt((
+        timezone_utc,
+        tuple((sign, offset_hour, opt(tag(b":")), offset_minute))
+            .map(|(s, h, _, m)| UtcOffset::from_hms(s * h as i32, s * m as i32, 0)),
+    ))(i)
+}
+
+/// Parses a datetime string in the format of the HTML `datetime` attribute.
+///
+/// See [`datetime_html()`][`crate::datetime_html()`] for the supported formats.
+// YYYY-MM-DD(T| )HH:MM[:SS[.sss]][(Z|+HH:MM|-HH:MM)]
+pub fn parse_datetime_html(i: &[u8]) -> IResult<&[u8], DateTime> {
+    tuple((html_date, one_of("T "), html_time, opt(html_offset)))
+        .map(
+            |(
+                date,
+                _,
+                ((hour, minute, second, nanosecond, fraction_digits), precision),
+                offset,
+            )| DateTime {
+                date,
+                time: Time {
+                    hour,
+                    minute,
+                    second,
+                    nanosecond,
+                    fraction_digits,
+                    offset: offset.unwrap_or(UtcOffset::Unspecified),
+                    precision,
+                },
+            },
+        )
+        .parse(i)
+}
+
+// PYTHON
+
+// YYYY-MM-DD, after the year 0000 and with a day that exists in that month
+fn python_date(i: &[u8]) -> IResult<&[u8], Date> {
+    let (i, (year, _, month, _)) = tuple((
+        verify(|i| take_n_digits(i, 4), |&year| year > 0),
+        tag(b"-"),
+        date_month,
+        tag(b"-"),
+    ))(i)?;
+    existing_day(i, year as i32, month)
+}
+
+// .fff or .ffffff
+fn python_fraction(i: &[u8]) -> IResult<&[u8], (u32, u8)> {
+    preceded(
+        tuple((
+            tag(b"."),
+            peek(alt((
+                recognize(tuple((
+                    take_while_m_n(6, 6, is_digit),
+                    not(one_of("0123456789")),
+                ))),
+                recognize(tuple((
+                    take_while_m_n(3, 3, is_digit),
+                    not(one_of("0123456789")),
+                ))),
+            ))),
+        )),
+        fraction_nanosecond_digits,
+    )(i)
+}
+
+// HH[:MM[:SS[.fff|.ffffff]]], before the hour 24 and without a leap second
+fn python_time(i: &[u8]) -> IResult<&[u8], TimeComponents> {
+    tuple((
+        time_hour_of_day,
+        opt(preceded(
+            tag(b":"),
+            tuple((
+                time_minute,
+                opt(preceded(
+                    tag(b":"),
+                    tuple((time_minute, opt(python_fraction))),
+                )),
+            )),
+        )),
+    ))
+    .map(|(h, ms)| match ms {
+        None => ((h, 0, 0, 0, 0), Precision::Hour),
+        Some((m, None)) => ((h, m, 0, 0, 0), Precision::Minute),
+        Some((m, Some((s, f)))) => {
+            let (ns, digits) = f.unwrap_or((0, 0));
+            ((h, m, s, ns, digits), second_precision(digits))
+        }
+    })
+    .parse(i)
+}
+
+// Z or (+|-)HH:MM[:SS], as a `UtcOffset` cannot hold the fraction of a second Python may add
+fn python_offset(i: &[u8]) -> IResult<&[u8], UtcOffset> {
+    alt((
+        timezone_utc,
+        tuple((
+            sign,
+            offset_hour,
+            tag(b":"),
+            offset_minute,
+            opt(preceded(tag(b":"), offset_minute)),
+        ))
+        .map(|(s, h, _, m, sec)| {
+            UtcOffset::from_hms(s * h as i32, s * m as i32, s * sec.unwrap_or(0) as i32)
+        }),
+    ))(i)
+}
+
+/// Parses a datetime string in the format of Python's `datetime.isoformat()`.
+///
+/// See [`datetime_python()`][`crate::datetime_python()`] for the supported formats.
+// YYYY-MM-DD[(T| )HH[:MM[:SS[.ffffff]]][(Z|+HH:MM[:SS]|-...)]]
+pub fn parse_datetime_python(i: &[u8]) -> IResult<&[u8], DateTime> {
+    tuple((
+        python_date,
+        opt(preceded(
+            one_of("T "),
+            tuple((python_time, opt(python_offset))),
+        )),
+    ))
+    .map(|(date, t)| {
+        // a date on its own stands for midnight at the start of that day
+        let (((hour, minute, second, nanosecond, fraction_digits), precision), z) =
+            t.unwrap_or((((0, 0, 0, 0, 0), Precision::Hour), None));
+        DateTime {
+            date,
+            time: Time {
+                hour,
+                minute,
+                second,
+                nanosecond,
+                fraction_digits,
+                offset: z.unwrap_or(UtcOffset::Unspecified),
+                precision,
+            },
+        }
+    })
+    .parse(i)
+}
+
+// IXDTF
+
+// ! for a critical suffix
+fn ixdtf_critical(i: &[u8]) -> IResult<&[u8], bool> {
+    opt(tag(b"!")).map(|c| c.is_some()).parse(i)
+}
+
+// (ALPHA / "." / "_") *(ALPHA / "." / "_" / "-" / "+" / DIGIT), but not `.` or `..`
+fn ixdtf_time_zone_part(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    verify(
+        recognize(tuple((
+            take_while_m_n(1, 1, |c: u8| {
+                c.is_ascii_alphabetic() || c == b'.' || c == b'_'
+            }),
+            take_while(|c: u8| c.is_ascii_alphanumeric() || matches!(c, b'.' | b'_' | b'-' | b'+')),
+        ))),
+        |part: &[u8]| part != b"." && part != b"..",
+    )(i)
+}
+
+// [!Area/Location] or [!+HH:MM]
+fn ixdtf_time_zone(i: &[u8]) -> IResult<&[u8], TimeZoneAnnotation> {
+    delimited(
+        tag(b"["),
+        tuple((
+            ixdtf_critical,
+            alt((
+                recognize(tuple((
+                    ixdtf_time_zone_part,
+                    many0(preceded(tag(b"/"), ixdtf_time_zone_part)),
+                ))),
+                recognize(tuple((sign, time_hour, tag(b":"), time_minute))),
+            )),
+        )),
+        tag(b"]"),
+    )
+    .map(|(critical, name)| TimeZoneAnnotation {
+        // only ASCII was accepted
+        name: String::from_utf8_lossy(name).into_owned(),
+        critical,
+    })
+    .parse(i)
+}
+
+// [!key=value(-value)*]
+fn ixdtf_annotation(i: &[u8]) -> IResult<&[u8], Annotation> {
+    delimited(
+        tag(b"["),
+        tuple((
+            ixdtf_critical,
+            recognize(tuple((
+                take_while_m_n(1, 1, |c: u8| c.is_ascii_lowercase() || c == b'_'),
+                take_while(|c: u8| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_' || c == b'-'
+                }),
+            ))),
+            tag(b"="),
+            recognize(tuple((
+                take_while1(|c: u8| c.is_ascii_alphanumeric()),
+                many0(preceded(
+                    tag(b"-"),
+                    take_while1(|c: u8| c.is_ascii_alphanumeric()),
+                )),
+            ))),
+        )),
+        tag(b"]"),
+    )
+    .map(|(critical, key, _, value)| Annotation {
+        // only ASCII was accepted
+        key: String::from_utf8_lossy(key).into_owned(),
+        value: String::from_utf8_lossy(value).into_owned(),
+        critical,
+    })
+    .parse(i)
+}
+
+/// Parses a datetime string in the Internet Extended Date/Time Format (IXDTF) of RFC 9557.
+///
+/// See [`datetime_ixdtf()`][`crate::datetime_ixdtf()`] for the supported formats.
+// <RFC 3339 datetime>[time zone][key=value]...
+pub fn parse_datetime_ixdtf(i: &[u8]) -> IResult<&[u8], IxdtfDateTime> {
+    tuple((
+        parse_datetime_rfc3339,
+        opt(ixdtf_time_zone),
+        many0(ixdtf_annotation),
+    ))
+    .map(|(datetime, time_zone, annotations)| IxdtfDateTime {
+        datetime,
+        time_zone,
+        annotations,
+    })
+    .parse(i)
+}
+
+// TEMPORAL
+
+// YYYY or (+|-)YYYYYY, but not -000000
+#[cfg(feature = "temporal")]
+fn temporal_year(i: &[u8]) -> IResult<&[u8], i32> {
+    alt((
+        verify(tuple((sign, |i| take_n_digits(i, 6))), |&(s, year)| {
+            s > 0 || year > 0
+        })
+        .map(|(s, year)| s * year as i32),
+        (|i| take_n_digits(i, 4)).map(|year| year as i32),
+    ))(i)
+}
+
+// year-MM-DD or yearMMDD
+#[cfg(feature = "temporal")]
+fn temporal_date(i: &[u8]) -> IResult<&[u8], Date> {
+    tuple((
+        temporal_year,
+        alt((
+            tuple((tag(b"-"), date_month, tag(b"-"), date_day)).map(|(_, m, _, d)| (m, d)),
+            tuple((date_month, date_day)),
+        )),
+    ))
+    .map(|(year, (month, day))| Date::YMD { year, month, day })
+    .parse(i)
+}
+
+// HH[:MM[:SS[.s*]]], before the hour 24
+#[cfg(feature = "temporal")]
+fn temporal_time(i: &[u8]) -> IResult<&[u8], TimeComponents> {
+    verify(
+        alt((
+            time_hms.map(|t| (t, second_precision(t.4))),
+            time_hm.map(|t| (t, Precision::Minute)),
+            time_hour.map(|h| ((h, 0, 0, 0, 0), Precision::Hour)),
+        )),
+        |&((h, _, _, _, _), _)| h < 24,
+    )(i)
+}
+
+/// Parses a datetime string in the grammar of the ECMAScript Temporal API.
+///
+/// See [`datetime_temporal()`][`crate::datetime_temporal()`] for the supported formats.
+// date(T|t| )time[(Z|z|+...|-...)][time zone][key=value]...
+#[cfg(feature = "temporal")]
+pub fn parse_datetime_temporal(i: &[u8]) -> IResult<&[u8], IxdtfDateTime> {
+    tuple((
+        temporal_date,
+        one_of("Tt "),
+        temporal_time,
+        opt(alt((timezone_hour, timezone_utc_lenient))),
+        opt(ixdtf_time_zone),
+        many0(ixdtf_annotation),
+    ))
+    .map(
+        |(
+            date,
+            _,
+            ((hour, minute, second, nanosecond, fraction_digits), precision),
+            z,
+            time_zone,
+            annotations,
+        )| {
+            IxdtfDateTime {
+                datetime: DateTime {
+                    date,
+                    time: Time {
+                        hour,
+                        minute,
+                        second,
+                        nanosecond,
+                        fraction_digits,
+                        offset: z.unwrap_or(UtcOffset::Unspecified),
+                        precision,
+                    },
+                },
+                time_zone,
+                annotations,
+            }
+        },
+    )
+    .parse(i)
+}
+
 // DURATION
 
+// The optional sign of a single unit, like the `-3D` in `P1M-3D` from ISO 8601-2
+fn duration_unit_sign(i: &[u8]) -> IResult<&[u8], i64> {
+    opt(tag(b"-"))
+        .map(|s| if s.is_some() { -1 } else { 1 })
+        .parse(i)
+}
+
+// [-]1*DIGIT [(,|.) 1*DIGIT] designator, with the fraction in billionths of the unit
+fn duration_component<'a>(
+    designator: &'static [u8],
+) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], (i64, Option<i64>)> {
+    move |i| {
+        terminated(
+            tuple((
+                duration_unit_sign,
+                take_digits,
+                opt(preceded(one_of(",."), |i| {
+                    fraction_of_unit(i, 1_000_000_000)
+                })),
+            )),
+            tag(designator),
+        )
+        // a fraction is less than a billion
+        .map(|(sign, value, fraction): (i64, i64, _)| {
+            (sign * value, fraction.map(|f| sign * f as i64))
+        })
+        .parse(i)
+    }
+}
+
 ///    dur-year          = 1*DIGIT "Y" [dur-month]
-fn duration_year(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"Y"))(i)
+fn duration_year(i: &[u8]) -> IResult<&[u8], (i64, Option<i64>)> {
+    duration_component(b"Y")(i)
 }
 
 ///    dur-month         = 1*DIGIT "M" [dur-day]
-fn duration_month(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"M"))(i)
+fn duration_month(i: &[u8]) -> IResult<&[u8], (i64, Option<i64>)> {
+    duration_component(b"M")(i)
 }
 
 ///    dur-week          = 1*DIGIT "W"
-fn duration_week(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"W"))(i)
+fn duration_week(i: &[u8]) -> IResult<&[u8], i64> {
+    terminated(tuple((duration_unit_sign, take_digits)), tag(b"W"))
+        .map(|(sign, weeks): (i64, i64)| sign * weeks)
+        .parse(i)
 }
 
 //    dur-day           = 1*DIGIT "D"
-fn duration_day(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"D"))(i)
+fn duration_day(i: &[u8]) -> IResult<&[u8], (i64, Option<i64>)> {
+    duration_component(b"D")(i)
 }
 
 ///    dur-hour          = 1*DIGIT "H" [dur-minute]
 ///    dur-time          = "T" (dur-hour / dur-minute / dur-second)
-fn duration_hour(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"H"))(i)
+fn duration_hour(i: &[u8]) -> IResult<&[u8], (i64, Option<i64>)> {
+    duration_component(b"H")(i)
 }
 
 ///    dur-minute        = 1*DIGIT "M" [dur-second]
-fn duration_minute(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag(b"M"))(i)
+fn duration_minute(i: &[u8]) -> IResult<&[u8], (i64, Option<i64>)> {
+    duration_component(b"M")(i)
 }
 
 ///    dur-second        = 1*DIGIT "S"
-fn duration_second(i: &[u8]) -> IResult<&[u8], u32> {
+fn duration_second(i: &[u8]) -> IResult<&[u8], i64> {
     terminated(take_digits, tag(b"S"))(i)
 }
 
 ///    dur-second-ext    = 1*DIGIT (,|.) 1*DIGIT "S"
-fn duration_second_and_millisecond(i: &[u8]) -> IResult<&[u8], (u32, u32)> {
-    alt((
-        // no milliseconds
-        duration_second.map(|m| (m, 0)),
-        terminated(
-            // with milliseconds
-            separated_pair(take_digits, one_of(",."), fraction_millisecond),
-            tag(b"S"),
-        ),
-    ))(i)
-}
-
-fn duration_time(i: &[u8]) -> IResult<&[u8], (u32, u32, u32, u32)> {
+fn duration_second_and_millisecond(i: &[u8]) -> IResult<&[u8], (i64, i64)> {
     tuple((
-        opt(duration_hour),
-        opt(duration_minute),
-        opt(duration_second_and_millisecond),
+        duration_unit_sign,
+        alt((
+            // no milliseconds
+            duration_second.map(|m| (m, 0)),
+            terminated(
+                // with milliseconds
+                separated_pair(take_digits, one_of(",."), fraction_millisecond),
+                tag(b"S"),
+            ),
+        )),
     ))
-    .map(|(h, m, s)| {
-        let (s, ms) = s.unwrap_or((0, 0));
+    .map(|(sign, (s, ms))| (sign * s, sign * i64::from(ms)))
+    .parse(i)
+}
+
+// an optional duration unit, with an optional fraction of it in billionths
+type DurationComponent = Option<(i64, Option<i64>)>;
+
+// (hours, minutes, (seconds, milliseconds))
+type DurationTime = (DurationComponent, DurationComponent, Option<(i64, i64)>);
 
-        (h.unwrap_or(0), m.unwrap_or(0), s, ms)
+// at least one unit has to follow the `T`, so `PT` is not a duration
+fn duration_time(i: &[u8]) -> IResult<&[u8], DurationTime> {
+    verify(
+        tuple((
+            opt(duration_hour),
+            opt(duration_minute),
+            opt(duration_second_and_millisecond),
+        )),
+        |&time| time != (None, None, None),
+    )(i)
+}
+
+// Builds a YMDHMS duration, if at least one unit is present
+// and only the smallest present unit has a fraction.
+fn duration_from_components(
+    y: DurationComponent,
+    mo: DurationComponent,
+    d: DurationComponent,
+    time: Option<DurationTime>,
+) -> Option<Duration> {
+    // at least one element must be present for a valid duration representation
+    if y.is_none() && mo.is_none() && d.is_none() && time.is_none() {
+        return None;
+    }
+
+    let (h, mi, s) = time.unwrap_or((None, None, None));
+    let components = [
+        (y, Unit::Year),
+        (mo, Unit::Month),
+        (d, Unit::Day),
+        (h, Unit::Hour),
+        (mi, Unit::Minute),
+    ];
+
+    // only the smallest present unit may have a fraction
+    let mut has_fraction = false;
+    let mut fraction = None;
+    for (component, unit) in components {
+        if component.is_some() && has_fraction {
+            return None;
+        }
+        if let Some((_, Some(billionths))) = component {
+            has_fraction = true;
+            // a zero fraction like in `PT1.0H` adds nothing
+            if billionths != 0 {
+                fraction = Some(Fraction { unit, billionths });
+            }
+        }
+    }
+    if s.is_some() && has_fraction {
+        return None;
+    }
+
+    let value = |c: DurationComponent| c.map_or(0, |(v, _)| v);
+    let (s, ms) = s.unwrap_or((0, 0));
+
+    Some(Duration::YMDHMS {
+        year: value(y),
+        month: value(mo),
+        day: value(d),
+        hour: value(h),
+        minute: value(mi),
+        second: s,
+        millisecond: ms,
+        fraction,
     })
-    .parse(i)
 }
 
 fn duration_ymdhms(i: &[u8]) -> IResult<&[u8], Duration> {
@@ -317,22 +1279,46 @@ fn duration_ymdhms(i: &[u8]) -> IResult<&[u8], Duration> {
             )),
         ),
         |(y, mo, d, time)| {
-            // at least one element must be present for a valid duration representation
+            duration_from_components(y, mo, d, time).ok_or(Err::Error(Error::from_error_kind(
+                i,
+                nom::error::ErrorKind::Verify,
+            )))
+        },
+    )(i)
+}
+
+// Like `P1Y2W3DT4H`, with the weeks folded into the days
+fn duration_ymdhms_with_weeks(i: &[u8]) -> IResult<&[u8], Duration> {
+    map_res(
+        preceded(
+            tag(b"P"),
+            tuple((
+                opt(duration_year),
+                opt(duration_month),
+                duration_week,
+                opt(duration_day),
+                opt(preceded(tag(b"T"), duration_time)),
+            )),
+        ),
+        |(y, mo, w, d, time)| {
+            let error = || Err::Error(Error::from_error_kind(i, nom::error::ErrorKind::Verify));
+
+            // weeks on their own are left to `duration_weeks`
             if y.is_none() && mo.is_none() && d.is_none() && time.is_none() {
-                return Err(Err::Error((i, nom::error::ErrorKind::Eof)));
+                return Err(error());
+            }
+            // weeks are the smallest unit so far, the larger ones must not have a fraction
+            if matches!(y, Some((_, Some(_)))) || matches!(mo, Some((_, Some(_)))) {
+                return Err(error());
             }
 
-            let (h, mi, s, ms) = time.unwrap_or((0, 0, 0, 0));
-
-            Ok(Duration::YMDHMS {
-                year: y.unwrap_or(0),
-                month: mo.unwrap_or(0),
-                day: d.unwrap_or(0),
-                hour: h,
-                minute: mi,
-                second: s,
-                millisecond: ms,
-            })
+            let (days, fraction) = d.unwrap_or((0, None));
+            let days = w
+                .checked_mul(7)
+                .and_then(|w| w.checked_add(days))
+                .ok_or_else(error)?;
+
+            duration_from_components(y, mo, Some((days, fraction)), time).ok_or_else(error)
         },
     )(i)
 }
@@ -348,27 +1334,38 @@ fn duration_datetime_year(i: &[u8]) -> IResult<&[u8], u32> {
     take_n_digits(i, 4)
 }
 
+// MM, where zero months are allowed
+fn duration_datetime_month(i: &[u8]) -> IResult<&[u8], u32> {
+    n_digit_in_range(i, 2, 0..=12, ErrorKind::InvalidMonth)
+}
+
+// DD, where zero days are allowed
+fn duration_datetime_day(i: &[u8]) -> IResult<&[u8], u32> {
+    n_digit_in_range(i, 2, 0..=31, ErrorKind::InvalidDay)
+}
+
 fn duration_datetime(i: &[u8]) -> IResult<&[u8], Duration> {
     preceded(
         tuple((tag(b"P"), not(sign))),
         tuple((
             duration_datetime_year,
             opt(tag(b"-")),
-            date_month,
+            duration_datetime_month,
             opt(tag(b"-")),
-            date_day,
+            duration_datetime_day,
             tag(b"T"),
-            parse_time,
+            designated_time_of_day,
         )),
     )
     .map(|(year, _, month, _, day, _, t)| Duration::YMDHMS {
-        year,
-        month,
-        day,
-        hour: t.hour,
-        minute: t.minute,
-        second: t.second,
-        millisecond: t.millisecond,
+        year: year.into(),
+        month: month.into(),
+        day: day.into(),
+        hour: t.hour.into(),
+        minute: t.minute.into(),
+        second: t.second.into(),
+        millisecond: t.millisecond().into(),
+        fraction: None,
     })
     .parse(i)
 }
@@ -376,6 +1373,138 @@ fn duration_datetime(i: &[u8]) -> IResult<&[u8], Duration> {
 /// Parses a duration string.
 ///
 /// See [`duration()`][`crate::duration()`] for supported formats.
+// [+/-]P...
 pub fn parse_duration(i: &[u8]) -> IResult<&[u8], Duration> {
-    alt((duration_ymdhms, duration_weeks, duration_datetime))(i)
+    tuple((
+        opt(sign), // [+/-]
+        alt((duration_ymdhms, duration_weeks, duration_datetime)),
+    ))
+    .map(|(s, duration)| match s {
+        Some(-1) => -duration,
+        _ => duration,
+    })
+    .parse(i)
+}
+
+// `P` or `PT` without any units, only accepted as zero with `ParserConfig::empty_durations`
+pub(crate) fn empty_duration(i: &[u8]) -> IResult<&[u8], Duration> {
+    tuple((opt(sign), tag(b"P"), opt(tag(b"T"))))
+        .map(|_| Duration::default())
+        .parse(i)
+}
+
+/// Parses a duration string, also accepting weeks combined with other units like `P1W2D`.
+///
+/// See [`duration_lenient()`][`crate::duration_lenient()`] for supported formats.
+// [+/-]P...
+pub fn parse_duration_lenient(i: &[u8]) -> IResult<&[u8], Duration> {
+    tuple((
+        opt(sign), // [+/-]
+        alt((
+            duration_ymdhms_with_weeks,
+            duration_ymdhms,
+            duration_weeks,
+            duration_datetime,
+        )),
+    ))
+    .map(|(s, duration)| match s {
+        Some(-1) => -duration,
+        _ => duration,
+    })
+    .parse(i)
+}
+
+// INTERVAL
+
+// datetime or date
+fn interval_bound(i: &[u8]) -> IResult<&[u8], Bound> {
+    alt((
+        parse_datetime.map(Bound::DateTime),
+        parse_date.map(Bound::Date),
+    ))(i)
+}
+
+// datetime, date or .. for an open bound
+fn interval_bound_or_open(i: &[u8]) -> IResult<&[u8], Bound> {
+    alt((interval_bound, tag(b"..").map(|_| Bound::Open)))(i)
+}
+
+// start/end, where at most one of them is open
+fn interval_start_end(i: &[u8]) -> IResult<&[u8], Interval> {
+    verify(
+        separated_pair(interval_bound_or_open, tag(b"/"), interval_bound_or_open),
+        |(start, end): &(Bound, Bound)| start.is_known() || end.is_known(),
+    )
+    .map(|(start, end)| Interval::StartEnd { start, end })
+    .parse(i)
+}
+
+// /end or start/, with an unknown start or end,
+// where only a `/` at the end of the input leaves the end unknown, not one before a typo
+fn interval_unknown_bound(i: &[u8]) -> IResult<&[u8], Interval> {
+    alt((
+        preceded(tag(b"/"), interval_bound).map(|end| Interval::StartEnd {
+            start: Bound::Unknown,
+            end,
+        }),
+        terminated(interval_bound, pair(tag(b"/"), eof)).map(|start| Interval::StartEnd {
+            start,
+            end: Bound::Unknown,
+        }),
+    ))(i)
+}
+
+// start/duration
+fn interval_start_duration(i: &[u8]) -> IResult<&[u8], Interval> {
+    separated_pair(interval_bound, tag(b"/"), parse_duration)
+        .map(|(start, duration)| Interval::StartDuration { start, duration })
+        .parse(i)
+}
+
+// duration/end
+fn interval_duration_end(i: &[u8]) -> IResult<&[u8], Interval> {
+    separated_pair(parse_duration, tag(b"/"), interval_bound)
+        .map(|(duration, end)| Interval::DurationEnd { duration, end })
+        .parse(i)
+}
+
+/// Parses an interval string.
+///
+/// See [`interval()`][`crate::interval()`] for supported formats.
+pub fn parse_interval(i: &[u8]) -> IResult<&[u8], Interval> {
+    alt((
+        interval_start_end,
+        interval_start_duration,
+        interval_duration_end,
+        // an empty start or end only after everything else failed
+        interval_unknown_bound,
+        parse_duration.map(Interval::Duration),
+    ))(i)
+}
+
+/// Parses a repeating interval string.
+///
+/// See [`repeating_interval()`][`crate::repeating_interval()`] for supported formats.
+// R[n]/interval
+pub fn parse_repeating_interval(i: &[u8]) -> IResult<&[u8], RepeatingInterval> {
+    tuple((
+        preceded(tag(b"R"), opt(take_digits)), // R[n]
+        tag(b"/"),                             // /
+        parse_interval,                        // interval
+    ))
+    .map(|(count, _, interval)| RepeatingInterval { count, interval })
+    .parse(i)
+}
+
+/// Parses any of a datetime, date, time, duration or interval string, which has to be consumed entirely.
+///
+/// See [`parse_any()`][`crate::parse_any()`] for the order they are tried in.
+pub fn parse_any(i: &[u8]) -> IResult<&[u8], Iso8601> {
+    alt((
+        all_consuming(parse_datetime).map(Iso8601::DateTime),
+        all_consuming(parse_date).map(Iso8601::Date),
+        all_consuming(parse_time).map(Iso8601::Time),
+        all_consuming(parse_duration).map(Iso8601::Duration),
+        all_consuming(parse_interval).map(Iso8601::Interval),
+    ))(i)
 }
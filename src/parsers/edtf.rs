@@ -0,0 +1,213 @@
+//! The low-level parsers for the Extended Date/Time Format (EDTF) of ISO 8601-2.
+
+use alloc::vec::Vec;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::{
+        complete::{one_of, space0},
+        is_digit,
+    },
+    combinator::{map_opt, not, opt, verify},
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+
+use super::{
+    date_day, date_month, date_year, date_year_month, date_year_only, n_digit_in_range, IResult,
+};
+use crate::{
+    Date, DateSet, EdtfDate, ErrorKind, LongYear, Qualification, QualifiedDate, SetKind, SetMember,
+    SubYearGrouping, UnspecifiedDate,
+};
+
+// YYYY-NN
+fn sub_year_grouping(i: &[u8]) -> IResult<&[u8], SubYearGrouping> {
+    separated_pair(date_year, tag(b"-"), |i| {
+        n_digit_in_range(i, 2, 21..=41, ErrorKind::InvalidGrouping)
+    })
+    .map(|(year, grouping)| SubYearGrouping { year, grouping })
+    .parse(i)
+}
+
+/// Parses a season or other sub-year grouping string.
+///
+/// See [`sub_year_grouping()`][`crate::sub_year_grouping()`] for the supported formats.
+pub fn parse_sub_year_grouping(i: &[u8]) -> IResult<&[u8], SubYearGrouping> {
+    // a following digit or separator means this is the start of a complete date instead
+    terminated(sub_year_grouping, not(one_of("-0123456789")))(i)
+}
+
+// YYYY-MM-DD, YYYY-MM, YYYY-NN or YYYY, always in the extended format
+fn edtf_date(i: &[u8]) -> IResult<&[u8], EdtfDate> {
+    alt((
+        tuple((date_year, tag(b"-"), date_month, tag(b"-"), date_day))
+            .map(|(year, _, month, _, day)| EdtfDate::Date(Date::YMD { year, month, day })),
+        date_year_month.map(EdtfDate::Reduced),
+        sub_year_grouping.map(EdtfDate::Grouping),
+        date_year_only.map(EdtfDate::Reduced),
+    ))(i)
+}
+
+// ?, ~ or %
+fn qualification(i: &[u8]) -> IResult<&[u8], Qualification> {
+    one_of("?~%")
+        .map(|q| Qualification {
+            uncertain: q != '~',
+            approximate: q != '?',
+        })
+        .parse(i)
+}
+
+/// Parses a date string with an optional EDTF qualifier.
+///
+/// See [`qualified_date()`][`crate::qualified_date()`] for the supported formats.
+pub fn parse_qualified_date(i: &[u8]) -> IResult<&[u8], QualifiedDate> {
+    tuple((
+        // a following digit or separator means this is not an EDTF date
+        terminated(edtf_date, not(one_of("-0123456789"))),
+        opt(qualification),
+    ))
+    .map(|(date, qualification)| QualifiedDate {
+        date,
+        qualification: qualification.unwrap_or_default(),
+    })
+    .parse(i)
+}
+
+// a digit or X
+fn digit_or_unspecified(i: &[u8]) -> IResult<&[u8], Option<u8>> {
+    one_of("0123456789X")
+        .map(|c| c.to_digit(10).map(|d| d as u8))
+        .parse(i)
+}
+
+// two digits or Xs
+fn two_digits_or_unspecified(i: &[u8]) -> IResult<&[u8], [Option<u8>; 2]> {
+    tuple((digit_or_unspecified, digit_or_unspecified))
+        .map(|(a, b)| [a, b])
+        .parse(i)
+}
+
+/// Parses a date string with unspecified digits.
+///
+/// See [`unspecified_date()`][`crate::unspecified_date()`] for the supported formats.
+pub fn parse_unspecified_date(i: &[u8]) -> IResult<&[u8], UnspecifiedDate> {
+    // a following digit or separator means this is not a complete date
+    terminated(unspecified_date, not(one_of("-0123456789X")))(i)
+}
+
+// YYYY[-MM[-DD]], where each digit may be X
+fn unspecified_date(i: &[u8]) -> IResult<&[u8], UnspecifiedDate> {
+    verify(
+        tuple((
+            tuple((
+                digit_or_unspecified,
+                digit_or_unspecified,
+                digit_or_unspecified,
+                digit_or_unspecified,
+            )),
+            opt(preceded(
+                tag(b"-"),
+                tuple((
+                    two_digits_or_unspecified,
+                    opt(preceded(tag(b"-"), two_digits_or_unspecified)),
+                )),
+            )),
+        ))
+        .map(|((y0, y1, y2, y3), month_day)| UnspecifiedDate {
+            year: [y0, y1, y2, y3],
+            month: month_day.map(|(month, _)| month),
+            day: month_day.and_then(|(_, day)| day),
+        }),
+        // the digits have to cover at least one valid date
+        |date| date.earliest().is_some(),
+    )(i)
+}
+
+// date..date, date.., ..date or date
+fn set_member(i: &[u8]) -> IResult<&[u8], SetMember> {
+    alt((
+        separated_pair(edtf_date, tag(b".."), edtf_date)
+            .map(|(start, end)| SetMember::Range { start, end }),
+        terminated(edtf_date, tag(b"..")).map(SetMember::OnOrAfter),
+        preceded(tag(b".."), edtf_date).map(SetMember::OnOrBefore),
+        edtf_date.map(SetMember::Date),
+    ))(i)
+}
+
+// member, member, ...
+fn set_members(i: &[u8]) -> IResult<&[u8], Vec<SetMember>> {
+    separated_list1(tuple((tag(b","), space0)), set_member)(i)
+}
+
+/// Parses an EDTF set of dates.
+///
+/// See [`date_set()`][`crate::date_set()`] for the supported formats.
+// [member, ...] or {member, ...}
+pub fn parse_date_set(i: &[u8]) -> IResult<&[u8], DateSet> {
+    alt((
+        delimited(tag(b"["), set_members, tag(b"]")).map(|members| DateSet {
+            kind: SetKind::OneOf,
+            members,
+        }),
+        delimited(tag(b"{"), set_members, tag(b"}")).map(|members| DateSet {
+            kind: SetKind::AllOf,
+            members,
+        }),
+    ))(i)
+}
+
+// 1*DIGIT, as long as it fits, along with its number of digits
+fn long_digits(i: &[u8]) -> IResult<&[u8], (i64, usize)> {
+    map_opt(take_while1(is_digit), |digits: &[u8]| {
+        digits
+            .iter()
+            .try_fold(0i64, |value, digit| {
+                value.checked_mul(10)?.checked_add(i64::from(digit - b'0'))
+            })
+            .map(|value| (value, digits.len()))
+    })(i)
+}
+
+/// Parses a year with a `Y` prefix.
+///
+/// See [`long_year()`][`crate::long_year()`] for the supported formats.
+// Y[-]Y*[E*][S*]
+pub fn parse_long_year(i: &[u8]) -> IResult<&[u8], LongYear> {
+    map_opt(
+        tuple((
+            tag(b"Y"),
+            opt(tag(b"-")),
+            long_digits,
+            opt(preceded(tag(b"E"), long_digits)),
+            opt(preceded(tag(b"S"), long_digits)),
+        )),
+        |(_, minus, (mantissa, _), exponent, significant)| {
+            // without an exponent, only years not fitting into four digits need the prefix,
+            // however many leading zeros they are written with
+            if exponent.is_none() && mantissa <= 9999 {
+                return None;
+            }
+
+            let exponent = match exponent {
+                Some((exponent, _)) => Some(u32::try_from(exponent).ok()?),
+                None => None,
+            };
+            let year = mantissa.checked_mul(10i64.checked_pow(exponent.unwrap_or(0))?)?;
+            let significant_digits = match significant {
+                Some((0, _)) => return None,
+                Some((significant, _)) => Some(u32::try_from(significant).ok()?),
+                None => None,
+            };
+
+            Some(LongYear {
+                year: if minus.is_some() { -year } else { year },
+                exponent,
+                significant_digits,
+            })
+        },
+    )(i)
+}
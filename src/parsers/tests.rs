@@ -1,5 +1,10 @@
 use super::*;
 use crate::assert_parser;
+#[cfg(feature = "edtf")]
+use crate::{
+    DateSet, EdtfDate, LongYear, Qualification, QualifiedDate, SetKind, SetMember, SubYearGrouping,
+    UnspecifiedDate,
+};
 
 #[test]
 fn test_date_year() {
@@ -13,6 +18,88 @@ fn test_date_year() {
     assert!(date_year(b"2a03").is_err());
 }
 
+#[test]
+fn test_date_expanded_year() {
+    assert_eq!(Ok((&[][..], 20230)), date_expanded_year(3)(b"+0020230"));
+    assert_eq!(Ok((&[][..], -1)), date_expanded_year(0)(b"-0001"));
+    assert_eq!(Ok((&[][..], -12345)), date_expanded_year(1)(b"-12345"));
+    assert_eq!(Ok((&b"0"[..], 2023)), date_expanded_year(0)(b"+20230"));
+    assert!(date_expanded_year(1)(b"012345").is_err()); // missing sign
+    assert!(date_expanded_year(3)(b"+002023").is_err()); // too few digits
+    assert!(date_expanded_year(6)(b"+9999999999").is_err()); // does not fit
+}
+
+#[test]
+fn test_date_expanded() {
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::YMD {
+                year: 20230,
+                month: 2,
+                day: 8
+            }
+        )),
+        parse_date_expanded(3)(b"+0020230-02-08")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::YMD {
+                year: -1,
+                month: 12,
+                day: 31
+            }
+        )),
+        parse_date_expanded(0)(b"-0001-12-31")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::Week {
+                year: 12023,
+                ww: 6,
+                d: 2
+            }
+        )),
+        parse_date_expanded(1)(b"+12023W062")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::Ordinal {
+                year: -12023,
+                ddd: 39
+            }
+        )),
+        parse_date_expanded(2)(b"-012023-039")
+    );
+    assert!(parse_date_expanded(2)(b"2023-02-08").is_err());
+}
+
+#[test]
+fn test_error_kind() {
+    let error = |input, kind| Err::Error(Error { input, kind });
+
+    assert_eq!(
+        date_month(b"13").unwrap_err(),
+        error(&b"13"[..], Some(ErrorKind::InvalidMonth))
+    );
+    assert_eq!(
+        take_digits::<i64>(b"9223372036854775808D").unwrap_err(),
+        error(
+            &b"9223372036854775808D"[..],
+            Some(ErrorKind::NumberTooLarge)
+        )
+    );
+    // the alternative that got furthest is reported
+    assert_eq!(
+        parse_date(b"2023-W54").unwrap_err(),
+        error(&b"54"[..], Some(ErrorKind::InvalidWeek))
+    );
+    assert_eq!(parse_date(b"nope").unwrap_err(), error(&b"nope"[..], None));
+}
+
 #[test]
 fn test_date_month() {
     assert_eq!(Ok((&[][..], 1)), date_month(b"01"));
@@ -77,6 +164,82 @@ fn test_time_second() {
     assert!(time_second(b"ab").is_err());
 }
 
+#[test]
+fn test_fraction_of_unit() {
+    assert_eq!(Ok((&[][..], 1_800_000)), fraction_of_unit(b"5", 3_600_000));
+    assert_eq!(Ok((&[][..], 900_000)), fraction_of_unit(b"25", 3_600_000));
+    assert_eq!(Ok((&[][..], 15_000)), fraction_of_unit(b"25", 60_000));
+    assert_eq!(
+        Ok((&[][..], 59_999)),
+        fraction_of_unit(b"9999999999", 60_000)
+    );
+    assert_eq!(Ok((&b"Z"[..], 30_000)), fraction_of_unit(b"5Z", 60_000));
+    assert!(fraction_of_unit(b"", 60_000).is_err());
+    assert!(fraction_of_unit(b"Z", 60_000).is_err());
+    assert_eq!(
+        Ok((&[][..], 3_599_999_996_400)),
+        fraction_of_unit(b"999999999", 3_600_000_000_000)
+    );
+}
+
+#[test]
+fn test_fraction_nanosecond_digits() {
+    assert_eq!(
+        Ok((&[][..], (500_000_000, 1))),
+        fraction_nanosecond_digits(b"5")
+    );
+    assert_eq!(
+        Ok((&[][..], (500_000_000, 3))),
+        fraction_nanosecond_digits(b"500")
+    );
+    assert_eq!(
+        Ok((&b"Z"[..], (123_456_789, 9))),
+        fraction_nanosecond_digits(b"1234567891Z")
+    );
+}
+
+#[test]
+fn test_fraction_nanosecond() {
+    assert_eq!(Ok((&[][..], 0)), fraction_nanosecond(b""));
+    assert_eq!(Ok((&[][..], 100_000_000)), fraction_nanosecond(b"1"));
+    assert_eq!(Ok((&[][..], 123_456_000)), fraction_nanosecond(b"123456"));
+    assert_eq!(
+        Ok((&[][..], 123_456_789)),
+        fraction_nanosecond(b"123456789")
+    );
+    assert_eq!(
+        Ok((&b"Z"[..], 123_456_789)),
+        fraction_nanosecond(b"1234567891Z")
+    );
+}
+
+#[test]
+fn test_time_fractional_hour_and_minute() {
+    assert_eq!(
+        Ok((&[][..], (12, 30, 0, 0, 0))),
+        time_fractional_hour(b"12,5")
+    );
+    assert_eq!(
+        Ok((&[][..], (12, 20, 24, 0, 0))),
+        time_fractional_hour(b"12.34")
+    );
+    assert_eq!(
+        Ok((&[][..], (12, 0, 0, 360_000_000, 0))),
+        time_fractional_hour(b"12.0001")
+    );
+    assert_eq!(
+        Ok((&[][..], (12, 30, 15, 0, 0))),
+        time_fractional_minute(b"12:30,25")
+    );
+    assert_eq!(
+        Ok((&[][..], (12, 30, 15, 0, 0))),
+        time_fractional_minute(b"1230.25")
+    );
+    assert!(time_fractional_hour(b"12,").is_err());
+    assert!(time_fractional_hour(b"12:30").is_err());
+    assert!(time_fractional_minute(b"12:30:15,5").is_err());
+}
+
 #[test]
 fn test_date() {
     assert!(parse_date(b"201").is_err());
@@ -91,6 +254,67 @@ fn test_time() {
     assert!(parse_time(b"pppp").is_err());
 }
 
+#[test]
+fn test_time_precision() {
+    fn precision(i: &[u8]) -> Option<Precision> {
+        parse_time(i).ok().map(|(_, time)| time.precision)
+    }
+
+    assert_eq!(precision(b"T23"), Some(Precision::Hour));
+    assert_eq!(precision(b"T23Z"), Some(Precision::Hour));
+    assert_eq!(precision(b"23,5"), Some(Precision::Hour));
+    assert_eq!(precision(b"23:40"), Some(Precision::Minute));
+    assert_eq!(precision(b"T2340"), Some(Precision::Minute));
+    assert_eq!(precision(b"23:40,5"), Some(Precision::Minute));
+    assert_eq!(precision(b"23:40:00"), Some(Precision::Second));
+    assert_eq!(precision(b"234000"), Some(Precision::Second));
+    assert_eq!(precision(b"234000.5"), Some(Precision::Fraction));
+    assert_eq!(precision(b"23:40:00,000"), Some(Precision::Fraction));
+
+    // a lone hour without the designator could as well be the start of something else
+    assert!(parse_time(b"23").is_err());
+    assert!(parse_time(b"T24").is_ok());
+    assert!(parse_time(b"T25").is_err());
+}
+
+#[test]
+fn test_time_basic_format() {
+    assert_eq!(parse_time(b"23:40:00Z"), parse_time(b"T234000Z"));
+    assert_eq!(parse_time(b"23:40:00+01:00"), parse_time(b"234000+0100"));
+    assert_eq!(
+        parse_datetime(b"2023-02-08T23:40:00+01:00"),
+        parse_datetime(b"20230208T234000+0100")
+    );
+
+    assert!(parse_time(b"TT234000Z").is_err());
+    assert!(parse_datetime(b"20230208TT234000+0100").is_err());
+}
+
+#[test]
+fn test_time_end_of_day() {
+    assert_eq!(
+        Ok((
+            &[][..],
+            Time {
+                hour: 24,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Second
+            }
+        )),
+        parse_time(b"24:00:00")
+    );
+    assert!(parse_time(b"24:00").is_ok());
+    assert!(parse_time(b"24:00:00.000Z").is_ok());
+    assert!(parse_time(b"24:30:00").is_err());
+    assert!(parse_time(b"24:00:01").is_err());
+    assert!(parse_time(b"24:00:00.001").is_err());
+    assert!(parse_time(b"24,5").is_err());
+}
+
 #[test]
 fn test_time_with_timezone() {
     assert!(parse_time(b"20:").is_err());
@@ -98,6 +322,104 @@ fn test_time_with_timezone() {
     assert!(parse_time(b"pppp").is_err());
 }
 
+#[test]
+fn test_timezone_seconds() {
+    let offset = |seconds| UtcOffset::Fixed { seconds };
+    assert_eq!(timezone_hour(b"+00:19:32"), Ok((&[][..], offset(1172))));
+    assert_eq!(timezone_hour(b"+001932"), Ok((&[][..], offset(1172))));
+    assert_eq!(timezone_hour(b"-01:00:15"), Ok((&[][..], offset(-3615))));
+    assert_eq!(timezone_hour(b"+01:00:60"), Ok((&b":60"[..], offset(3600))));
+    assert_eq!(timezone_hour(b"+01"), Ok((&[][..], offset(3600))));
+}
+
+#[test]
+fn test_unknown_local_offset() {
+    fn unknown_local(i: &[u8]) -> Option<bool> {
+        parse_time(i)
+            .ok()
+            .map(|(_, time)| time.offset == UtcOffset::UnknownLocal)
+    }
+
+    assert_eq!(unknown_local(b"16:43:16-00:00"), Some(true));
+    assert_eq!(unknown_local(b"16:43:16-0000"), Some(true));
+    assert_eq!(unknown_local(b"16:43:16-00"), Some(true));
+    assert_eq!(unknown_local(b"16:43:16+00:00"), Some(false));
+    assert_eq!(unknown_local(b"16:43:16Z"), Some(false));
+    assert_eq!(unknown_local(b"16:43:16-01:00"), Some(false));
+    assert_eq!(unknown_local(b"16:43:16-00:30"), Some(false));
+    assert_eq!(unknown_local(b"16:43:16"), Some(false));
+}
+
+#[test]
+fn test_date_basic_format() {
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::YMD {
+                year: 2023,
+                month: 2,
+                day: 8
+            }
+        )),
+        parse_date(b"20230208")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::Ordinal {
+                year: 2023,
+                ddd: 39
+            }
+        )),
+        parse_date(b"2023039")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Date::Week {
+                year: 2023,
+                ww: 6,
+                d: 2
+            }
+        )),
+        parse_date(b"2023W062")
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn reduced_date_roundtrip() {
+    assert_parser!(parse_reduced_date, "2015", ReducedDate::Year { year: 2015 });
+    assert_parser!(parse_reduced_date, "2015-11", ReducedDate::YearMonth { year: 2015, month: 11 });
+}
+
+#[test]
+fn test_reduced_date_error() {
+    assert!(parse_reduced_date(b"201").is_err());
+    assert!(parse_reduced_date(b"2015-13").is_err());
+    assert!(parse_reduced_date(b"201511").is_err()); // no basic format
+    assert!(parse_reduced_date(b"2015-11-02").is_err()); // complete date
+    assert!(parse_reduced_date(b"2015-306").is_err()); // ordinal date
+    assert!(parse_reduced_date(b"20151102").is_err()); // basic complete date
+}
+
+#[rustfmt::skip]
+#[test]
+fn week_roundtrip() {
+    assert_parser!(parse_week, "2015-W45", Week { year: 2015, ww: 45 });
+    assert_parser!(parse_week, "2020-W53", Week { year: 2020, ww: 53 });
+}
+
+#[test]
+fn test_week_error() {
+    assert_eq!(parse_week(b"2015W45"), parse_week(b"2015-W45"));
+    assert!(parse_week(b"2015-W00").is_err());
+    assert!(parse_week(b"2015-W54").is_err());
+    assert!(parse_week(b"2015-45").is_err());
+    assert!(parse_week(b"2015-W45-1").is_err()); // week date
+    assert!(parse_week(b"2015W451").is_err()); // basic week date
+}
+
 #[test]
 fn test_date_iso_week_date() {
     assert!(date_iso_week(b"2015-W06-8").is_err());
@@ -125,7 +447,7 @@ fn format_equivalence() {
         parse_datetime(b"20010203T04:05:06+0700")
     );
     assert_eq!(
-        parse_datetime(b"2001-02-03T04:05:00+07:00"),
+        parse_datetime(b"2001-02-03T04:05+07:00"),
         parse_datetime(b"20010203T0405+0700")
     );
     assert_eq!(
@@ -137,6 +459,10 @@ fn format_equivalence() {
         parse_datetime(b"2001-02-03T040506+0700")
     );
     assert_eq!(
+        parse_datetime(b"20010203T040506+0000"),
+        parse_datetime(b"2001-02-03T04:05:06+00:00")
+    );
+    assert_ne!(
         parse_datetime(b"20010203T040506+0000"),
         parse_datetime(b"20010203T040506Z")
     );
@@ -156,6 +482,274 @@ fn test_datetime_error() {
     }
 }
 
+#[rustfmt::skip]
+#[test]
+fn offset_roundtrip() {
+    assert_parser!(parse_offset, "Z", UtcOffset::Utc);
+    assert_parser!(parse_offset, "+05:30", UtcOffset::Fixed { seconds: 19800 });
+    assert_parser!(parse_offset, "-05:30", UtcOffset::Fixed { seconds: -19800 });
+    assert_parser!(parse_offset, "+00:00", UtcOffset::Fixed { seconds: 0 });
+    assert_parser!(parse_offset, "+00:19:32", UtcOffset::Fixed { seconds: 1172 });
+    assert_parser!(parse_offset, "-00:00", UtcOffset::UnknownLocal);
+}
+
+#[test]
+fn test_offset() {
+    assert_eq!(
+        parse_offset("\u{2212}05:30".as_bytes()),
+        Ok((&[][..], UtcOffset::Fixed { seconds: -19800 }))
+    );
+    assert_eq!(
+        parse_offset("\u{2212}00:00".as_bytes()),
+        parse_offset(b"-00:00")
+    );
+    assert_eq!(parse_offset(b"-0530"), parse_offset(b"-05:30"));
+    assert_eq!(parse_offset(b"+05"), parse_offset(b"+05:00"));
+    assert!(parse_offset(b"05:30").is_err());
+    assert!(parse_offset(b"+25:00").is_err());
+    assert!(parse_offset(b"z").is_err());
+}
+
+#[rustfmt::skip]
+#[test]
+fn truncated_date_roundtrip() {
+    assert_parser!(parse_truncated_date, "--02-08", TruncatedDate::MonthDay { month: 2, day: 8 });
+    assert_parser!(parse_truncated_date, "--12", TruncatedDate::Month { month: 12 });
+    assert_parser!(parse_truncated_date, "---31", TruncatedDate::Day { day: 31 });
+    assert_eq!(
+        parse_truncated_date(b"--0208"),
+        Ok((&[][..], TruncatedDate::MonthDay { month: 2, day: 8 }))
+    );
+    assert!(parse_truncated_date(b"--13").is_err());
+    assert!(parse_truncated_date(b"--02-").is_err());
+    assert!(parse_truncated_date(b"---32").is_err());
+    assert!(parse_truncated_date(b"-02-08").is_err());
+}
+
+#[test]
+fn test_datetime_lenient() {
+    for lenient in [
+        "2015-06-26 16:43:16Z",
+        "2015-06-26t16:43:16Z",
+        "2015-06-26T16:43:16z",
+        "2015-06-26 16:43:16z",
+    ] {
+        assert_eq!(
+            parse_datetime_lenient(lenient.as_bytes()),
+            parse_datetime(b"2015-06-26T16:43:16Z"),
+            "{}",
+            lenient
+        );
+        assert_ne!(
+            parse_datetime(lenient.as_bytes()),
+            parse_datetime(b"2015-06-26T16:43:16Z"),
+            "{}",
+            lenient
+        );
+    }
+    assert!(parse_datetime_lenient(b"2015-06-26_16:43:16Z").is_err());
+}
+
+#[test]
+fn test_datetime_rfc3339() {
+    assert_eq!(
+        parse_datetime_rfc3339(b"2015-06-26T16:43:16.123+02:30"),
+        parse_datetime(b"2015-06-26T16:43:16.123+02:30")
+    );
+    assert_eq!(
+        parse_datetime_rfc3339(b"2015-06-26t16:43:16z"),
+        parse_datetime(b"2015-06-26T16:43:16Z")
+    );
+    assert_eq!(
+        parse_datetime_rfc3339(b"2015-06-26 16:43:16-05:00"),
+        parse_datetime(b"2015-06-26T16:43:16-05:00")
+    );
+
+    let rejected = [
+        "2015-06-26T16:43:16",      // missing offset
+        "2015-06-26T16:43Z",        // missing seconds
+        "20150626T164316Z",         // basic format
+        "2015-06-26T164316Z",       // basic time
+        "2015-W26-5T16:43:16Z",     // week date
+        "2015-177T16:43:16Z",       // ordinal date
+        "+2015-06-26T16:43:16Z",    // signed year
+        "2015-06-26T24:00:00Z",     // end of day
+        "2015-06-26T16:43:16,123Z", // comma separator
+        "2015-06-26T16:43:16.Z",    // empty fraction
+        "2015-06-26T16:43:16+0200", // offset without colon
+        "2015-06-26T16:43:16+02",   // offset without minutes
+        "2015-06-26T16,5Z",         // fractional hours
+    ];
+    for rejected in rejected {
+        assert!(
+            parse_datetime_rfc3339(rejected.as_bytes()).is_err(),
+            "{}",
+            rejected
+        );
+    }
+}
+
+#[test]
+fn test_datetime_html() {
+    assert_eq!(
+        parse_datetime_html(b"2015-06-26T16:43:16.123+02:30"),
+        parse_datetime(b"2015-06-26T16:43:16.123+02:30")
+    );
+    assert_eq!(
+        parse_datetime_html(b"2015-06-26 16:43"),
+        parse_datetime(b"2015-06-26T16:43")
+    );
+    assert_eq!(
+        parse_datetime_html(b"2015-06-26T16:43-0500"),
+        parse_datetime(b"2015-06-26T16:43-05:00")
+    );
+    assert_eq!(
+        parse_datetime_html(b"2016-02-29T16:43:16Z"),
+        parse_datetime(b"2016-02-29T16:43:16Z")
+    );
+    assert!(parse_datetime_html(b"12345-06-26T16:43").is_ok());
+
+    let rejected = [
+        "2015-W26-5T16:43",      // week date
+        "2015-177T16:43",        // ordinal date
+        "20150626T1643",         // basic format
+        "2015-06-26T1643",       // basic time
+        "+2015-06-26T16:43",     // signed year
+        "0000-06-26T16:43",      // year zero
+        "2015-02-29T16:43",      // day not in month
+        "2015-06-26t16:43",      // lowercase separator
+        "2015-06-26T16",         // missing minutes
+        "2015-06-26T24:00",      // end of day
+        "2015-06-26T23:59:60",   // leap second
+        "2015-06-26T16:43:16.",  // empty fraction
+        "2015-06-26T16:43:16,1", // comma separator
+        "2015-06-26T16:43+02",   // offset without minutes
+        "2015-06-26T16:43z",     // lowercase UTC
+    ];
+    for rejected in rejected {
+        assert!(
+            !matches!(parse_datetime_html(rejected.as_bytes()), Ok((&[], _))),
+            "{}",
+            rejected
+        );
+    }
+    assert_eq!(
+        parse_datetime_html(b"2015-06-26T16:43:16.1234").map(|(rest, _)| rest),
+        Ok(&b".1234"[..])
+    );
+}
+
+#[test]
+fn test_datetime_python() {
+    assert_eq!(
+        parse_datetime_python(b"2015-06-26T16:43:16.123456+02:30"),
+        parse_datetime(b"2015-06-26T16:43:16.123456+02:30")
+    );
+    assert_eq!(
+        parse_datetime_python(b"2015-06-26 16:43:16.123"),
+        parse_datetime(b"2015-06-26T16:43:16.123")
+    );
+    assert_eq!(
+        parse_datetime_python(b"2015-06-26T16"),
+        parse_datetime(b"2015-06-26T16")
+    );
+    assert_eq!(
+        parse_datetime_python(b"2015-06-26T16:43-05:00"),
+        parse_datetime(b"2015-06-26T16:43-05:00")
+    );
+    assert_eq!(
+        parse_datetime_python(b"2015-06-26T16:43:16+00:19:32"),
+        parse_datetime(b"2015-06-26T16:43:16+00:19:32")
+    );
+    assert_eq!(
+        parse_datetime_python(b"2016-02-29T16:43:16Z"),
+        parse_datetime(b"2016-02-29T16:43:16Z")
+    );
+
+    let rejected = [
+        "2015-W26-5T16:43",                 // week date
+        "2015-177T16:43",                   // ordinal date
+        "20150626T1643",                    // basic format
+        "+2015-06-26T16:43",                // signed year
+        "12345-06-26T16:43",                // five digit year
+        "0000-06-26T16:43",                 // year zero
+        "2015-02-29T16:43",                 // day not in month
+        "2015-06-26T24:00",                 // end of day
+        "2015-06-26T23:59:60",              // leap second
+        "2015-06-26T16:43.123",             // fraction of a minute
+        "2015-06-26T16:43:16.1",            // one digit fraction
+        "2015-06-26T16:43:16.1234",         // four digit fraction
+        "2015-06-26T16:43:16,123",          // comma separator
+        "2015-06-26T16:43+0200",            // basic offset
+        "2015-06-26T16:43+02",              // offset without minutes
+        "2015-06-26T16:43+00:19:32.500000", // fraction of an offset second
+        "2015-06-26T16:43+00:19:32.000000", // zero fraction of an offset second
+    ];
+    for rejected in rejected {
+        assert!(
+            !matches!(parse_datetime_python(rejected.as_bytes()), Ok((&[], _))),
+            "{}",
+            rejected
+        );
+    }
+}
+
+#[test]
+fn test_datetime_ixdtf() {
+    let (rest, parsed) =
+        parse_datetime_ixdtf(b"2023-02-08T23:40:00+01:00[!Europe/Berlin][u-ca=gregory][_x1=a-b]")
+            .unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(
+        parsed.time_zone,
+        Some(TimeZoneAnnotation {
+            name: "Europe/Berlin".into(),
+            critical: true,
+        })
+    );
+    assert_eq!(
+        parsed.annotations,
+        vec![
+            Annotation {
+                key: "u-ca".into(),
+                value: "gregory".into(),
+                critical: false,
+            },
+            Annotation {
+                key: "_x1".into(),
+                value: "a-b".into(),
+                critical: false,
+            },
+        ]
+    );
+
+    let (_, parsed) = parse_datetime_ixdtf(b"2023-02-08T23:40:00Z[+01:00]").unwrap();
+    assert_eq!(parsed.time_zone.unwrap().name, "+01:00");
+    let (_, parsed) = parse_datetime_ixdtf(b"2023-02-08T23:40:00Z[!u-ca=iso8601]").unwrap();
+    assert_eq!(parsed.time_zone, None);
+    assert!(parsed.annotations[0].critical);
+
+    // upper case keys, empty values and `.` as a time zone part are invalid
+    assert_eq!(
+        parse_datetime_ixdtf(b"2023-02-08T23:40:00Z[U-CA=gregory]")
+            .unwrap()
+            .0,
+        b"[U-CA=gregory]"
+    );
+    assert_eq!(
+        parse_datetime_ixdtf(b"2023-02-08T23:40:00Z[u-ca=]")
+            .unwrap()
+            .0,
+        b"[u-ca=]"
+    );
+    assert_eq!(
+        parse_datetime_ixdtf(b"2023-02-08T23:40:00Z[Europe/..]")
+            .unwrap()
+            .0,
+        b"[Europe/..]"
+    );
+}
+
 #[test]
 fn disallows_notallowed() {
     assert!(parse_time(b"30:90:90").is_err());
@@ -165,18 +759,18 @@ fn disallows_notallowed() {
 
 #[test]
 fn test_duration_year() {
-    assert_eq!(Ok((&[][..], 2019)), duration_year(b"2019Y"));
-    assert_eq!(Ok((&[][..], 0)), duration_year(b"0Y"));
-    assert_eq!(Ok((&[][..], 10000)), duration_year(b"10000Y"));
+    assert_eq!(Ok((&[][..], (2019, None))), duration_year(b"2019Y"));
+    assert_eq!(Ok((&[][..], (0, None))), duration_year(b"0Y"));
+    assert_eq!(Ok((&[][..], (10000, None))), duration_year(b"10000Y"));
     assert!(duration_year(b"abcd").is_err());
     assert!(duration_year(b"-1").is_err());
 }
 
 #[test]
 fn test_duration_month() {
-    assert_eq!(Ok((&[][..], 6)), duration_month(b"6M"));
-    assert_eq!(Ok((&[][..], 0)), duration_month(b"0M"));
-    assert_eq!(Ok((&[][..], 12)), duration_month(b"12M"));
+    assert_eq!(Ok((&[][..], (6, None))), duration_month(b"6M"));
+    assert_eq!(Ok((&[][..], (0, None))), duration_month(b"0M"));
+    assert_eq!(Ok((&[][..], (12, None))), duration_month(b"12M"));
     assert!(duration_month(b"ab").is_err());
     assert!(duration_month(b"-1").is_err());
     assert!(duration_month(b"13").is_err());
@@ -194,9 +788,9 @@ fn test_duration_week() {
 
 #[test]
 fn test_duration_day() {
-    assert_eq!(Ok((&[][..], 16)), duration_day(b"16D"));
-    assert_eq!(Ok((&[][..], 0)), duration_day(b"0D"));
-    assert_eq!(Ok((&[][..], 31)), duration_day(b"31D"));
+    assert_eq!(Ok((&[][..], (16, None))), duration_day(b"16D"));
+    assert_eq!(Ok((&[][..], (0, None))), duration_day(b"0D"));
+    assert_eq!(Ok((&[][..], (31, None))), duration_day(b"31D"));
     assert!(duration_day(b"ab").is_err());
     assert!(duration_day(b"-1").is_err());
     assert!(duration_day(b"32").is_err());
@@ -204,9 +798,9 @@ fn test_duration_day() {
 
 #[test]
 fn test_duration_hour() {
-    assert_eq!(Ok((&[][..], 12)), duration_hour(b"12H"));
-    assert_eq!(Ok((&[][..], 0)), duration_hour(b"0H"));
-    assert_eq!(Ok((&[][..], 24)), duration_hour(b"24H"));
+    assert_eq!(Ok((&[][..], (12, None))), duration_hour(b"12H"));
+    assert_eq!(Ok((&[][..], (0, None))), duration_hour(b"0H"));
+    assert_eq!(Ok((&[][..], (24, None))), duration_hour(b"24H"));
     assert!(duration_hour(b"ab").is_err());
     assert!(duration_hour(b"-1").is_err());
     assert!(duration_hour(b"25").is_err());
@@ -214,9 +808,9 @@ fn test_duration_hour() {
 
 #[test]
 fn test_duration_minute() {
-    assert_eq!(Ok((&[][..], 30)), duration_minute(b"30M"));
-    assert_eq!(Ok((&[][..], 0)), duration_minute(b"0M"));
-    assert_eq!(Ok((&[][..], 60)), duration_minute(b"60M"));
+    assert_eq!(Ok((&[][..], (30, None))), duration_minute(b"30M"));
+    assert_eq!(Ok((&[][..], (0, None))), duration_minute(b"0M"));
+    assert_eq!(Ok((&[][..], (60, None))), duration_minute(b"60M"));
     assert!(duration_minute(b"ab").is_err());
     assert!(duration_minute(b"-1").is_err());
     assert!(duration_minute(b"61").is_err());
@@ -245,25 +839,73 @@ fn test_duration_second_and_millisecond1() {
         duration_second_and_millisecond(b"2.34S")
     );
     assert!(duration_second_and_millisecond(b"abS").is_err());
-    assert!(duration_second_and_millisecond(b"-1S").is_err());
+    assert_eq!(
+        Ok((&[][..], (-1, -500))),
+        duration_second_and_millisecond(b"-1.5S")
+    );
+    assert!(duration_second_and_millisecond(b"+1S").is_err());
 }
 
 #[test]
 fn test_duration_time() {
-    assert_eq!(Ok((&[][..], (1, 2, 3, 0))), duration_time(b"1H2M3S"));
-    assert_eq!(Ok((&[][..], (10, 12, 30, 0))), duration_time(b"10H12M30S"));
-    assert_eq!(Ok((&[][..], (1, 0, 3, 0))), duration_time(b"1H3S"));
-    assert_eq!(Ok((&[][..], (0, 2, 0, 0))), duration_time(b"2M"));
-    assert_eq!(Ok((&[][..], (1, 2, 3, 400))), duration_time(b"1H2M3,4S"));
-    assert_eq!(Ok((&[][..], (1, 2, 3, 400))), duration_time(b"1H2M3.4S"));
-    assert_eq!(Ok((&[][..], (0, 0, 0, 123))), duration_time(b"0,123S"));
-    assert_eq!(Ok((&[][..], (0, 0, 0, 123))), duration_time(b"0.123S"));
+    assert_eq!(
+        Ok((&[][..], (Some((1, None)), Some((2, None)), Some((3, 0))))),
+        duration_time(b"1H2M3S")
+    );
+    assert_eq!(
+        Ok((&[][..], (Some((10, None)), Some((12, None)), Some((30, 0))))),
+        duration_time(b"10H12M30S")
+    );
+    assert_eq!(
+        Ok((&[][..], (Some((1, None)), None, Some((3, 0))))),
+        duration_time(b"1H3S")
+    );
+    assert_eq!(
+        Ok((&[][..], (None, Some((2, None)), None))),
+        duration_time(b"2M")
+    );
+    assert_eq!(
+        Ok((&[][..], (Some((1, None)), Some((2, None)), Some((3, 400))))),
+        duration_time(b"1H2M3,4S")
+    );
+    assert_eq!(
+        Ok((&[][..], (Some((1, None)), Some((2, None)), Some((3, 400))))),
+        duration_time(b"1H2M3.4S")
+    );
+    assert_eq!(
+        Ok((&[][..], (None, None, Some((0, 123))))),
+        duration_time(b"0,123S")
+    );
+    assert_eq!(
+        Ok((&[][..], (None, None, Some((0, 123))))),
+        duration_time(b"0.123S")
+    );
+    assert_eq!(
+        Ok((&[][..], (Some((1, Some(500_000_000))), None, None))),
+        duration_time(b"1,5H")
+    );
+}
+
+#[test]
+fn test_duration_component_fraction() {
+    assert_eq!(
+        Ok((&[][..], (0, Some(250_000_000)))),
+        duration_year(b"0.25Y")
+    );
+    assert_eq!(
+        Ok((&[][..], (1, Some(500_000_000)))),
+        duration_minute(b"1,5M")
+    );
+    assert!(duration_day(b"1.D").is_err());
+    assert!(duration_hour(b".5H").is_err());
 }
 
 #[test]
 fn test_duration_ymdhms_error() {
     assert!(duration_ymdhms(b"").is_err());
     assert!(duration_ymdhms(b"P").is_err()); // empty duration is not 0 seconds
+    assert!(duration_ymdhms(b"PT").is_err()); // neither is an empty time
+    assert_eq!(duration_ymdhms(b"P1DT").unwrap().0, b"T"); // the T needs a unit to belong to it
     assert!(duration_ymdhms(b"1Y2M3DT4H5M6S").is_err()); // missing P at start
     assert!(duration_ymdhms(b"T4H5M6S").is_err()); // missing P, required even if no YMD part
 }
@@ -288,19 +930,19 @@ fn test_duration_datetime_error() {
 fn test_duration_second_and_millisecond2() {
     assert_parser!(
         parse_duration, "PT30S", 
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 0, fraction: None }
 
     );
 
     assert_parser!(
         parse_duration, "PT30.123S", 
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 123 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 123, fraction: None }
 
     );
 
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59.123S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123, fraction: None }
     );
 }
 
@@ -313,60 +955,143 @@ fn duration_roundtrip() {
 
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59.123S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16D",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT1S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 1, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 1, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT0.471S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 471 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 471, fraction: None }
     );
     assert_parser!(
         parse_duration, "P2021Y11M",
-        Duration::YMDHMS { year: 2021, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P11M",
-        Duration::YMDHMS { year: 0, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P16D",
-        Duration::YMDHMS { year: 0, month: 0, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P0D",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn duration_signed_roundtrip() {
+    assert_parser!(
+        parse_duration, "-P1DT2H",
+        Duration::YMDHMS { year: 0, month: 0, day: -1, hour: -2, minute: 0, second: 0, millisecond: 0, fraction: None }
+    );
+    assert_parser!(
+        parse_duration, "-P2W", Duration::Weeks(-2)
+    );
+    assert_parser!(
+        parse_duration, "-PT1.500S",
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: -1, millisecond: -500, fraction: None }
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn duration_fraction_roundtrip() {
+    assert_parser!(
+        parse_duration, "PT0.5H",
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: Some(Fraction { unit: Unit::Hour, billionths: 500_000_000 }) }
+    );
+    assert_parser!(
+        parse_duration, "P1Y2.25M",
+        Duration::YMDHMS { year: 1, month: 2, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: Some(Fraction { unit: Unit::Month, billionths: 250_000_000 }) }
+    );
+    assert_parser!(
+        parse_duration, "-P1DT1.5M",
+        Duration::YMDHMS { year: 0, month: 0, day: -1, hour: 0, minute: -1, second: 0, millisecond: 0, fraction: Some(Fraction { unit: Unit::Minute, billionths: -500_000_000 }) }
+    );
+    // only the smallest unit may have a fraction
+    assert!(parse_duration(b"P1.5Y2M").is_err());
+    assert!(parse_duration(b"PT1.5H30S").is_err());
+}
+
+#[test]
+fn test_duration_ymdhms_with_weeks() {
+    assert_eq!(
+        duration_ymdhms_with_weeks(b"P1M2W3D"),
+        duration_ymdhms(b"P1M17D")
+    );
+    assert!(duration_ymdhms_with_weeks(b"P2W").is_err());
+    assert!(duration_ymdhms_with_weeks(b"P1Y2D").is_err());
+    assert!(duration_ymdhms_with_weeks(b"P1.5Y2W").is_err());
+    assert!(duration_ymdhms_with_weeks(b"P2D1W").is_err());
+    assert!(duration_ymdhms_with_weeks(b"P1317624576693539401W1D").is_err());
+    // the strict parser stops after the weeks
+    assert_eq!(
+        parse_duration(b"P1W2D"),
+        Ok((&b"2D"[..], Duration::Weeks(1)))
+    );
+    assert_eq!(
+        parse_duration_lenient(b"P1W2D").map(|(rest, _)| rest),
+        Ok(&b""[..])
     );
 }
 
+#[test]
+fn test_duration_signed() {
+    assert_eq!(parse_duration(b"PT30M"), parse_duration(b"+PT30M"));
+    assert_eq!(
+        Ok((
+            &[][..],
+            Duration::YMDHMS {
+                year: -1,
+                month: -2,
+                day: -3,
+                hour: -4,
+                minute: -5,
+                second: -6,
+                millisecond: 0,
+                fraction: None
+            }
+        )),
+        parse_duration(b"-P0001-02-03T04:05:06")
+    );
+    assert!(parse_duration(b"--P1D").is_err());
+    assert!(parse_duration(b"-").is_err());
+    assert!(parse_duration(b"P+1D").is_err());
+    assert!(parse_duration(b"P--1D").is_err());
+}
+
 #[rustfmt::skip]
 #[test]
 fn duration_multi_digit_hour() {
     assert_parser!(
         parse_duration, "PT12H",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 12, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 12, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "PT8760H",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 365*24, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 365*24, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
 }
 
@@ -375,11 +1100,11 @@ fn duration_multi_digit_hour() {
 fn duration_multi_digit_minute() {
     assert_parser!(
         parse_duration, "PT15M",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 15, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 15, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "PT600M",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 600, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 600, second: 0, millisecond: 0, fraction: None }
     );
 }
 
@@ -388,12 +1113,12 @@ fn duration_multi_digit_minute() {
 fn duration_multi_digit_second() {
     assert_parser!(
         parse_duration, "PT16S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 16, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 16, millisecond: 0, fraction: None }
     );
 
     assert_parser!(
         parse_duration, "PT900S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 900, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 900, millisecond: 0, fraction: None }
     );
 }
 
@@ -402,14 +1127,114 @@ fn duration_multi_digit_second() {
 fn duration_multi_digit_day() {
     assert_parser!(
         parse_duration, "P365D",
-        Duration::YMDHMS { year: 0, month: 0, day: 365, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 365, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
     );
     assert_parser!(
         parse_duration, "P36500D",
-        Duration::YMDHMS { year: 0, month: 0, day: 36500, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, day: 36500, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None }
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn interval_roundtrip() {
+    assert_parser!(
+        parse_interval, "2015-06-26/2015-06-28",
+        Interval::StartEnd {
+            start: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }),
+            end: Bound::Date(Date::YMD { year: 2015, month: 6, day: 28 }),
+        }
+    );
+    assert_parser!(
+        parse_interval, "2015-06-26T16:43:16.0+00:00/P1DT2H",
+        Interval::StartDuration {
+            start: Bound::DateTime(DateTime {
+                date: Date::YMD { year: 2015, month: 6, day: 26 },
+                time: Time { hour: 16, minute: 43, second: 16, nanosecond: 0, fraction_digits: 1, offset: UtcOffset::Fixed { seconds: 0 }, precision: Precision::Fraction},
+            }),
+            duration: Duration::YMDHMS { year: 0, month: 0, day: 1, hour: 2, minute: 0, second: 0, millisecond: 0, fraction: None },
+        }
+    );
+    assert_parser!(
+        parse_interval, "P2W/2015-06-26",
+        Interval::DurationEnd {
+            duration: Duration::Weeks(2),
+            end: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }),
+        }
+    );
+    assert_parser!(
+        parse_interval, "P1Y",
+        Interval::Duration(Duration::YMDHMS { year: 1, month: 0, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None })
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn interval_open_and_unknown_roundtrip() {
+    assert_parser!(
+        parse_interval, "2015-06-26/..",
+        Interval::StartEnd { start: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }), end: Bound::Open }
+    );
+    assert_parser!(
+        parse_interval, "../2015-06-26",
+        Interval::StartEnd { start: Bound::Open, end: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }) }
+    );
+    assert_parser!(
+        parse_interval, "/2015-06-26",
+        Interval::StartEnd { start: Bound::Unknown, end: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }) }
+    );
+    assert_parser!(
+        parse_interval, "2015-06-26/",
+        Interval::StartEnd { start: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }), end: Bound::Unknown }
+    );
+}
+
+#[test]
+fn test_interval_error() {
+    assert!(parse_interval(b"").is_err());
+    assert!(parse_interval(b"/").is_err());
+    assert!(parse_interval(b"../..").is_err()); // no known bound
+    assert!(parse_interval(b"/..").is_err());
+    assert!(parse_interval(b"pppp/2015-06-26").is_err());
+    // an end that does not parse is not an unknown one
+    assert!(parse_interval(b"2023-02-08/15").is_err());
+    assert!(parse_interval(b"2023-02-08/x").is_err());
+    assert!(parse_interval(b"2023-02-08T10:00/12:00").is_err());
+}
+
+#[rustfmt::skip]
+#[test]
+fn repeating_interval_roundtrip() {
+    assert_parser!(
+        parse_repeating_interval, "R5/2015-06-26/P1D",
+        RepeatingInterval {
+            count: Some(5),
+            interval: Interval::StartDuration {
+                start: Bound::Date(Date::YMD { year: 2015, month: 6, day: 26 }),
+                duration: Duration::YMDHMS { year: 0, month: 0, day: 1, hour: 0, minute: 0, second: 0, millisecond: 0, fraction: None },
+            },
+        }
+    );
+    assert_parser!(
+        parse_repeating_interval, "R/P1W",
+        RepeatingInterval { count: None, interval: Interval::Duration(Duration::Weeks(1)) }
+    );
+    assert_parser!(
+        parse_repeating_interval, "R0/P1W",
+        RepeatingInterval { count: Some(0), interval: Interval::Duration(Duration::Weeks(1)) }
     );
 }
 
+#[test]
+fn test_repeating_interval_error() {
+    assert!(parse_repeating_interval(b"").is_err());
+    assert!(parse_repeating_interval(b"R").is_err());
+    assert!(parse_repeating_interval(b"R5").is_err()); // missing interval
+    assert!(parse_repeating_interval(b"R5/").is_err());
+    assert!(parse_repeating_interval(b"5/P1D").is_err()); // missing R
+    assert!(parse_repeating_interval(b"R-5/P1D").is_err());
+}
+
 // #[test]
 // fn corner_cases() {
 //    // how to deal with left overs?
@@ -435,3 +1260,176 @@ fn iso8601_vs_rfc3339() {
     // "2023-02-18_17:29:49.278Z".parse::<DateTime>().unwrap();
     // "2021-208T22:20:32.332320+08".parse::<DateTime>().unwrap();
 }
+
+#[cfg(feature = "edtf")]
+#[rustfmt::skip]
+#[test]
+fn qualified_date_roundtrip() {
+    assert_parser!(
+        parse_qualified_date, "2023-02-08?",
+        QualifiedDate { date: EdtfDate::Date(Date::YMD { year: 2023, month: 2, day: 8 }), qualification: Qualification { uncertain: true, approximate: false } }
+    );
+    assert_parser!(
+        parse_qualified_date, "2023-02~",
+        QualifiedDate { date: EdtfDate::Reduced(ReducedDate::YearMonth { year: 2023, month: 2 }), qualification: Qualification { uncertain: false, approximate: true } }
+    );
+    assert_parser!(
+        parse_qualified_date, "2023%",
+        QualifiedDate { date: EdtfDate::Reduced(ReducedDate::Year { year: 2023 }), qualification: Qualification { uncertain: true, approximate: true } }
+    );
+    assert_parser!(
+        parse_qualified_date, "2023-02-08",
+        QualifiedDate { date: EdtfDate::Date(Date::YMD { year: 2023, month: 2, day: 8 }), qualification: Qualification::default() }
+    );
+}
+
+#[cfg(feature = "edtf")]
+#[rustfmt::skip]
+#[test]
+fn sub_year_grouping_roundtrip() {
+    assert_parser!(parse_sub_year_grouping, "2023-21", SubYearGrouping { year: 2023, grouping: 21 });
+    assert_parser!(parse_sub_year_grouping, "2023-41", SubYearGrouping { year: 2023, grouping: 41 });
+    assert_parser!(
+        parse_qualified_date, "2023-24~",
+        QualifiedDate { date: EdtfDate::Grouping(SubYearGrouping { year: 2023, grouping: 24 }), qualification: Qualification { uncertain: false, approximate: true } }
+    );
+    assert!(parse_sub_year_grouping(b"2023-20").is_err());
+    assert!(parse_sub_year_grouping(b"2023-42").is_err());
+    assert!(parse_sub_year_grouping(b"2023-210").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[rustfmt::skip]
+#[test]
+fn unspecified_date_roundtrip() {
+    assert_parser!(
+        parse_unspecified_date, "201X",
+        UnspecifiedDate { year: [Some(2), Some(0), Some(1), None], month: None, day: None }
+    );
+    assert_parser!(
+        parse_unspecified_date, "2023-XX",
+        UnspecifiedDate { year: [Some(2), Some(0), Some(2), Some(3)], month: Some([None, None]), day: None }
+    );
+    assert_parser!(
+        parse_unspecified_date, "2023-02-XX",
+        UnspecifiedDate { year: [Some(2), Some(0), Some(2), Some(3)], month: Some([Some(0), Some(2)]), day: Some([None, None]) }
+    );
+    assert!(parse_unspecified_date(b"2023-2X").is_err());
+    assert!(parse_unspecified_date(b"2023-02-3X").is_err());
+    assert!(parse_unspecified_date(b"201X0").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[rustfmt::skip]
+#[test]
+fn date_set_roundtrip() {
+    let year = |year| EdtfDate::Reduced(ReducedDate::Year { year });
+    let month = |year, month| EdtfDate::Reduced(ReducedDate::YearMonth { year, month });
+
+    assert_parser!(
+        parse_date_set, "[1760-01, 1760-02, 1760-12..]",
+        DateSet {
+            kind: SetKind::OneOf,
+            members: vec![
+                SetMember::Date(month(1760, 1)),
+                SetMember::Date(month(1760, 2)),
+                SetMember::OnOrAfter(month(1760, 12)),
+            ],
+        }
+    );
+    assert_parser!(
+        parse_date_set, "{1667, 1668, 1670..1672}",
+        DateSet {
+            kind: SetKind::AllOf,
+            members: vec![
+                SetMember::Date(year(1667)),
+                SetMember::Date(year(1668)),
+                SetMember::Range { start: year(1670), end: year(1672) },
+            ],
+        }
+    );
+    assert_parser!(
+        parse_date_set, "[..1984]",
+        DateSet { kind: SetKind::OneOf, members: vec![SetMember::OnOrBefore(year(1984))] }
+    );
+    assert!(parse_date_set(b"[]").is_err());
+    assert!(parse_date_set(b"[1667}").is_err());
+    assert!(parse_date_set(b"{1667,}").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[rustfmt::skip]
+#[test]
+fn long_year_roundtrip() {
+    assert_parser!(parse_long_year, "Y170000002", LongYear { year: 170_000_002, exponent: None, significant_digits: None });
+    assert_parser!(parse_long_year, "Y-170000002", LongYear { year: -170_000_002, exponent: None, significant_digits: None });
+    assert_parser!(parse_long_year, "Y17E7", LongYear { year: 170_000_000, exponent: Some(7), significant_digits: None });
+    assert_parser!(parse_long_year, "Y17E7S3", LongYear { year: 170_000_000, exponent: Some(7), significant_digits: Some(3) });
+    assert!(parse_long_year(b"Y2023").is_err());
+    assert!(parse_long_year(b"Y17E20").is_err());
+    assert!(parse_long_year(b"Y99999999999999999999").is_err());
+    assert!(parse_long_year(b"Y17E7S0").is_err());
+}
+
+#[test]
+fn test_parse_any() {
+    assert!(matches!(
+        parse_any(b"2023-02-08T23:40"),
+        Ok((_, Iso8601::DateTime(_)))
+    ));
+    assert!(matches!(parse_any(b"2023-039"), Ok((_, Iso8601::Date(_)))));
+    assert!(matches!(parse_any(b"23:40"), Ok((_, Iso8601::Time(_)))));
+    assert!(matches!(parse_any(b"-P1D"), Ok((_, Iso8601::Duration(_)))));
+    assert!(matches!(
+        parse_any(b"2023-02-08/.."),
+        Ok((_, Iso8601::Interval(_)))
+    ));
+    assert!(parse_any(b"2023-02-08X").is_err());
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn test_datetime_temporal() {
+    fn datetime_of(i: &[u8]) -> Option<DateTime> {
+        parse_datetime_temporal(i)
+            .ok()
+            .map(|(_, parsed)| parsed.datetime)
+    }
+
+    assert_eq!(
+        datetime_of(b"2023-02-08T23:40:00.123456789+01:00"),
+        parse_datetime(b"2023-02-08T23:40:00.123456789+01:00")
+            .ok()
+            .map(|(_, dt)| dt)
+    );
+    assert_eq!(
+        datetime_of(b"20230208t234000z"),
+        parse_datetime(b"2023-02-08T23:40:00Z")
+            .ok()
+            .map(|(_, dt)| dt)
+    );
+    assert_eq!(
+        datetime_of(b"+002023-02-08 23:40"),
+        parse_datetime(b"2023-02-08T23:40").ok().map(|(_, dt)| dt)
+    );
+    assert_eq!(
+        datetime_of(b"-000001-02-08T23").map(|dt| (dt.date, dt.time.precision)),
+        Some((
+            Date::YMD {
+                year: -1,
+                month: 2,
+                day: 8
+            },
+            Precision::Hour
+        ))
+    );
+    assert_eq!(
+        datetime_of(b"1900-01-01T00:00:00+00:19:32").map(|dt| dt.time.offset_seconds()),
+        Some(Some(1172))
+    );
+
+    assert!(parse_datetime_temporal(b"-000000-02-08T23:40").is_err());
+    assert!(parse_datetime_temporal(b"2023-02-08T24:00").is_err());
+    assert!(parse_datetime_temporal(b"2023-0208T23:40").is_err());
+    assert!(parse_datetime_temporal(b"02023-02-08T23:40").is_err());
+}
@@ -257,3 +257,255 @@ mod duration {
         assert_eq!(deserialized_duration, duration);
     }
 }
+
+mod interval {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::Interval {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IntervalVisitor)
+        }
+    }
+
+    impl Serialize for crate::Interval {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct IntervalVisitor;
+    impl<'de> Visitor<'de> for IntervalVisitor {
+        type Value = crate::Interval;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string that follows iso8601 Interval format")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match Self::Value::from_str(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let interval_json = r#""2023-02-10/P1Y2M3DT4H5M6S""#;
+        let interval = crate::interval("2023-02-10/P1Y2M3DT4H5M6S").unwrap();
+
+        let serialized_interval = serde_json::to_string(&interval).unwrap();
+
+        assert_eq!(serialized_interval, interval_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let interval_json = r#""2023-02-10/P1Y2M3DT4H5M6S""#;
+        let interval = crate::interval("2023-02-10/P1Y2M3DT4H5M6S").unwrap();
+
+        let deserialized_interval = serde_json::from_str::<crate::Interval>(interval_json).unwrap();
+
+        assert_eq!(deserialized_interval, interval);
+    }
+}
+
+mod repeating_interval {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::RepeatingInterval {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(RepeatingIntervalVisitor)
+        }
+    }
+
+    impl Serialize for crate::RepeatingInterval {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct RepeatingIntervalVisitor;
+    impl<'de> Visitor<'de> for RepeatingIntervalVisitor {
+        type Value = crate::RepeatingInterval;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a string that follows iso8601 repeating Interval format"
+            )
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match Self::Value::from_str(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let repeating_json = r#""R5/2023-02-10/P1D""#;
+        let repeating = crate::repeating_interval("R5/2023-02-10/P1D").unwrap();
+
+        let serialized_repeating = serde_json::to_string(&repeating).unwrap();
+
+        assert_eq!(serialized_repeating, repeating_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let repeating_json = r#""R/2023-02-10/P1D""#;
+        let repeating = crate::repeating_interval("R/2023-02-10/P1D").unwrap();
+
+        let deserialized_repeating =
+            serde_json::from_str::<crate::RepeatingInterval>(repeating_json).unwrap();
+
+        assert_eq!(deserialized_repeating, repeating);
+    }
+}
+
+mod reduced_date {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::ReducedDate {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(ReducedDateVisitor)
+        }
+    }
+
+    impl Serialize for crate::ReducedDate {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct ReducedDateVisitor;
+    impl<'de> Visitor<'de> for ReducedDateVisitor {
+        type Value = crate::ReducedDate;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a string that follows iso8601 reduced precision date format"
+            )
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match Self::Value::from_str(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let reduced_date_json = r#""2023-02""#;
+        let reduced_date = crate::reduced_date("2023-02").unwrap();
+
+        let serialized_reduced_date = serde_json::to_string(&reduced_date).unwrap();
+
+        assert_eq!(serialized_reduced_date, reduced_date_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let reduced_date_json = r#""2023-02""#;
+        let reduced_date = crate::reduced_date("2023-02").unwrap();
+
+        let deserialized_reduced_date =
+            serde_json::from_str::<crate::ReducedDate>(reduced_date_json).unwrap();
+
+        assert_eq!(deserialized_reduced_date, reduced_date);
+    }
+}
+
+mod week {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::Week {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(WeekVisitor)
+        }
+    }
+
+    impl Serialize for crate::Week {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct WeekVisitor;
+    impl<'de> Visitor<'de> for WeekVisitor {
+        type Value = crate::Week;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string that follows iso8601 week format")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match Self::Value::from_str(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let week_json = r#""2023-W06""#;
+        let week = crate::week("2023-W06").unwrap();
+
+        let serialized_week = serde_json::to_string(&week).unwrap();
+
+        assert_eq!(serialized_week, week_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let week_json = r#""2023-W06""#;
+        let week = crate::week("2023-W06").unwrap();
+
+        let deserialized_week = serde_json::from_str::<crate::Week>(week_json).unwrap();
+
+        assert_eq!(deserialized_week, week);
+    }
+}
@@ -0,0 +1,223 @@
+//! [proptest](https://docs.rs/proptest) strategies for dates, times, datetimes and durations.
+//!
+//! The strategies named after a type generate valid values within four-digit years,
+//! which are written in a way that [`date()`](crate::date()), [`time()`](crate::time()),
+//! [`datetime()`](crate::datetime()) and [`duration()`](crate::duration()) parse back into the same value.
+//! The `well_formed_` strategies fill every component with any digits of the right count instead,
+//! like `2023-13-45` or `25:61:99`, which are mostly invalid.
+//! Wrap either in [`rendered()`] to get the written string along with the value.
+//!
+//! Only available with the `proptest` feature.
+//!
+//! ```
+//! use iso8601::strategies::{date, rendered};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn parses_back((value, string) in rendered(date())) {
+//!         prop_assert_eq!(iso8601::date(&string), Ok(value));
+//!     }
+//! }
+//! # parses_back();
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+use proptest::prelude::*;
+
+use crate::{calendar, Date, DateTime, Duration, Precision, Time, UtcOffset};
+
+/// Dates that exist in the calendar, in any format, from the year 0000 to 9999.
+pub fn date() -> impl Strategy<Value = Date> {
+    prop_oneof![
+        (0..=9999i32, 1..=12u32).prop_flat_map(|(year, month)| {
+            (1..=calendar::days_in_month(year, month)).prop_map(move |day| Date::YMD {
+                year,
+                month,
+                day,
+            })
+        }),
+        (0..=9999i32).prop_flat_map(|year| {
+            (1..=calendar::weeks_in_iso_year(year), 1..=7u32).prop_map(move |(ww, d)| Date::Week {
+                year,
+                ww,
+                d,
+            })
+        }),
+        (0..=9999i32).prop_flat_map(|year| {
+            (1..=calendar::days_in_year(year)).prop_map(move |ddd| Date::Ordinal { year, ddd })
+        }),
+    ]
+}
+
+/// Dates of any format with two digits for each month, day and week,
+/// three for each ordinal day and one for each weekday, like `2023-00-99`.
+pub fn well_formed_date() -> impl Strategy<Value = Date> {
+    prop_oneof![
+        (0..=9999i32, 0..=99u32, 0..=99u32).prop_map(|(year, month, day)| Date::YMD {
+            year,
+            month,
+            day
+        }),
+        (0..=9999i32, 0..=99u32, 0..=9u32).prop_map(|(year, ww, d)| Date::Week { year, ww, d }),
+        (0..=9999i32, 0..=999u32).prop_map(|(year, ddd)| Date::Ordinal { year, ddd }),
+    ]
+}
+
+/// Offsets of less than a day in whole minutes, or none.
+pub fn offset() -> impl Strategy<Value = UtcOffset> {
+    prop_oneof![
+        Just(UtcOffset::Utc),
+        Just(UtcOffset::UnknownLocal),
+        Just(UtcOffset::Unspecified),
+        (-1439..=1439i32).prop_map(|minutes| UtcOffset::Fixed {
+            seconds: minutes * 60
+        }),
+    ]
+}
+
+/// Times from `00:00:00` to `23:59:59.999999999`,
+/// with up to nine digits of a fraction of a second and any [`offset()`].
+pub fn time() -> impl Strategy<Value = Time> {
+    (0..=23u32, 0..=59u32, 0..=59u32, offset()).prop_flat_map(|(hour, minute, second, offset)| {
+        fraction().prop_map(move |(nanosecond, fraction_digits)| {
+            with_fraction(hour, minute, second, nanosecond, fraction_digits, offset)
+        })
+    })
+}
+
+/// Times with two digits for each hour, minute and second and for the hours of the offset,
+/// like `24:60:99+99:00`.
+pub fn well_formed_time() -> impl Strategy<Value = Time> {
+    let offset = prop_oneof![
+        offset(),
+        (0..=99i32, 0..=59i32, any::<bool>()).prop_map(|(hours, minutes, negative)| {
+            let seconds = hours * 3600 + minutes * 60;
+            UtcOffset::Fixed {
+                seconds: if negative { -seconds } else { seconds },
+            }
+        }),
+    ];
+    (0..=99u32, 0..=99u32, 0..=99u32, offset).prop_flat_map(|(hour, minute, second, offset)| {
+        fraction().prop_map(move |(nanosecond, fraction_digits)| {
+            with_fraction(hour, minute, second, nanosecond, fraction_digits, offset)
+        })
+    })
+}
+
+/// Datetimes of a [`date()`] and a [`time()`].
+pub fn datetime() -> impl Strategy<Value = DateTime> {
+    (date(), time()).prop_map(|(date, time)| DateTime { date, time })
+}
+
+/// Datetimes of a [`well_formed_date()`] and a [`well_formed_time()`].
+pub fn well_formed_datetime() -> impl Strategy<Value = DateTime> {
+    (well_formed_date(), well_formed_time()).prop_map(|(date, time)| DateTime { date, time })
+}
+
+/// Durations in weeks, or of years, months, days, hours, minutes, seconds and milliseconds,
+/// each of them positive or zero.
+pub fn duration() -> impl Strategy<Value = Duration> {
+    let units = (0..=u32::MAX, 0..=u32::MAX, 0..=u32::MAX, 0..=u32::MAX);
+    prop_oneof![
+        (0..=u32::MAX).prop_map(|weeks| Duration::Weeks(weeks.into())),
+        (units, 0..=u32::MAX, 0..=u32::MAX, 0..=999i64).prop_map(
+            |((year, month, day, hour), minute, second, millisecond)| Duration::YMDHMS {
+                year: year.into(),
+                month: month.into(),
+                day: day.into(),
+                hour: hour.into(),
+                minute: minute.into(),
+                second: second.into(),
+                millisecond,
+                fraction: None,
+            }
+        ),
+    ]
+}
+
+/// Pairs each value of `strategy` with the string it is written as.
+pub fn rendered<S>(strategy: S) -> impl Strategy<Value = (S::Value, String)>
+where
+    S: Strategy,
+    S::Value: Display,
+{
+    strategy.prop_map(|value| {
+        let string = value.to_string();
+        (value, string)
+    })
+}
+
+// Billionths of a second and the number of digits they are written with, from none to nine
+fn fraction() -> impl Strategy<Value = (u32, u8)> {
+    (0..=9u8).prop_flat_map(|digits| {
+        let unit = 10u32.pow(9 - u32::from(digits));
+        (0..=999_999_999 / unit).prop_map(move |fraction| (fraction * unit, digits))
+    })
+}
+
+// A time written with seconds, and a fraction of them if it has digits
+fn with_fraction(
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+    fraction_digits: u8,
+    offset: UtcOffset,
+) -> Time {
+    Time {
+        hour,
+        minute,
+        second,
+        nanosecond,
+        fraction_digits,
+        offset,
+        precision: if fraction_digits > 0 {
+            Precision::Fraction
+        } else {
+            Precision::Second
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParserConfig, Trailing};
+
+    proptest! {
+        #[test]
+        fn valid_values_parse_back((date, string) in rendered(date())) {
+            prop_assert!(date.validate().is_ok());
+            prop_assert_eq!(crate::date(&string), Ok(date));
+        }
+
+        #[test]
+        fn valid_datetimes_parse_back((dt, string) in rendered(datetime())) {
+            prop_assert!(dt.validate().is_ok());
+            prop_assert_eq!(dt.time.violations(), []);
+            prop_assert_eq!(crate::datetime(&string), Ok(dt));
+        }
+
+        #[test]
+        fn valid_durations_parse_back((duration, string) in rendered(duration())) {
+            prop_assert_eq!(crate::duration(&string), Ok(duration));
+        }
+
+        #[test]
+        fn well_formed_values_parse_back_or_fail((dt, string) in rendered(well_formed_datetime())) {
+            // the whole string has to match, as `00:00:62` would otherwise be `00:00`
+            let config = ParserConfig {
+                trailing: Trailing::Reject,
+                ..ParserConfig::default()
+            };
+            if let Ok(parsed) = config.datetime(&string) {
+                prop_assert_eq!(parsed, dt);
+            }
+            if let Ok(parsed) = config.date(&dt.date.to_string()) {
+                prop_assert_eq!(parsed, dt.date);
+            }
+        }
+    }
+}
@@ -1,48 +1,714 @@
-use alloc::string::String;
-use core::str::FromStr;
+use alloc::vec::Vec;
+use core::{cmp::Ordering, str::FromStr};
 
-use crate::parsers;
+use crate::{error, parsers, Component, Duration, ErrorKind, ParseError, Unit, UtcOffset};
 
-/// A time object.
-/// ```
-/// # use std::str::FromStr;
-/// assert_eq!(
-///     iso8601::Time::from_str("17:08:08.793Z"),
-///     Ok(iso8601::Time{ hour: 17, minute: 8, second: 8, millisecond: 793, tz_offset_hours: 0, tz_offset_minutes: 00 })
-/// )
-/// ```
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
-pub struct Time {
-    /// a 24th of a day
-    pub hour: u32,
-    /// 60 discrete parts of an hour
-    pub minute: u32,
-    /// a minute are 60 of these
-    pub second: u32,
-    /// everything after a `.`
-    pub millisecond: u32,
-    /// the hour part of the timezone offset from UTC
-    pub tz_offset_hours: i32,
-    /// the minute part of the timezone offset from UTC
-    pub tz_offset_minutes: i32,
+// Declares a struct whose fields are public with the `legacy-fields` feature,
+// and only visible within this crate without it.
+macro_rules! legacy_fields {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $($(#[$field_attr:meta])* $field:ident: $ty:ty,)*
+        }
+    ) => {
+        #[cfg(feature = "legacy-fields")]
+        $(#[$attr])*
+        pub struct $name {
+            $($(#[$field_attr])* pub $field: $ty,)*
+        }
+
+        #[cfg(not(feature = "legacy-fields"))]
+        $(#[$attr])*
+        pub struct $name {
+            $($(#[$field_attr])* pub(crate) $field: $ty,)*
+        }
+    };
+}
+
+legacy_fields! {
+    /// A time object.
+    ///
+    /// Build one with [`Time::new()`] or [`Time::builder()`], which check each component,
+    /// and read it with the getters like [`Time::hour()`].
+    /// The fields are only public with the default `legacy-fields` feature.
+    /// ```
+    /// # use std::str::FromStr;
+    /// let time = iso8601::Time::from_str("17:08:08.793Z").unwrap();
+    /// assert_eq!((time.hour(), time.minute(), time.second()), (17, 8, 8));
+    /// assert_eq!((time.nanosecond(), time.fraction_digits()), (793_000_000, 3));
+    /// assert_eq!(time.offset(), iso8601::UtcOffset::Utc);
+    /// assert_eq!(time.precision(), iso8601::Precision::Fraction);
+    /// ```
+    #[derive(Eq, PartialEq, Debug, Copy, Clone, Default, Hash)]
+    pub struct Time {
+        /// a 24th of a day
+        hour: u32,
+        /// 60 discrete parts of an hour
+        minute: u32,
+        /// a minute are 60 of these
+        second: u32,
+        /// everything after a `.`, in billionths of a second
+        nanosecond: u32,
+        /// how many digits the fraction of a second was written with, like `3` for `.500`,
+        /// or zero without one
+        fraction_digits: u8,
+        /// the offset from UTC following the time, or [`UtcOffset::Unspecified`] if there was none
+        offset: UtcOffset,
+        /// the smallest component the time was written with
+        precision: Precision,
+    }
+}
+
+/// Why a [`Time`] could not be built by [`Time::new()`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum TimeError {
+    /// The hour is above `24`, or `24` without all other components being zero
+    Hour,
+    /// The minute is above `59`
+    Minute,
+    /// The second is above `60`, the leap second
+    Second,
+    /// The nanosecond is not less than a second
+    Nanosecond,
+    /// The offset from UTC is a day or more, see [`TimeBuilder::build()`]
+    Offset,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeError {}
+
+/// The smallest component a date or [`Time`] was written with.
+///
+/// Omitted components are zero, so `23:40` is equal to `23:40:00`
+/// except for its precision.
+/// Precisions are ordered from the coarsest to the finest, so `Precision::Year < Precision::Second`.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    /// Only a year, like `2023`, see [`ReducedDate::precision()`](crate::ReducedDate::precision)
+    Year,
+    /// A year and a month, like `2023-02`
+    Month,
+    /// A complete date, like `2023-02-08`, `2023-W06-3` or `2023-039`
+    Day,
+    /// Only hours, like `T23` or `23,5`
+    Hour,
+    /// Hours and minutes, like `23:40` or `23:40,5`
+    Minute,
+    /// Hours, minutes and seconds, like `23:40:00`
+    #[default]
+    Second,
+    /// Hours, minutes and seconds with a fraction of a second, like `23:40:00.5`
+    Fraction,
 }
 
 impl Time {
+    /// A builder for a time with an offset, which checks the components once they are all set.
+    ///
+    /// ```
+    /// let time = iso8601::Time::builder().hour(23).minute(40).offset_hm(1, 0).build().unwrap();
+    /// assert_eq!(time, iso8601::time("23:40:00+01:00").unwrap());
+    /// ```
+    pub fn builder() -> TimeBuilder {
+        TimeBuilder::default()
+    }
+
+    /// Builds a time without an offset from its components, checking each of them.
+    ///
+    /// The hour `24` is only allowed for the end of a day, `24:00:00`,
+    /// and the second `60` for a leap second.
+    /// The time has a precision of [`Precision::Second`],
+    /// or [`Precision::Fraction`] with a non-zero `nanosecond`.
+    ///
+    /// ```
+    /// use iso8601::{Time, TimeError};
+    ///
+    /// let time = Time::new(23, 40, 0, 500_000_000).unwrap();
+    /// assert_eq!(time, iso8601::time("23:40:00.5").unwrap());
+    /// assert_eq!(Time::new(23, 60, 0, 0), Err(TimeError::Minute));
+    /// assert_eq!(Time::new(24, 0, 0, 1), Err(TimeError::Hour));
+    /// ```
+    pub fn new(hour: u32, minute: u32, second: u32, nanosecond: u32) -> Result<Time, TimeError> {
+        if hour > 24 || (hour == 24 && (minute, second, nanosecond) != (0, 0, 0)) {
+            return Err(TimeError::Hour);
+        }
+        if minute > 59 {
+            return Err(TimeError::Minute);
+        }
+        if second > 60 {
+            return Err(TimeError::Second);
+        }
+        if nanosecond > 999_999_999 {
+            return Err(TimeError::Nanosecond);
+        }
+
+        // the digits of the fraction without trailing zeros
+        let mut fraction_digits = 0;
+        let mut rest = u64::from(nanosecond);
+        while rest != 0 {
+            fraction_digits += 1;
+            rest = rest * 10 % 1_000_000_000;
+        }
+
+        Ok(Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            fraction_digits,
+            offset: UtcOffset::Unspecified,
+            precision: if nanosecond == 0 {
+                Precision::Second
+            } else {
+                Precision::Fraction
+            },
+        })
+    }
+
+    /// All reasons why this time would not be parsed, empty if there are none,
+    /// like a minute above `59` or an offset of more than `23:59:59`.
+    ///
+    /// These are the kinds of [`ParseError`]s the parsers give, checking the same as
+    /// [`Time::new()`] does and the offset, but finding all problems at once.
+    ///
+    /// ```
+    /// use iso8601::{ErrorKind, Time, UtcOffset};
+    ///
+    /// let time = Time::new(23, 40, 0, 0).unwrap();
+    /// assert_eq!(time.violations(), vec![]);
+    /// assert_eq!(
+    ///     time.set_offset(UtcOffset::Fixed { seconds: 25 * 3600 }).violations(),
+    ///     vec![ErrorKind::OffsetOutOfRange]
+    /// );
+    /// ```
+    pub fn violations(&self) -> Vec<ErrorKind> {
+        let mut violations = Vec::new();
+        let mut check = |ok: bool, kind| {
+            if !ok {
+                violations.push(kind);
+            }
+        };
+
+        check(
+            self.hour < 24
+                || (self.hour == 24 && (self.minute, self.second, self.nanosecond) == (0, 0, 0)),
+            ErrorKind::InvalidHour,
+        );
+        check(self.minute <= 59, ErrorKind::InvalidMinute);
+        check(self.second <= 60, ErrorKind::InvalidSecond);
+        check(self.nanosecond <= 999_999_999, ErrorKind::InvalidFraction);
+        // the parsers take up to `23` hours and `59` minutes and seconds
+        check(
+            self.offset.offset_seconds().unsigned_abs() <= 23 * 3600 + 59 * 60 + 59,
+            ErrorKind::OffsetOutOfRange,
+        );
+        violations
+    }
+
+    /// The hour, a 24th of a day.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// The minute, 60 discrete parts of an hour.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// The second, up to `60` for a leap second.
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// The fraction of a second in billionths of a second.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// How many digits the fraction of a second was written with,
+    /// like `3` for `.500`, or zero without one.
+    pub fn fraction_digits(&self) -> u8 {
+        self.fraction_digits
+    }
+
+    /// The offset from UTC as written, see [`DateTime::offset()`](crate::DateTime::offset).
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// The smallest component this time was written with.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// The fraction of a second in whole milliseconds, truncating the rest of [`Time::nanosecond`].
+    ///
+    /// ```
+    /// assert_eq!(iso8601::time("23:40:00.123456789").unwrap().millisecond(), 123);
+    /// ```
+    pub fn millisecond(&self) -> u32 {
+        self.nanosecond / 1_000_000
+    }
+
+    /// The whole seconds since the start of the day, on the clock of this time's offset.
+    ///
+    /// The end of a day `24:00:00` is `86_400`, as is a leap second at the end of a day,
+    /// which counts like the first second of the next minute.
+    ///
+    /// ```
+    /// let time = |s| iso8601::time(s).unwrap();
+    /// assert_eq!(time("23:40:00.5").seconds_of_day(), 85_200);
+    /// assert_eq!(time("23:59:60").seconds_of_day(), 86_400);
+    /// ```
+    pub fn seconds_of_day(&self) -> u32 {
+        // saturating for times out of range, which can only be built by hand
+        self.hour
+            .saturating_mul(3600)
+            .saturating_add(self.minute.saturating_mul(60))
+            .saturating_add(self.second)
+    }
+
+    /// The nanoseconds since the start of the day, like [`Time::seconds_of_day()`].
+    ///
+    /// ```
+    /// assert_eq!(iso8601::time("00:00:01.5").unwrap().nanos_of_day(), 1_500_000_000);
+    /// ```
+    pub fn nanos_of_day(&self) -> u64 {
+        u64::from(self.seconds_of_day()) * 1_000_000_000 + u64::from(self.nanosecond)
+    }
+
+    /// The part of the day passed since its start, from `0.0` up to `1.0`
+    /// for the end of a day, like [`Time::nanos_of_day()`].
+    ///
+    /// ```
+    /// let time = |s| iso8601::time(s).unwrap();
+    /// assert_eq!(time("18:00").day_fraction(), 0.75);
+    /// assert_eq!(time("24:00").day_fraction(), 1.0);
+    /// ```
+    pub fn day_fraction(&self) -> f64 {
+        self.nanos_of_day() as f64 / 86_400_000_000_000.0
+    }
+
+    /// Whether this time was written with at least the components of `precision`,
+    /// like seconds for [`Precision::Second`].
+    ///
+    /// ```
+    /// use iso8601::Precision;
+    ///
+    /// let time = iso8601::time("23:40").unwrap();
+    /// assert!(time.has_precision(Precision::Minute));
+    /// assert!(!time.has_precision(Precision::Second));
+    /// ```
+    pub fn has_precision(&self, precision: Precision) -> bool {
+        self.precision >= precision
+    }
+
     /// Change this time's timezone offset.
     ///
     /// # Arguments
     ///
     /// * `tzo` - A tuple of `(hours, minutes)` specifying the timezone offset from UTC.
+    ///
+    /// The seconds of the offset are reset to zero,
+    /// see [`Time::set_tz_seconds()`] for offsets with seconds.
+    /// The new offset is always a [`UtcOffset::Fixed`] one.
     pub fn set_tz(&self, tzo: (i32, i32)) -> Time {
+        self.set_tz_seconds((tzo.0, tzo.1, 0))
+    }
+
+    /// Change this time's timezone offset, including its seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `tzo` - A tuple of `(hours, minutes, seconds)` specifying the timezone offset from UTC.
+    pub fn set_tz_seconds(&self, tzo: (i32, i32, i32)) -> Time {
+        self.set_offset(UtcOffset::from_hms(tzo.0, tzo.1, tzo.2))
+    }
+
+    /// Change this time's offset from UTC.
+    pub fn set_offset(&self, offset: UtcOffset) -> Time {
         let mut t = *self;
-        t.tz_offset_hours = tzo.0;
-        t.tz_offset_minutes = tzo.1;
+        t.offset = offset;
         t
     }
+
+    /// The offset in seconds east of UTC, or `None` if the time was written without one.
+    ///
+    /// ```
+    /// assert_eq!(iso8601::time("23:40:00+01:00").unwrap().offset_seconds(), Some(3600));
+    /// assert_eq!(iso8601::time("23:40:00Z").unwrap().offset_seconds(), Some(0));
+    /// assert_eq!(iso8601::time("23:40:00").unwrap().offset_seconds(), None);
+    /// ```
+    pub fn offset_seconds(&self) -> Option<i32> {
+        if self.offset.is_specified() {
+            Some(self.offset.offset_seconds())
+        } else {
+            None
+        }
+    }
+}
+
+/// Builds a [`Time`] one component at a time, see [`Time::builder()`].
+///
+/// Components that are not set are zero, and the offset is [`UtcOffset::Unspecified`].
+/// Nothing is checked before [`TimeBuilder::build()`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct TimeBuilder {
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+    offset: UtcOffset,
+}
+
+impl TimeBuilder {
+    /// Sets the hour, up to `24` for the end of a day.
+    pub fn hour(self, hour: u32) -> TimeBuilder {
+        TimeBuilder { hour, ..self }
+    }
+
+    /// Sets the minute.
+    pub fn minute(self, minute: u32) -> TimeBuilder {
+        TimeBuilder { minute, ..self }
+    }
+
+    /// Sets the second, up to `60` for a leap second.
+    pub fn second(self, second: u32) -> TimeBuilder {
+        TimeBuilder { second, ..self }
+    }
+
+    /// Sets the fraction of a second in billionths of a second.
+    pub fn nanosecond(self, nanosecond: u32) -> TimeBuilder {
+        TimeBuilder { nanosecond, ..self }
+    }
+
+    /// Sets the fraction of a second in thousandths of a second.
+    pub fn millisecond(self, millisecond: u32) -> TimeBuilder {
+        self.nanosecond(millisecond.saturating_mul(1_000_000))
+    }
+
+    /// Sets the offset from UTC.
+    pub fn offset(self, offset: UtcOffset) -> TimeBuilder {
+        TimeBuilder { offset, ..self }
+    }
+
+    /// Sets the offset from UTC to `Z`.
+    pub fn utc(self) -> TimeBuilder {
+        self.offset(UtcOffset::Utc)
+    }
+
+    /// Sets the offset from UTC to signed hours and minutes, like `(-5, -30)` for `-05:30`.
+    pub fn offset_hm(self, hours: i32, minutes: i32) -> TimeBuilder {
+        self.offset_hms(hours, minutes, 0)
+    }
+
+    /// Sets the offset from UTC to signed hours, minutes and seconds, see [`UtcOffset::from_hms()`].
+    pub fn offset_hms(self, hours: i32, minutes: i32, seconds: i32) -> TimeBuilder {
+        self.offset(UtcOffset::from_hms(hours, minutes, seconds))
+    }
+
+    /// Builds the time, checking its components like [`Time::new()`]
+    /// and that its offset is less than a day.
+    ///
+    /// ```
+    /// use iso8601::{Time, TimeError};
+    ///
+    /// assert_eq!(Time::builder().hour(23).minute(60).build(), Err(TimeError::Minute));
+    /// assert_eq!(Time::builder().offset_hm(24, 0).build(), Err(TimeError::Offset));
+    /// ```
+    pub fn build(self) -> Result<Time, TimeError> {
+        let time = Time::new(self.hour, self.minute, self.second, self.nanosecond)?;
+        if self.offset.offset_seconds().unsigned_abs() >= 86_400 {
+            return Err(TimeError::Offset);
+        }
+        Ok(time.set_offset(self.offset))
+    }
+}
+
+/// How to deal with a leap second, written as second `60`, like in `23:59:60Z`.
+///
+/// See [`Time::resolve_leap_second()`] and [`DateTime::resolve_leap_second()`](crate::DateTime::resolve_leap_second).
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum LeapSecondPolicy {
+    /// Keep the leap second as is.
+    /// Conversions to chrono represent it as second `59` with more than a second worth of nanoseconds.
+    #[default]
+    Preserve,
+    /// Refuse times with a leap second.
+    Reject,
+    /// Replace the leap second with the last nanosecond of the minute, `59.999999999`.
+    Clamp,
+    /// Carry the leap second over into second `00` of the following minute.
+    Carry,
+}
+
+impl Time {
+    /// Whether this time falls into a leap second, written as second `60`.
+    pub fn is_leap_second(&self) -> bool {
+        self.second == 60
+    }
+
+    /// Applies `policy` to a leap second, see [`LeapSecondPolicy`] for the available choices.
+    ///
+    /// Returns the resulting time and the number of days carried over,
+    /// which can only be `1` when a leap second at the end of a day is carried.
+    /// Returns `None` if the policy rejects this time.
+    /// Times without a leap second are returned unchanged.
+    ///
+    /// ```
+    /// use iso8601::LeapSecondPolicy;
+    ///
+    /// let time = iso8601::time("23:59:60Z").unwrap();
+    /// assert_eq!(time.resolve_leap_second(LeapSecondPolicy::Reject), None);
+    /// assert_eq!(
+    ///     time.resolve_leap_second(LeapSecondPolicy::Clamp),
+    ///     Some((iso8601::time("23:59:59.999999999Z").unwrap(), 0))
+    /// );
+    /// assert_eq!(
+    ///     time.resolve_leap_second(LeapSecondPolicy::Carry),
+    ///     Some((iso8601::time("00:00:00Z").unwrap(), 1))
+    /// );
+    /// ```
+    pub fn resolve_leap_second(&self, policy: LeapSecondPolicy) -> Option<(Time, i32)> {
+        if !self.is_leap_second() {
+            return Some((*self, 0));
+        }
+
+        let mut t = *self;
+        match policy {
+            LeapSecondPolicy::Preserve => Some((t, 0)),
+            LeapSecondPolicy::Reject => None,
+            LeapSecondPolicy::Clamp => {
+                t.second = 59;
+                t.nanosecond = 999_999_999;
+                t.fraction_digits = 9;
+                t.precision = Precision::Fraction;
+                Some((t, 0))
+            }
+            LeapSecondPolicy::Carry => {
+                t.second = 0;
+                t.minute = t.minute.saturating_add(1);
+                if t.minute == 60 {
+                    t.minute = 0;
+                    t.hour = t.hour.saturating_add(1);
+                }
+                Some(t.normalize_midnight())
+            }
+        }
+    }
+
+    /// Whether this is the end of a day, written as `24:00:00`.
+    pub fn is_end_of_day(&self) -> bool {
+        self.hour == 24
+    }
+
+    /// Rolls the end of a day `24:00:00` over to the start of the next day `00:00:00`.
+    ///
+    /// Returns the normalized time and the number of days carried over,
+    /// which is `1` for the end of a day and `0` for every other time.
+    /// Use [`DateTime::normalize_midnight()`](crate::DateTime::normalize_midnight)
+    /// to apply the carried day to a date.
+    ///
+    /// ```
+    /// let time = iso8601::time("24:00:00Z").unwrap();
+    /// let (normalized, days) = time.normalize_midnight();
+    /// assert_eq!(normalized, iso8601::time("00:00:00Z").unwrap());
+    /// assert_eq!(days, 1);
+    /// ```
+    pub fn normalize_midnight(&self) -> (Time, i32) {
+        if self.is_end_of_day() {
+            let mut t = *self;
+            t.hour = 0;
+            (t, 1)
+        } else {
+            (*self, 0)
+        }
+    }
+
+    /// This time with the components smaller than `unit` set to zero, keeping its offset.
+    ///
+    /// Days and larger units give the start of the day `00:00`, also for the end of a day.
+    /// A leap second stays one when truncated to seconds.
+    /// The precision goes down to `unit`, or to hours for days and larger units,
+    /// so the time is written without the components that were set to zero.
+    ///
+    /// ```
+    /// use iso8601::Unit;
+    ///
+    /// let time = |s| iso8601::time(s).unwrap();
+    /// assert_eq!(time("23:40:12.345Z").truncate_to(Unit::Hour), time("T23Z"));
+    /// assert_eq!(time("23:40:12.345Z").truncate_to(Unit::Second), time("23:40:12Z"));
+    /// assert_eq!(time("23:40").truncate_to(Unit::Second), time("23:40"));
+    /// assert_eq!(time("23:40:12").truncate_to(Unit::Day), time("T00"));
+    /// ```
+    pub fn truncate_to(&self, unit: Unit) -> Time {
+        let (hour, minute, second, precision) = match unit {
+            Unit::Second => (self.hour, self.minute, self.second, Precision::Second),
+            Unit::Minute => (self.hour, self.minute, 0, Precision::Minute),
+            Unit::Hour => (self.hour, 0, 0, Precision::Hour),
+            Unit::Day | Unit::Week | Unit::Month | Unit::Year => (0, 0, 0, Precision::Hour),
+        };
+        Time {
+            hour,
+            minute,
+            second,
+            nanosecond: 0,
+            fraction_digits: 0,
+            precision: self.precision.min(precision),
+            ..*self
+        }
+    }
+
+    /// Adds the weeks, days, hours, minutes and seconds of a duration to this time,
+    /// wrapping around midnight.
+    ///
+    /// Returns the resulting time and the number of days carried over,
+    /// which is negative for times moved back past midnight and saturates at the bounds of `i32`,
+    /// see [`Time::checked_add()`] to find out instead.
+    /// The days of the duration are carried over as well.
+    /// Its years and months are not added, as their length depends on the date,
+    /// see [`DateTime::checked_add()`](crate::DateTime::checked_add) to add them too.
+    ///
+    /// The offset stays the same, and the time shows at least the components it needs.
+    /// A leap second counts as the first second of the next minute,
+    /// and the end of a day `24:00:00` as the start of the next.
+    ///
+    /// ```
+    /// let time = |s| iso8601::time(s).unwrap();
+    /// let duration = |s| iso8601::duration(s).unwrap();
+    /// assert_eq!(time("22:00:00").add(&duration("PT9H")), (time("07:00:00"), 1));
+    /// assert_eq!(time("01:30:00Z").add(&duration("-PT2H")), (time("23:30:00Z"), -1));
+    /// assert_eq!(time("12:00:00").add(&duration("P1DT1H")), (time("13:00:00"), 1));
+    /// ```
+    pub fn add(&self, duration: &Duration) -> (Time, i32) {
+        let (time, days) = self.add_nanoseconds(duration.exact_nanoseconds());
+        (time, days.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    /// Like [`Time::add()`], but `None` instead of saturating
+    /// if the number of days carried over does not fit an `i32`.
+    ///
+    /// ```
+    /// let time = iso8601::time("22:00:00").unwrap();
+    /// assert_eq!(time.checked_add(&iso8601::duration("PT9H").unwrap()), Some((iso8601::time("07:00:00").unwrap(), 1)));
+    /// assert_eq!(time.checked_add(&iso8601::Duration::Weeks(i64::MAX)), None);
+    /// ```
+    pub fn checked_add(&self, duration: &Duration) -> Option<(Time, i32)> {
+        let (time, days) = self.add_nanoseconds(duration.exact_nanoseconds());
+        Some((time, days.try_into().ok()?))
+    }
+}
+
+/// Orders times by their time of day as written, ignoring their offsets,
+/// so this is their order in time only if they have the same offset.
+/// See [`DateTime`](crate::DateTime) for an order in time across offsets.
+///
+/// Times of the same time of day are further ordered by their offsets,
+/// the number of digits of their fractions and their precision, as they are not equal.
+///
+/// ```
+/// let time = |s| iso8601::time(s).unwrap();
+/// assert!(time("09:00:00Z") < time("17:00:00Z"));
+/// assert!(time("23:59:59.999") < time("23:59:60"));
+/// assert!(time("23:59:60") < time("24:00:00"));
+/// ```
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Time {
+    // The time of day, then everything else to tell apart all unequal times
+    fn sort_key(&self) -> (u32, u32, u32, u32, (u8, i32), u8, Precision) {
+        (
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+            self.offset.sort_key(),
+            self.fraction_digits,
+            self.precision,
+        )
+    }
+
+    // This time moved by a number of nanoseconds, and the number of days carried over
+    pub(crate) fn add_nanoseconds(&self, nanoseconds: i128) -> (Time, i128) {
+        const SECOND: i128 = 1_000_000_000;
+        const DAY: i128 = 86_400 * SECOND;
+
+        let nanoseconds =
+            (i128::from(self.hour) * 3600 + i128::from(self.minute) * 60 + i128::from(self.second))
+                * SECOND
+                + i128::from(self.nanosecond)
+                + nanoseconds;
+        let days = nanoseconds.div_euclid(DAY);
+
+        // the remainder is less than a day
+        let nanoseconds = nanoseconds.rem_euclid(DAY) as u64;
+        let seconds = (nanoseconds / 1_000_000_000) as u32;
+        let nanosecond = (nanoseconds % 1_000_000_000) as u32;
+        let (minute, second) = (seconds / 60 % 60, seconds % 60);
+        let precision = match () {
+            _ if nanosecond != 0 => Precision::Fraction,
+            _ if second != 0 => Precision::Second,
+            _ if minute != 0 => Precision::Minute,
+            _ => Precision::Hour,
+        };
+        let (mut digits, mut rest) = (9, nanosecond);
+        while digits > 0 && rest % 10 == 0 {
+            rest /= 10;
+            digits -= 1;
+        }
+        let time = Time {
+            hour: seconds / 3600,
+            minute,
+            second,
+            nanosecond,
+            fraction_digits: self.fraction_digits.max(digits),
+            offset: self.offset,
+            precision: self.precision.max(precision),
+        };
+        (time, days)
+    }
+
+    // Keeps `max` of the `digits` the fraction of a second was written with,
+    // rounding half up instead of truncating with `round`
+    pub(crate) fn shorten_fraction(&mut self, digits: &[u8], max: usize, round: bool) {
+        let unit = 10u32.pow(9 - max as u32);
+        self.nanosecond -= self.nanosecond % unit;
+        self.fraction_digits = max as u8;
+        if max == 0 {
+            self.precision = Precision::Second;
+        }
+
+        if round && digits[max] >= b'5' {
+            self.nanosecond += unit;
+            if self.nanosecond == 1_000_000_000 {
+                self.nanosecond = 0;
+                self.second += 1;
+                // even from a leap second, like `23:59:60.9999` to `24:00:00.000`
+                if self.second >= 60 {
+                    self.second = 0;
+                    self.minute += 1;
+                }
+                if self.minute == 60 {
+                    self.minute = 0;
+                    self.hour += 1;
+                }
+            }
+        }
+    }
 }
 
 impl FromStr for Time {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         time(s)
@@ -58,15 +724,64 @@ impl FromStr for Time {
 /// * `0735[00][.123][(Z|(+|-)00:00)]`
 /// * `0735[00][.123][(Z|(+|-)0000)]`
 ///
+/// The offset may also carry seconds, like the local mean time `+00:19:32` or `+001932`.
+/// An offset of `-00:00` is read as UTC whose offset to the local time is unknown,
+/// and recorded as [`UtcOffset::UnknownLocal`].
+///
+/// Omitted seconds are zero, and so are minutes when only the hour is given,
+/// which is only accepted after the time designator `T`, like `T07`.
+/// The components that were present are recorded as the [`Time::precision`].
+///
+/// The smallest component may also carry a decimal fraction,
+/// so `07,5` is read as `07:30:00` and `07:35,5` as `07:35:30`.
+///
+/// The end of a day may be written as `24:00:00`,
+/// see [`Time::normalize_midnight()`] to turn it into the start of the next day.
+///
+/// Each of them may be prefixed with the time designator `T`, like `T073500Z`.
+/// The fraction of a second may be separated by either a `.` or a `,`,
+/// so `07:35:00,123` and `07:35:00.123` are equivalent.
+/// It is kept down to the nanosecond, and any digits after the ninth are dropped.
+///
 /// ## Example
 ///
 /// ```rust
 /// let time = iso8601::time("21:56:42").unwrap();
+/// assert_eq!(iso8601::time("T21:56:42"), Ok(time));
+/// let time = iso8601::time("T21").unwrap();
+/// assert_eq!(time.precision(), iso8601::Precision::Hour);
 /// ```
-pub fn time(string: &str) -> Result<Time, String> {
-    if let Ok((_, parsed)) = parsers::parse_time(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse time: {}", string))
-    }
+pub fn time(string: &str) -> Result<Time, ParseError> {
+    time_bytes(string.as_bytes())
+}
+
+/// Parses a time from bytes, like [`time()`] but without requiring valid UTF-8 first.
+///
+/// ## Example
+///
+/// ```rust
+/// let time = iso8601::time_bytes(b"21:56:42").unwrap();
+/// ```
+pub fn time_bytes(bytes: &[u8]) -> Result<Time, ParseError> {
+    error::prefix(Component::Time, bytes, parsers::parse_time(bytes)).map(|(parsed, _)| parsed)
+}
+
+/// Parses a time at the start of a string, returning it along with the rest of the string.
+///
+/// Unlike [`time()`], the time may be followed by anything,
+/// so parsing can continue after it.
+///
+/// ## Example
+///
+/// ```rust
+/// let (time, rest) = iso8601::time_prefix("23:40:00Z sharp").unwrap();
+/// assert_eq!(rest, " sharp");
+/// ```
+pub fn time_prefix(string: &str) -> Result<(Time, &str), ParseError> {
+    let (parsed, rest) = error::prefix(
+        Component::Time,
+        string.as_bytes(),
+        parsers::parse_time(string.as_bytes()),
+    )?;
+    Ok((parsed, &string[string.len() - rest.len()..]))
 }
@@ -0,0 +1,18 @@
+/// A unit of calendar or clock time.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum Unit {
+    /// A calendar year
+    Year,
+    /// A calendar month
+    Month,
+    /// A week of seven days
+    Week,
+    /// A calendar day
+    Day,
+    /// An hour
+    Hour,
+    /// A minute
+    Minute,
+    /// A second
+    Second,
+}
@@ -0,0 +1,103 @@
+//! Successful parsing does not allocate, see the crate documentation.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use iso8601::*;
+
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// The number of allocations made by the current thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    drop(result);
+    after - before
+}
+
+#[track_caller]
+fn assert_no_allocations<T, E: std::fmt::Debug>(f: impl FnOnce() -> Result<T, E>) {
+    assert_eq!(allocations(|| f().unwrap()), 0);
+}
+
+#[test]
+fn parsing_does_not_allocate() {
+    assert_no_allocations(|| date("2023-02-08"));
+    assert_no_allocations(|| date("2023-W06-3"));
+    assert_no_allocations(|| date("2023-039"));
+    assert_no_allocations(|| date_bytes(b"20230208"));
+    assert_no_allocations(|| date_prefix("2023-02-08 and more"));
+    assert_no_allocations(|| date_expanded("+002023-02-08", 2));
+    assert_no_allocations(|| reduced_date("2023-02"));
+    assert_no_allocations(|| week("2023-W06"));
+    assert_no_allocations(|| truncated_date("--02-08"));
+    assert_no_allocations(|| time("23:40:00.123456789+05:30"));
+    assert_no_allocations(|| time_bytes(b"T2340Z"));
+    assert_no_allocations(|| time_prefix("23:40Z, then"));
+    assert_no_allocations(|| offset("\u{2212}05:30"));
+    assert_no_allocations(|| datetime("2023-02-08T23:40:00.5Z"));
+    assert_no_allocations(|| datetime_rfc3339("2023-02-08T23:40:00-05:00"));
+    assert_no_allocations(|| datetime_lenient("2023-02-08 23:40:00z"));
+    assert_no_allocations(|| datetime_html("2023-02-08T23:40"));
+    assert_no_allocations(|| datetime_python("2023-02-08T23:40:00.000123+01:00"));
+    assert_no_allocations(|| duration("P1Y2M3DT4H5M6.789S"));
+    assert_no_allocations(|| duration("-PT1,5H"));
+    assert_no_allocations(|| duration_lenient("P1W2D"));
+    assert_no_allocations(|| interval("2023-02-08T23:40:00Z/P1D"));
+    assert_no_allocations(|| repeating_interval("R5/2023-02-08/P1D"));
+    assert_no_allocations(|| parse_any("2023-02-08T23:40:00Z"));
+    assert_no_allocations(|| "2023-02-08".parse::<ValidDate>());
+    assert_no_allocations(|| "2023-02-08T23:40:00Z".parse::<ValidDateTime>());
+
+    for profile in [
+        Profile::Strict8601,
+        Profile::Rfc3339,
+        Profile::Html,
+        Profile::Python,
+        Profile::Lenient,
+    ] {
+        let config = ParserConfig::from(profile);
+        assert_no_allocations(|| config.datetime("2023-02-08T23:40:00.123+01:00"));
+        assert_no_allocations(|| config.date("2023-02-08"));
+    }
+    let config = ParserConfig {
+        max_fraction_digits: Some(3),
+        excess_fraction_digits: ExcessDigits::Round,
+        offset_range: OffsetRange::EighteenHours,
+        ..Profile::Lenient.into()
+    };
+    assert_no_allocations(|| config.time("23:59:59.9999+18:00"));
+    assert_no_allocations(|| config.duration("PT"));
+}
+
+#[test]
+fn failing_allocates_the_error() {
+    assert!(allocations(|| date("2023-13-08").unwrap_err()) > 0);
+    // uppercasing the input needs a copy of it
+    let config = ParserConfig::from(Profile::Lenient);
+    assert!(allocations(|| config.datetime("2023-02-08t23:40z").unwrap()) > 0);
+}
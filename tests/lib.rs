@@ -20,6 +20,153 @@ fn test_date() {
     );
 }
 
+#[test]
+fn test_date_expanded() {
+    assert_eq!(
+        Ok(Date::YMD {
+            year: 20230,
+            month: 2,
+            day: 8,
+        }),
+        date_expanded("+0020230-02-08", 3)
+    );
+    assert_eq!(
+        Ok(Date::YMD {
+            year: -1,
+            month: 12,
+            day: 31,
+        }),
+        date_expanded("-0001-12-31", 0)
+    );
+    assert!(date_expanded("+0020230-02-08", 2).is_err());
+    assert!(date_expanded("0020230-02-08", 3).is_err());
+
+    // without `date_expanded()`, a signed year cannot have more than four digits
+    let error = date("+0020230-02-08").unwrap_err();
+    assert_eq!((error.kind, error.offset), (ErrorKind::Syntax, 5));
+    assert!(date("-20230208").is_err());
+    assert_eq!(date("-2023-02-08").map(|d| d.year()), Ok(-2023));
+    assert_eq!(date("+2023W061"), date("2023-W06-1"));
+}
+
+#[test]
+fn test_reduced_date() {
+    assert_eq!(Ok(ReducedDate::Year { year: 2023 }), reduced_date("2023"));
+    assert_eq!(
+        Ok(ReducedDate::YearMonth {
+            year: 2023,
+            month: 2
+        }),
+        reduced_date("2023-02")
+    );
+    assert_eq!(
+        Ok(ReducedDate::YearMonth {
+            year: -333,
+            month: 7
+        }),
+        reduced_date("-0333-07")
+    );
+
+    assert!(reduced_date("2023-13").is_err());
+    assert!(reduced_date("2023039").is_err());
+    assert!(date("2023-02").is_err());
+}
+
+#[test]
+fn test_week() {
+    assert_eq!(Ok(Week { year: 2023, ww: 6 }), week("2023-W06"));
+    assert_eq!(Ok(Week { year: 2023, ww: 6 }), week("2023W06"));
+    assert_eq!(
+        Date::Week {
+            year: 2023,
+            ww: 6,
+            d: 1
+        },
+        week("2023-W06").unwrap().first_day()
+    );
+    assert_eq!(
+        Date::Week {
+            year: 2023,
+            ww: 6,
+            d: 7
+        },
+        week("2023-W06").unwrap().last_day()
+    );
+
+    assert!(week("2023-W06-1").is_err());
+    assert!(date("2023-W06").is_err());
+}
+
+#[test]
+fn test_truncated_date() {
+    assert_eq!(
+        Ok(TruncatedDate::MonthDay { month: 2, day: 8 }),
+        truncated_date("--02-08")
+    );
+    assert_eq!(
+        Ok(TruncatedDate::MonthDay { month: 2, day: 8 }),
+        truncated_date("--0208")
+    );
+    assert_eq!(
+        Ok(TruncatedDate::Month { month: 2 }),
+        truncated_date("--02")
+    );
+    assert_eq!(Ok(TruncatedDate::Day { day: 8 }), truncated_date("---08"));
+    assert!(truncated_date("2023-02-08").is_err());
+    assert!(date("--02-08").is_err());
+}
+
+#[test]
+fn test_nanosecond() {
+    let t = time("23:40:00.123456789Z").unwrap();
+    assert_eq!(t.nanosecond(), 123_456_789);
+    assert_eq!(t.millisecond(), 123);
+    assert_eq!(t.to_string(), "23:40:00.123456789Z");
+
+    assert_eq!(
+        time("23:40:00.1234567891").map(|t| t.nanosecond()),
+        Ok(123_456_789)
+    );
+    assert_eq!(time("23:40:00.000001").map(|t| t.nanosecond()), Ok(1_000));
+    assert_eq!(
+        time("23:40:00.000001").unwrap().to_string(),
+        "23:40:00.000001"
+    );
+    assert_eq!(time("23:40:00.005").unwrap().to_string(), "23:40:00.005");
+    assert_eq!(
+        datetime("2023-02-08T23:40,000000001").map(|dt| dt.time().nanosecond()),
+        Ok(60)
+    );
+}
+
+#[test]
+fn test_fraction_digits() {
+    let short = time("23:40:00.5Z").unwrap();
+    let long = time("23:40:00.500Z").unwrap();
+    assert_eq!(short.nanosecond(), long.nanosecond());
+    assert_eq!((short.fraction_digits(), long.fraction_digits()), (1, 3));
+    assert_ne!(short, long);
+
+    for written in [
+        "23:40:00",
+        "23:40:00.5Z",
+        "23:40:00.500Z",
+        "23:40:00.000000+01:00",
+        "23:40:00.123456789-05:30",
+    ] {
+        assert_eq!(time(written).unwrap().to_string(), written);
+    }
+    assert_eq!(
+        datetime_rfc3339("2023-02-08T23:40:00.120Z")
+            .unwrap()
+            .to_string(),
+        "2023-02-08T23:40:00.120Z"
+    );
+    assert_eq!(time("23:40,5").unwrap().fraction_digits(), 0);
+    assert_eq!(time("23:40,5").unwrap().to_string(), "23:40:30");
+}
+
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn test_millisecond() {
     let mut i = 0;
@@ -30,9 +177,10 @@ fn test_millisecond() {
                 hour: 16,
                 minute: 43,
                 second: 0,
-                millisecond: i,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: i * 1_000_000,
+                fraction_digits: 3,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Fraction
             }),
             time(format!("16:43:00.{:0>3}", i).as_str())
         );
@@ -43,9 +191,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:00.1")
     );
@@ -54,9 +203,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 120,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 120_000_000,
+            fraction_digits: 2,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:00.12")
     );
@@ -65,9 +215,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:00.123")
     );
@@ -76,9 +227,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:00.4321")
     );
@@ -86,10 +238,11 @@ fn test_millisecond() {
         Ok(Time {
             hour: 16,
             minute: 43,
-            second: 0,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            second: 25,
+            nanosecond: 926_000_000,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Minute
         }),
         time("16:43.4321")
     );
@@ -98,9 +251,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 11,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:11.4321")
     );
@@ -110,9 +264,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:00,1")
     );
@@ -122,9 +277,10 @@ fn test_millisecond() {
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_450_000,
+            fraction_digits: 5,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("04:05:06.12345")
     );
@@ -140,9 +296,10 @@ fn test_millisecond() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 123_450_000,
+                fraction_digits: 5,
+                offset: UtcOffset::Utc,
+                precision: Precision::Fraction
             }
         }),
         datetime("2001-W05-6T04:05:06.12345Z")
@@ -153,9 +310,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
         }),
         time("16:43:16.123")
     );
@@ -164,9 +322,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::Fixed { seconds: 0 },
+            precision: Precision::Fraction
         }),
         time("16:43:16.123+00:00")
     );
@@ -175,9 +334,10 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::UnknownLocal,
+            precision: Precision::Fraction
         }),
         time("16:43:16.123-00:00")
     );
@@ -186,14 +346,16 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 5,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::Fixed { seconds: 18000 },
+            precision: Precision::Fraction
         }),
         time("16:43:16.123+05:00")
     );
 }
 
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn test_time() {
     assert_eq!(
@@ -202,9 +364,10 @@ fn test_time() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second,
         })
     );
     assert_eq!(
@@ -213,9 +376,10 @@ fn test_time() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Minute,
         })
     );
 
@@ -224,28 +388,264 @@ fn test_time() {
     assert!(time("pppp").is_err());
 }
 
+#[test]
+fn test_end_of_day() {
+    let end_of_day = time("24:00:00+01:00").unwrap();
+    assert!(end_of_day.is_end_of_day());
+    assert_eq!(
+        (time("00:00:00+01:00").unwrap(), 1),
+        end_of_day.normalize_midnight()
+    );
+    assert_eq!(
+        (time("23:59:59").unwrap(), 0),
+        time("23:59:59").unwrap().normalize_midnight()
+    );
+    assert!(time("24:01:00").is_err());
+
+    let normalize = |s| datetime(s).unwrap().normalize_midnight();
+    assert_eq!(
+        normalize("2023-02-28T24:00"),
+        datetime("2023-03-01T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2024-02-28T24:00"),
+        datetime("2024-02-29T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2023-12-31T24:00"),
+        datetime("2024-01-01T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2023-365T24:00"),
+        datetime("2024-001T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2024-365T24:00"),
+        datetime("2024-366T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2023-W06-7T24:00"),
+        datetime("2023-W07-1T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2023-W52-7T24:00"),
+        datetime("2024-W01-1T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2020-W52-7T24:00"),
+        datetime("2020-W53-1T00:00").unwrap()
+    );
+    assert_eq!(
+        normalize("2023-02-08T23:00"),
+        datetime("2023-02-08T23:00").unwrap()
+    );
+}
+
+#[test]
+fn test_leap_second() {
+    let leap = time("23:59:60.5Z").unwrap();
+    assert!(leap.is_leap_second());
+    assert!(!time("23:59:59Z").unwrap().is_leap_second());
+
+    assert_eq!(
+        Some((leap, 0)),
+        leap.resolve_leap_second(LeapSecondPolicy::Preserve)
+    );
+    assert_eq!(None, leap.resolve_leap_second(LeapSecondPolicy::Reject));
+    assert_eq!(
+        Some((time("23:59:59.999999999Z").unwrap(), 0)),
+        leap.resolve_leap_second(LeapSecondPolicy::Clamp)
+    );
+    assert_eq!(
+        Some((time("00:00:00.5Z").unwrap(), 1)),
+        leap.resolve_leap_second(LeapSecondPolicy::Carry)
+    );
+    assert_eq!(
+        Some((time("05:30:00+05:30").unwrap(), 0)),
+        time("05:29:60+05:30")
+            .unwrap()
+            .resolve_leap_second(LeapSecondPolicy::Carry)
+    );
+
+    let leap = datetime("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(
+        Some(datetime("2017-01-01T00:00:00Z").unwrap()),
+        leap.resolve_leap_second(LeapSecondPolicy::Carry)
+    );
+    assert_eq!(None, leap.resolve_leap_second(LeapSecondPolicy::Reject));
+    let regular = datetime("2016-12-31T23:59:59Z").unwrap();
+    assert_eq!(
+        Some(regular),
+        regular.resolve_leap_second(LeapSecondPolicy::Reject)
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn test_time_set_tz() {
     let original = Time {
         hour: 0,
         minute: 0,
         second: 0,
-        millisecond: 0,
-        tz_offset_hours: 0,
-        tz_offset_minutes: 0,
+        nanosecond: 0,
+        fraction_digits: 0,
+        offset: UtcOffset::Unspecified,
+        precision: Precision::Second,
     };
     let expected = Time {
         hour: 0,
         minute: 0,
         second: 0,
-        millisecond: 0,
-        tz_offset_hours: 2,
-        tz_offset_minutes: 30,
+        nanosecond: 0,
+        fraction_digits: 0,
+        offset: UtcOffset::Fixed { seconds: 9000 },
+        precision: Precision::Second,
     };
 
     assert_eq!(expected, original.set_tz((2, 30)));
 }
 
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_time_offset_seconds() {
+    assert_eq!(
+        Ok(Time {
+            hour: 12,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 1172 },
+            precision: Precision::Second,
+        }),
+        time("12:00:00+00:19:32")
+    );
+    assert_eq!(time("12:00:00+00:19:32"), time("120000+001932"));
+    assert_eq!(
+        time("12:00:00-00:19:32").map(|t| t.offset_seconds()),
+        Ok(Some(-1172))
+    );
+    assert_eq!(
+        time("12:00:00-00:30").map(|t| t.offset_seconds()),
+        Ok(Some(-1800))
+    );
+    assert_eq!(
+        time("12:00:00-05:30").map(|t| t.offset_seconds()),
+        Ok(Some(-19_800))
+    );
+    assert_eq!(
+        time("12:00:00-05").map(|t| t.offset_seconds()),
+        Ok(Some(-18_000))
+    );
+    assert_eq!(
+        datetime("1900-01-01T12:00:00+00:19:32").map(|dt| dt.time.offset_seconds()),
+        Ok(Some(1172))
+    );
+
+    let original = time("12:00:00+00:19:32").unwrap();
+    assert_eq!(original.set_tz((1, 0)), time("12:00:00+01:00").unwrap());
+    assert_eq!(original.set_tz_seconds((0, 19, 32)), original);
+}
+
+#[test]
+fn test_offset() {
+    assert_eq!(Ok(UtcOffset::Fixed { seconds: -19800 }), offset("−05:30"));
+    assert_eq!(Ok(UtcOffset::Fixed { seconds: 3600 }), offset("+01"));
+    assert_eq!(Ok(UtcOffset::Utc), offset("Z"));
+    assert_eq!(Ok(UtcOffset::UnknownLocal), offset("-00:00"));
+    assert_eq!(offset("+00:19:32").map(|o| o.offset_seconds()), Ok(1172));
+    assert_eq!("-05:30".parse::<UtcOffset>(), offset("-0530"));
+    assert_eq!(offset("−05:30").unwrap().to_string(), "-05:30");
+    assert!(offset("05:30").is_err());
+    assert!(offset("").is_err());
+
+    assert_eq!(time("23:40:00−05:30"), time("23:40:00-05:30"));
+}
+
+#[test]
+fn test_unspecified_offset() {
+    let local = time("23:40:00").unwrap();
+    assert_eq!(local.offset(), UtcOffset::Unspecified);
+    assert_eq!(local.offset_seconds(), None);
+    assert_eq!(local.to_string(), "23:40:00");
+
+    let utc = time("23:40:00Z").unwrap();
+    let zero = time("23:40:00+00:00").unwrap();
+    assert_eq!(utc.offset(), UtcOffset::Utc);
+    assert_eq!(zero.offset(), UtcOffset::Fixed { seconds: 0 });
+    assert_ne!(local, utc);
+    assert_ne!(utc, zero);
+    assert_eq!(utc.offset_seconds(), zero.offset_seconds());
+    assert_eq!(utc.to_string(), "23:40:00Z");
+    assert_eq!(zero.to_string(), "23:40:00+00:00");
+
+    assert_eq!(
+        time("23:40:00-05:30").unwrap().to_string(),
+        "23:40:00-05:30"
+    );
+    assert_eq!(local.set_tz((-5, -30)), time("23:40:00-05:30").unwrap());
+}
+
+#[test]
+fn test_datetime_offset_as_written() {
+    for parse in [datetime, datetime_lenient, datetime_html, datetime_python] {
+        let utc = parse("2023-02-08T23:40:00Z").unwrap();
+        let zero = parse("2023-02-08T23:40:00+00:00").unwrap();
+        let local = parse("2023-02-08T23:40:00").unwrap();
+
+        assert_eq!(utc.offset(), UtcOffset::Utc);
+        assert_eq!(zero.offset(), UtcOffset::Fixed { seconds: 0 });
+        assert_eq!(local.offset(), UtcOffset::Unspecified);
+        assert_eq!(utc.offset_seconds(), Some(0));
+        assert_eq!(zero.offset_seconds(), Some(0));
+        assert_eq!(local.offset_seconds(), None);
+    }
+
+    assert_eq!(
+        datetime_rfc3339("2023-02-08T23:40:00z").unwrap().offset(),
+        UtcOffset::Utc
+    );
+    assert_eq!(
+        datetime_rfc3339("2023-02-08T23:40:00+00:00")
+            .unwrap()
+            .offset(),
+        UtcOffset::Fixed { seconds: 0 }
+    );
+    assert_eq!(
+        datetime_ixdtf("2023-02-08T23:40:00Z[Europe/London]")
+            .unwrap()
+            .datetime
+            .offset(),
+        UtcOffset::Utc
+    );
+}
+
+#[test]
+fn test_unknown_local_offset() {
+    let unknown = datetime_rfc3339("2023-02-08T23:40:00-00:00").unwrap();
+    let utc = datetime_rfc3339("2023-02-08T23:40:00Z").unwrap();
+
+    assert_eq!(unknown.time().offset(), UtcOffset::UnknownLocal);
+    assert_eq!(utc.time().offset(), UtcOffset::Utc);
+    assert_ne!(unknown, utc);
+    assert_eq!(unknown.time().set_offset(UtcOffset::Utc), utc.time());
+
+    assert_eq!(
+        time("23:40:00-00:00").unwrap().offset(),
+        UtcOffset::UnknownLocal
+    );
+    assert_ne!(
+        time("23:40:00+00:00").unwrap().offset(),
+        UtcOffset::UnknownLocal
+    );
+    assert_eq!(
+        unknown.to_string(),
+        datetime("2023-02-08T23:40:00-00:00").unwrap().to_string()
+    );
+    assert!(unknown.to_string().ends_with("-00:00"));
+}
+
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time1() {
     assert_eq!(
@@ -254,12 +654,14 @@ fn short_time1() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Minute,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time2() {
     assert_eq!(
@@ -268,12 +670,14 @@ fn short_time2() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Minute,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time3() {
     assert_eq!(
@@ -282,12 +686,14 @@ fn short_time3() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Minute,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time4() {
     assert_eq!(
@@ -296,12 +702,14 @@ fn short_time4() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time5() {
     assert_eq!(
@@ -310,12 +718,14 @@ fn short_time5() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time6() {
     assert_eq!(
@@ -324,12 +734,14 @@ fn short_time6() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
+            offset: UtcOffset::Utc,
+            precision: Precision::Fraction,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_time7() {
     assert_eq!(
@@ -338,13 +750,15 @@ fn short_time7() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second,
         })
     );
 }
 
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_twtz1() {
     assert_eq!(
@@ -353,12 +767,14 @@ fn short_twtz1() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Minute,
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_twtz2() {
     assert_eq!(
@@ -367,13 +783,15 @@ fn short_twtz2() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Minute,
         })
     );
 }
 
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_dtim1() {
     assert_eq!(
@@ -388,13 +806,15 @@ fn short_dtim1() {
                 hour: 16,
                 minute: 48,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Minute,
             }
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_dtim2() {
     assert_eq!(
@@ -409,13 +829,15 @@ fn short_dtim2() {
                 hour: 16,
                 minute: 48,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Utc,
+                precision: Precision::Minute,
             },
         })
     );
 }
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn short_dtim3() {
     assert_eq!(
@@ -430,14 +852,16 @@ fn short_dtim3() {
                 hour: 18,
                 minute: 21,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Utc,
+                precision: Precision::Minute,
             },
         })
     );
 }
 
+#[cfg(feature = "legacy-fields")]
 #[test]
 fn test_time_with_timezone() {
     assert_eq!(
@@ -445,9 +869,10 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second,
         }),
         time("16:43:16")
     );
@@ -456,9 +881,10 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Second,
         }),
         time("16:43:16Z")
     );
@@ -467,9 +893,10 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 0 },
+            precision: Precision::Second,
         }),
         time("16:43:16+00:00")
     );
@@ -478,9 +905,10 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::UnknownLocal,
+            precision: Precision::Second,
         }),
         time("16:43:16-00:00")
     );
@@ -489,9 +917,10 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 5,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 18000 },
+            precision: Precision::Second,
         }),
         time("16:43:16+05:00")
     );
@@ -583,623 +1012,4837 @@ fn test_ordinal_date() {
 }
 
 #[test]
-fn format_equivalence() {
+fn test_basic_date() {
+    assert_eq!(date("2023-02-08"), date("20230208"));
+    assert_eq!(date("2023-039"), date("2023039"));
+    assert_eq!(date("2023-W06-2"), date("2023W062"));
+
     assert_eq!(
-        datetime("2001-02-03T04:05:06+07:00"),
-        datetime("20010203T040506+0700")
+        Ok(Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 8,
+        }),
+        date("20230208")
     );
     assert_eq!(
-        datetime("2001-02-03T04:05:06+07:00"),
-        datetime("20010203T04:05:06+0700")
+        Ok(Date::Ordinal {
+            year: 2023,
+            ddd: 39,
+        }),
+        date("2023039")
     );
     assert_eq!(
-        datetime("2001-02-03T04:05:00+07:00"),
-        datetime("20010203T0405+0700")
+        Ok(Date::Week {
+            year: 2023,
+            ww: 6,
+            d: 2,
+        }),
+        date("2023W062")
     );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_comma_decimal_separator() {
+    assert_eq!(time("23:40:00.123"), time("23:40:00,123"));
+    assert_eq!(time("234000.5Z"), time("234000,5Z"));
     assert_eq!(
-        datetime("20010203T0405+0700"),
-        datetime("2001-02-03T0405+0700")
+        datetime("2023-02-08T23:40:00.123+01:00"),
+        datetime("2023-02-08T23:40:00,123+01:00")
     );
+    assert_eq!(duration("PT1.5S"), duration("PT1,5S"));
     assert_eq!(
-        datetime("20010203T040506+0700"),
-        datetime("2001-02-03T040506+0700")
+        duration("P0001-02-03T04:05:06.7"),
+        duration("P0001-02-03T04:05:06,7")
     );
+
     assert_eq!(
-        datetime("20010203T040506+0000"),
-        datetime("20010203T040506Z")
+        Ok(Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
+        }),
+        time("23:40:00,123")
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_fractional_hour_and_minute() {
+    assert_eq!(
+        Ok(Time {
+            hour: 12,
+            minute: 30,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Hour
+        }),
+        time("T12,5")
     );
     assert_eq!(
-        datetime("2015W056T04:05:06+07:00"),
-        datetime("2015-W05-6T04:05:06+07:00")
+        Ok(Time {
+            hour: 12,
+            minute: 30,
+            second: 15,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Minute
+        }),
+        time("12:30,25")
+    );
+    assert_eq!(
+        Ok(Time {
+            hour: 12,
+            minute: 20,
+            second: 24,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 3600 },
+            precision: Precision::Hour
+        }),
+        time("12.34+01:00")
     );
+    assert_eq!(time("12:30,25"), time("1230,25"));
+    assert_eq!(datetime("2023-02-08T12.5Z"), datetime("2023-02-08T12,5Z"));
 }
 
+#[cfg(feature = "legacy-fields")]
 #[test]
-fn test_datetime_correct() {
+fn test_basic_time() {
     assert_eq!(
-        datetime("20060831T16:44+00:00"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2006,
-                month: 8,
-                day: 31
-            },
-            time: Time {
-                hour: 16,
-                minute: 44,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        Ok(Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Second
+        }),
+        time("T234000Z")
+    );
+    assert_eq!(
+        Ok(Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 3600 },
+            precision: Precision::Second
+        }),
+        time("234000+0100")
     );
     assert_eq!(
-        datetime("2007-08-31T16:45+00:00"),
         Ok(DateTime {
             date: Date::YMD {
-                year: 2007,
-                month: 8,
-                day: 31
+                year: 2023,
+                month: 2,
+                day: 8,
             },
             time: Time {
-                hour: 16,
-                minute: 45,
+                hour: 23,
+                minute: 40,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 3600 },
+                precision: Precision::Second
             }
-        })
+        }),
+        datetime("20230208T234000+0100")
     );
+}
+
+#[test]
+fn test_time_designator() {
+    assert_eq!(time("23:40:00"), time("T23:40:00"));
+    assert_eq!(time("23:40:00.5+01:00"), time("T23:40:00.5+01:00"));
+    assert_eq!(time("23:40"), time("T23:40"));
+    assert_eq!("T23:40:00".parse::<Time>(), time("23:40:00"));
+    assert!(time("TT23:40:00").is_err());
+    assert!(time("t23:40:00").is_err());
+}
+
+#[test]
+fn test_validated_constructors() {
+    let date = Date::from_ymd(2023, 2, 8).unwrap();
+    assert_eq!(date, iso8601::date("2023-02-08").unwrap());
+    assert_eq!(date.year(), 2023);
+    assert_eq!(Date::from_ymd(2023, 2, 29), Err(DateError::Day));
+    assert_eq!(Date::from_ymd(2023, 0, 1), Err(DateError::Month));
+    assert_eq!(Date::from_iso_week(2023, 0, 1), Err(DateError::Week));
+    assert_eq!(Date::from_iso_week(2023, 6, 0), Err(DateError::Weekday));
+    assert_eq!(Date::from_ordinal(2023, 0), Err(DateError::Ordinal));
+    assert_eq!(
+        Date::from_iso_week(2023, 6, 3).unwrap().to_string(),
+        iso8601::date("2023-W06-3").unwrap().to_string()
+    );
+    assert_eq!(DateError::Day.to_string(), "day out of range for the month");
+
+    let time = Time::new(23, 40, 5, 123_000_000).unwrap();
+    assert_eq!(time, iso8601::time("23:40:05.123").unwrap());
+    assert_eq!(
+        (time.hour(), time.minute(), time.second(), time.nanosecond()),
+        (23, 40, 5, 123_000_000)
+    );
+    assert_eq!(time.fraction_digits(), 3);
+    assert_eq!(time.precision(), Precision::Fraction);
+    assert_eq!(time.offset(), UtcOffset::Unspecified);
+    assert_eq!(Time::new(23, 40, 0, 0).unwrap().to_string(), "23:40:00");
+    assert_eq!(
+        Time::new(23, 40, 0, 0).unwrap().precision(),
+        Precision::Second
+    );
+    assert!(Time::new(24, 0, 0, 0).unwrap().is_end_of_day());
+    assert!(Time::new(23, 59, 60, 0).unwrap().is_leap_second());
+    assert_eq!(Time::new(25, 0, 0, 0), Err(TimeError::Hour));
+    assert_eq!(Time::new(23, 59, 61, 0), Err(TimeError::Second));
+    assert_eq!(
+        Time::new(23, 59, 59, 1_000_000_000),
+        Err(TimeError::Nanosecond)
+    );
+
+    let dt = DateTime::new(date, time.set_offset(UtcOffset::Utc));
+    assert_eq!(dt, datetime("2023-02-08T23:40:05.123Z").unwrap());
+}
+
+#[test]
+fn test_negative_years() {
+    for string in [
+        "-0333-07-11",
+        "0000-02-29",
+        "-0001-12-31",
+        "-0333-W28-1",
+        "-0001-W52-7",
+        "-0333-192",
+    ] {
+        let date = date(string).unwrap();
+        assert!(date.is_valid(), "{}", string);
+        assert_eq!(date.to_string(), string);
+    }
+    assert_eq!(
+        datetime("-0333-07-11T12:00:00Z").unwrap().to_string(),
+        "-0333-07-11T12:00:00Z"
+    );
+    assert_eq!(reduced_date("-0333").unwrap().to_string(), "-0333");
+    assert_eq!(week("-0001-W52").unwrap().to_string(), "-0001-W52");
+
+    // year 0 and every 400th year before it are leap years, other centuries are not
+    assert!(Date::from_ymd(0, 2, 29).is_ok());
+    assert!(Date::from_ymd(-400, 2, 29).is_ok());
+    assert!(Date::from_ymd(-4, 2, 29).is_ok());
+    assert_eq!(Date::from_ymd(-100, 2, 29), Err(DateError::Day));
+    assert_eq!(Date::from_ymd(-1, 2, 29), Err(DateError::Day));
+    assert_eq!(
+        Date::from_ordinal(0, 366),
+        Ok(Date::Ordinal { year: 0, ddd: 366 })
+    );
+    assert_eq!(Date::from_ordinal(-1, 366), Err(DateError::Ordinal));
+
+    // 1 BC starts on a Saturday, so its week-numbering year starts on 0000-01-03
+    // and the last week of -0001 ends on 0000-01-02
+    assert_eq!(
+        Week { year: 0, ww: 1 }.first_day(),
+        Date::from_iso_week(0, 1, 1).unwrap()
+    );
+    assert!(Date::from_iso_week(-1, 52, 7).is_ok());
+    assert_eq!(Date::from_iso_week(-1, 53, 1), Err(DateError::Week));
+}
+
+#[test]
+fn test_large_years() {
+    let date = Date::from_ymd(12345, 6, 7).unwrap();
+    assert_eq!(date.to_string(), "+12345-06-07");
+    assert_eq!(date_expanded(&date.to_string(), 1), Ok(date));
+    assert_eq!(
+        Date::from_ymd(-12345, 6, 7).unwrap().to_string(),
+        "-12345-06-07"
+    );
+    assert_eq!(
+        Date::from_ymd(9999, 12, 31).unwrap().to_string(),
+        "9999-12-31"
+    );
+    assert_eq!(
+        Date::from_iso_week(100_000, 1, 1).unwrap().to_string(),
+        "+100000-W01-1"
+    );
+    assert_eq!(
+        Date::from_ordinal(100_000, 1).unwrap().to_string(),
+        "+100000-001"
+    );
+    assert_eq!(
+        ReducedDate::YearMonth {
+            year: 10000,
+            month: 1
+        }
+        .to_string(),
+        "+10000-01"
+    );
+    assert_eq!(Week { year: 10000, ww: 1 }.to_string(), "+10000-W01");
+
+    let dt = DateTime::new(date, Time::new(12, 0, 0, 0).unwrap());
+    assert_eq!(dt.to_string(), "+12345-06-07T12:00:00");
+}
+
+#[test]
+fn test_canonical_date() {
+    let ymd = date("2023-02-08").unwrap();
+    for string in ["2023-W06-3", "2023W063", "2023-039", "2023039"] {
+        let other = date(string).unwrap();
+        assert_ne!(other, ymd);
+        assert_eq!(other.canonical(), Ok(ymd), "{}", string);
+        assert!(other.same_day(&ymd), "{}", string);
+    }
+    assert_eq!(ymd.canonical(), Ok(ymd));
+
+    // week-numbering years can start in the previous and end in the next calendar year
+    assert_eq!(
+        date("2020-W53-7").unwrap().canonical(),
+        Ok(date("2021-01-03").unwrap())
+    );
+    assert_eq!(
+        date("2025-W01-1").unwrap().canonical(),
+        Ok(date("2024-12-30").unwrap())
+    );
+    assert_eq!(
+        date("-0333-W28-1").unwrap().canonical(),
+        Ok(date("-0333-07-11").unwrap())
+    );
+
+    let invalid = date("2023-02-30").unwrap();
+    assert_eq!(invalid.canonical(), Err(DateError::Day));
+    assert!(!invalid.same_day(&invalid));
+    assert!(!date("2023-W06-2").unwrap().same_day(&ymd));
+}
+
+#[test]
+fn test_ordering() {
+    use std::cmp::Ordering;
+    use std::collections::BTreeSet;
+
+    // ordering agrees with equality
+    fn check<T: Ord + core::fmt::Debug>(values: &[T]) {
+        for a in values {
+            for b in values {
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b, "{:?} {:?}", a, b);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    let dates = [
+        "2023-02-08",
+        "2023-W06-3",
+        "2023-039",
+        "2023-02-30",
+        "2023-W06-2",
+        "-0001-12-31",
+    ]
+    .map(|s| date(s).unwrap());
+    check(&dates);
+    assert_eq!(BTreeSet::from(dates).len(), 6);
+    assert!(date("-0001-12-31").unwrap() < date("0000-01-01").unwrap());
+    assert!(date("2022-W52-7").unwrap() < date("2023-01-02").unwrap());
+
+    let times = [
+        "23:40:00Z",
+        "23:40:00+01:00",
+        "23:40:00",
+        "23:40:00.0",
+        "23:40",
+        "23:59:60",
+    ]
+    .map(|s| time(s).unwrap());
+    check(&times);
+
+    let datetimes = [
+        "2023-02-08T23:40:00Z",
+        "2023-02-09T00:40:00+01:00",
+        "2023-02-08T23:40:00",
+        "2023-02-08T23:40:00-00:00",
+        "2023-02-08T24:00:00Z",
+        "2023-02-09T00:00:00Z",
+        "2016-12-31T23:59:60Z",
+        "2017-01-01T00:00:00Z",
+    ]
+    .map(|s| datetime(s).unwrap());
+    check(&datetimes);
+
+    let mut sorted = [
+        "2023-02-09T00:30:00+01:00",
+        "2023-02-08T23:40:00Z",
+        "2016-12-31T23:59:60Z",
+        "2023-02-08T18:00:00-05:00",
+        "2016-12-31T23:59:59.999Z",
+    ]
+    .map(|s| datetime(s).unwrap());
+    sorted.sort();
+    assert_eq!(
+        sorted.map(|dt| dt.to_string()),
+        [
+            "2016-12-31T23:59:59.999Z",
+            "2016-12-31T23:59:60Z",
+            "2023-02-08T18:00:00-05:00",
+            "2023-02-09T00:30:00+01:00",
+            "2023-02-08T23:40:00Z",
+        ]
+    );
+    // the same instant in other offsets is not equal, but next to each other
+    let utc = datetime("2023-02-08T23:40:00Z").unwrap();
+    let cet = datetime("2023-02-09T00:40:00+01:00").unwrap();
+    assert_ne!(utc.cmp(&cet), Ordering::Equal);
+    assert!(datetime("2023-02-08T23:39:59Z").unwrap() < utc.min(cet));
+    assert!(datetime("2023-02-08T23:40:01Z").unwrap() > utc.max(cet));
+
+    let duration = |s| iso8601::duration(s).unwrap();
+    assert!(duration("PT59M") < duration("PT1H"));
+    assert!(duration("P2W") > duration("P13D"));
+    assert!(duration("-P1D") < duration("PT0S"));
+    assert!(duration("P1Y1D") < duration("P1YT25H"));
+    assert_eq!(duration("P1Y").partial_cmp(&duration("P365D")), None);
+    assert_eq!(duration("P1W").partial_cmp(&duration("P7D")), None);
+    // years and months are ordered apart from the rest
+    assert!(duration("P1Y") < duration("P2Y"));
+    assert!(duration("P1M") < duration("P2M"));
+    assert!(duration("P1M") < duration("P1M1D"));
+    assert!(duration("P11M") < duration("P1Y"));
+    assert!(duration("P1Y1D") < duration("P2Y2D"));
+    assert!(duration("-P1M") < duration("PT0S"));
+    assert_eq!(duration("P1Y").partial_cmp(&duration("P12M")), None);
+    assert_eq!(duration("P1M").partial_cmp(&duration("P29D")), None);
+    assert_eq!(duration("P2M").partial_cmp(&duration("P1M40D")), None);
+    assert_eq!(duration("P1Y-1D").partial_cmp(&duration("P0D")), None);
+    assert_eq!(
+        duration("PT1H").partial_cmp(&duration("PT1H")),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::{HashMap, HashSet};
+
+    let events = [
+        "2023-02-08T23:40:00Z",
+        "2023-02-08T23:40:00Z",
+        "2023-02-08T23:40:00+01:00",
+        "2023-02-08T23:40:00",
+        "2023-02-08T23:40:00.000Z",
+        "2023-02-08T23:40Z",
+    ];
+    let unique: HashSet<DateTime> = events.iter().map(|s| datetime(s).unwrap()).collect();
+    assert_eq!(unique.len(), 5);
+
+    let mut counts = HashMap::new();
+    for date in ["2023-02-08", "2023-W06-3", "2023-039", "2023-02-09"] {
+        let day = iso8601::date(date).unwrap().canonical().unwrap();
+        *counts.entry(day).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&iso8601::date("2023-02-08").unwrap()], 3);
+    assert_eq!(counts.len(), 2);
+
+    let durations: HashSet<Duration> = ["P1D", "P1D", "PT24H", "P1W"]
+        .iter()
+        .map(|s| duration(s).unwrap())
+        .collect();
+    assert_eq!(durations.len(), 3);
+    let times: HashSet<Time> = ["23:40:00", "23:40:00", "23:40:00Z"]
+        .iter()
+        .map(|s| time(s).unwrap())
+        .collect();
+    assert_eq!(times.len(), 2);
+}
+
+#[test]
+fn test_same_instant() {
+    use std::cmp::Ordering;
+
+    let dt = |s| datetime(s).unwrap();
+    let instant = dt("2023-02-08T22:40:00Z");
+    for other in [
+        "2023-02-08T23:40:00+01:00",
+        "2023-02-08T17:40:00-05:00",
+        "2023-02-09T04:10:00+05:30",
+        "2023-02-08T22:40:00-00:00",
+        "2023-02-08T22:40:00",
+        "2023-W06-3T22:40:00Z",
+        "2023-039T22:40:00.000Z",
+        "20230208T224000Z",
+    ] {
+        assert!(instant.same_instant(&dt(other)), "{}", other);
+        assert_eq!(
+            dt(other).cmp_instant(&instant),
+            Ordering::Equal,
+            "{}",
+            other
+        );
+    }
+    assert!(!instant.same_instant(&dt("2023-02-08T22:40:00.000000001Z")));
+    assert_eq!(
+        dt("2023-02-08T23:59:00-01:00").cmp_instant(&dt("2023-02-09T00:30:00Z")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        dt("2023-02-09T00:30:00+01:00").cmp_instant(&dt("2023-02-08T23:40:00Z")),
+        Ordering::Less
+    );
+
+    // across the turn of a year and in the other direction
+    assert!(dt("2022-12-31T23:30:00-01:00").same_instant(&dt("2023-01-01T00:30:00Z")));
+    assert!(dt("2023-01-01T00:30:00+01:00").same_instant(&dt("2022-12-31T23:30:00Z")));
+}
+
+#[test]
+fn test_date_range() {
+    assert_eq!(date_expanded("-2147483648-01-01", 6), Ok(Date::MIN));
+    assert_eq!(date_expanded("+2147483647-12-31", 6), Ok(Date::MAX));
+    assert!(Date::MIN.is_valid());
+    assert!(Date::MAX.is_valid());
+    assert_eq!(Date::MIN.to_string(), "-2147483648-01-01");
+    assert_eq!(
+        DateTime::MAX.to_string(),
+        "+2147483647-12-31T23:59:59.999999999"
+    );
+    assert!(DateTime::MIN.is_valid());
+
+    // the first week-numbering year starts before the first day
+    assert_eq!(
+        Date::from_iso_week(i32::MIN, 1, 1),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::Week {
+            year: i32::MIN,
+            ww: 1,
+            d: 1
+        }
+        .validate(),
+        Err(DateError::OutOfRange)
+    );
+    assert!(Date::from_iso_week(i32::MIN, 2, 1).is_ok());
+    let last_week = Date::from_iso_week(i32::MAX, 52, 7).unwrap();
+
+    // there is no day after the last one
+    let end = DateTime::new(Date::MAX, Time::new(24, 0, 0, 0).unwrap());
+    assert_eq!(end.normalize_midnight(), end);
+    let end = DateTime::new(last_week, Time::new(24, 0, 0, 0).unwrap());
+    assert_eq!(end.normalize_midnight(), end);
+    let leap = DateTime::new(Date::MAX, Time::new(23, 59, 60, 0).unwrap());
+    assert_eq!(leap.resolve_leap_second(LeapSecondPolicy::Carry), None);
+    assert!(leap.resolve_leap_second(LeapSecondPolicy::Clamp).is_some());
+    let before = DateTime::new(
+        Date::from_ymd(i32::MAX, 12, 30).unwrap(),
+        Time::new(24, 0, 0, 0).unwrap(),
+    );
+    assert_eq!(before.normalize_midnight().date(), Date::MAX);
+}
+
+#[test]
+fn test_valid_wrappers() {
+    let date: ValidDate = "2024-W01-1".parse().unwrap();
+    assert_eq!(Date::from(date), iso8601::date("2024-W01-1").unwrap());
+    assert_eq!(
+        ValidDate::try_from(iso8601::date("2023-366").unwrap()),
+        Err(DateError::Ordinal)
+    );
+    assert_eq!(
+        "2023-02-29".parse::<ValidDate>().map_err(|e| e.expected),
+        Err(Expected::ExistingDate)
+    );
+    assert!("2023-02".parse::<ValidDate>().is_err());
+
+    let dt = datetime("2023-02-08T23:40:00Z").unwrap();
+    let valid = dt.validate().unwrap();
+    assert_eq!(*valid, dt);
+    assert_eq!(DateTime::from(valid), dt);
+    assert_eq!(valid.date(), dt.date().validate().unwrap());
+    assert_eq!(valid.offset(), UtcOffset::Utc);
+
+    // the date is checked, even if it was built by hand
+    let dt = DateTime::new(
+        Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 30,
+        },
+        Time::new(23, 40, 0, 0).unwrap(),
+    );
+    assert_eq!(ValidDateTime::try_from(dt), Err(DateError::Day));
+    assert!("2023-W53-1T00:00".parse::<ValidDateTime>().is_err());
+}
+
+#[test]
+fn test_precision() {
+    let precision = |s| datetime(s).unwrap().precision();
+    assert_eq!(precision("2023-02-08T23"), Precision::Hour);
+    assert_eq!(precision("2023-02-08T23:40Z"), Precision::Minute);
+    assert_eq!(precision("2023-02-08T23:40:00+01:00"), Precision::Second);
+    assert_eq!(precision("2023-02-08T23:40:00.0"), Precision::Fraction);
+    assert_eq!(
+        datetime_rfc3339("2023-02-08T23:40:00.5Z")
+            .unwrap()
+            .precision(),
+        Precision::Fraction
+    );
+
+    let dt = datetime("2023-02-08T23:40").unwrap();
+    assert!(dt.time().has_precision(Precision::Minute));
+    assert!(!dt.time().has_precision(Precision::Second));
+
+    assert_eq!(date("2023-W06-3").unwrap().precision(), Precision::Day);
+    assert_eq!(reduced_date("2023").unwrap().precision(), Precision::Year);
+    assert_eq!(
+        reduced_date("2023-02").unwrap().precision(),
+        Precision::Month
+    );
+    assert!(Precision::Year < Precision::Day && Precision::Second < Precision::Fraction);
+
+    // times are written up to their precision, so they read back the same
+    for s in [
+        "2023-02-08T23",
+        "2023-02-08T23Z",
+        "2023-02-08T23:40",
+        "2023-02-08T23:40+01:00",
+        "2023-02-08T23:40:00",
+        "2023-02-08T23:40:00.500Z",
+    ] {
+        assert_eq!(datetime(s).unwrap().to_string(), s);
+    }
+    for s in ["T23", "T23-05:00", "23:40", "23:40Z"] {
+        let t = time(s).unwrap();
+        assert_eq!(t.to_string(), s);
+        assert_eq!(time(&t.to_string()), Ok(t));
+    }
+    // but without dropping components that are not zero
+    assert_eq!(time("T23,5").unwrap().to_string(), "23:30");
+    assert_eq!(
+        datetime("2023-02-08T23,5").unwrap().to_string(),
+        "2023-02-08T23:30"
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_reduced_precision_time() {
+    assert_eq!(
+        Ok(Time {
+            hour: 23,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Hour,
+        }),
+        time("T23")
+    );
+    assert_eq!(
+        Ok(Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Fixed { seconds: 3600 },
+            precision: Precision::Minute,
+        }),
+        time("23:40+01:00")
+    );
+    assert_eq!(
+        datetime("2023-02-08T23Z").map(|dt| dt.time.precision()),
+        Ok(Precision::Hour)
+    );
+    assert_eq!(
+        datetime_lenient("2023-02-08 23:40").map(|dt| dt.time.precision()),
+        Ok(Precision::Minute)
+    );
+    assert!(time("23").is_err());
+}
+
+#[test]
+fn format_equivalence() {
+    assert_eq!(
+        datetime("2001-02-03T04:05:06+07:00"),
+        datetime("20010203T040506+0700")
+    );
+    assert_eq!(
+        datetime("2001-02-03T04:05:06+07:00"),
+        datetime("20010203T04:05:06+0700")
+    );
+    assert_eq!(
+        datetime("2001-02-03T04:05+07:00"),
+        datetime("20010203T0405+0700")
+    );
+    assert_eq!(
+        datetime("20010203T0405+0700"),
+        datetime("2001-02-03T0405+0700")
+    );
+    assert_eq!(
+        datetime("20010203T040506+0700"),
+        datetime("2001-02-03T040506+0700")
+    );
+    assert_eq!(
+        datetime("20010203T040506+0000"),
+        datetime("2001-02-03T04:05:06+00:00")
+    );
+    assert_ne!(
+        datetime("20010203T040506+0000"),
+        datetime("20010203T040506Z")
+    );
+    assert_eq!(
+        datetime("2015W056T04:05:06+07:00"),
+        datetime("2015-W05-6T04:05:06+07:00")
+    );
+}
+
+#[test]
+fn test_datetime_lenient() {
+    let expected = datetime("2023-02-08T23:40:00Z").unwrap();
+
+    assert_eq!(Ok(expected), datetime_lenient("2023-02-08 23:40:00z"));
+    assert_eq!(Ok(expected), datetime_lenient("2023-02-08t23:40:00Z"));
+    assert_eq!(Ok(expected), datetime_lenient("2023-02-08T23:40:00Z"));
+    assert_eq!(
+        datetime("2023-02-08T23:40:00+01:00"),
+        datetime_lenient("2023-02-08 23:40:00+01:00")
+    );
+    assert!(datetime("2023-02-08 23:40:00z").is_err());
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_datetime_rfc3339() {
+    assert_eq!(
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2023,
+                month: 2,
+                day: 8,
+            },
+            time: Time {
+                hour: 23,
+                minute: 40,
+                second: 0,
+                nanosecond: 500_000_000,
+                fraction_digits: 1,
+                offset: UtcOffset::Fixed { seconds: -18000 },
+                precision: Precision::Fraction,
+            },
+        }),
+        datetime_rfc3339("2023-02-08T23:40:00.5-05:00")
+    );
+    assert_eq!(
+        datetime_rfc3339("2023-02-08 23:40:00Z"),
+        datetime("2023-02-08T23:40:00Z")
+    );
+    assert!(datetime("2023-02-08T23:40").is_ok());
+    assert!(datetime_rfc3339("2023-02-08T23:40").is_err());
+    assert!(datetime_rfc3339("2023-02-08T23:40:00Z trailing").is_err());
+    assert!(datetime_rfc3339("2023-02-08").is_err());
+
+    // the day has to exist in its month
+    let error = datetime_rfc3339("2023-02-30T23:40:00Z").unwrap_err();
+    assert_eq!(
+        (error.kind, error.expected, error.offset),
+        (ErrorKind::NonexistentDate, Expected::ExistingDate, 8)
+    );
+    assert!(datetime_rfc3339("2023-02-29T23:40:00Z").is_err());
+    assert!(datetime_rfc3339("2024-02-29T23:40:00Z").is_ok());
+    assert!(datetime_rfc3339("2023-04-31T23:40:00Z").is_err());
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_datetime_html() {
+    assert_eq!(
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2023,
+                month: 2,
+                day: 8,
+            },
+            time: Time {
+                hour: 23,
+                minute: 40,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Minute,
+            },
+        }),
+        datetime_html("2023-02-08T23:40")
+    );
+    assert_eq!(
+        datetime_html("2023-02-08 23:40:00.5+01:00"),
+        datetime("2023-02-08T23:40:00.5+01:00")
+    );
+    assert!(datetime_html("2023-W06-3T23:40").is_err());
+    let error = datetime_html("2023-02-30T23:40").unwrap_err();
+    assert_eq!((error.kind, error.offset), (ErrorKind::NonexistentDate, 8));
+    assert!(datetime_html("2023-02-08T23:40:00.1234").is_err());
+    assert!(datetime_html("2023-02-08T23:40 trailing").is_err());
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_datetime_python() {
+    assert_eq!(
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2023,
+                month: 2,
+                day: 8,
+            },
+            time: Time {
+                hour: 23,
+                minute: 40,
+                second: 0,
+                nanosecond: 123_456_000,
+                fraction_digits: 6,
+                offset: UtcOffset::Fixed { seconds: 3600 },
+                precision: Precision::Fraction,
+            },
+        }),
+        datetime_python("2023-02-08T23:40:00.123456+01:00")
+    );
+    assert_eq!(
+        datetime_python("2023-02-08 23:40"),
+        datetime("2023-02-08T23:40")
+    );
+    assert_eq!(
+        datetime_python("2023-02-08T23:40:00-00:19:32"),
+        datetime("2023-02-08T23:40:00-00:19:32")
+    );
+    assert_eq!(datetime_python("2023-02-08"), datetime("2023-02-08T00"));
+    let error = datetime_python("2023-02-30").unwrap_err();
+    assert_eq!((error.kind, error.offset), (ErrorKind::NonexistentDate, 8));
+    let error = datetime_python("2023-02-08 trailing").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::TrailingInput);
+    // `isoformat()` of a datetime with an offset of `timedelta(seconds=1172.5)`,
+    // which a `UtcOffset` cannot hold
+    let error = datetime_python("2015-11-03T21:56:00+00:19:32.500000").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::TrailingInput);
+    assert!(datetime_python("2023-W06-3T23:40").is_err());
+    assert!(datetime_python("2023-02-30T23:40").is_err());
+    assert!(datetime_python("2023-02-08T23:40:00.12").is_err());
+    assert!(datetime_python("2023-02-08T23:40 trailing").is_err());
+}
+
+#[test]
+fn test_datetime_ixdtf() {
+    let dt = datetime_ixdtf("2023-02-08T23:40:00+01:00[Europe/Berlin][!u-ca=gregory]").unwrap();
+    assert_eq!(Ok(dt.datetime), datetime("2023-02-08T23:40:00+01:00"));
+    assert_eq!(
+        Some(TimeZoneAnnotation {
+            name: "Europe/Berlin".into(),
+            critical: false,
+        }),
+        dt.time_zone
+    );
+    assert_eq!(
+        vec![Annotation {
+            key: "u-ca".into(),
+            value: "gregory".into(),
+            critical: true,
+        }],
+        dt.annotations
+    );
+    assert_eq!(
+        "2023-02-08T23:40:00+01:00[Europe/Berlin][!u-ca=gregory]",
+        dt.to_string()
+    );
+
+    let dt = datetime_ixdtf("2023-02-08T23:40:00Z").unwrap();
+    assert_eq!(None, dt.time_zone);
+    assert!(dt.annotations.is_empty());
+
+    assert!(datetime_ixdtf("2023-02-08T23:40:00Z[Europe/Berlin").is_err());
+    assert!(datetime_ixdtf("2023-02-08T23:40:00Z[U-CA=gregory]").is_err());
+    assert!(datetime_ixdtf("2023-02-08T23:40:00Z[u-ca=gregory][Europe/Berlin]").is_err());
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_datetime_correct() {
+    assert_eq!(
+        datetime("20060831T16:44+00:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2006,
+                month: 8,
+                day: 31
+            },
+            time: Time {
+                hour: 16,
+                minute: 44,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 0 },
+                precision: Precision::Minute
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2007-08-31T16:45+00:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2007,
+                month: 8,
+                day: 31
+            },
+            time: Time {
+                hour: 16,
+                minute: 45,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 0 },
+                precision: Precision::Minute
+            }
+        })
+    );
+    assert_eq!(
+        datetime("20070831T1646+00:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2007,
+                month: 8,
+                day: 31
+            },
+            time: Time {
+                hour: 16,
+                minute: 46,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 0 },
+                precision: Precision::Minute
+            }
+        })
+    );
+    assert_eq!(
+        datetime("20070831T1647+0000"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2007,
+                month: 8,
+                day: 31
+            },
+            time: Time {
+                hour: 16,
+                minute: 47,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 0 },
+                precision: Precision::Minute
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2009-02-01T09:00:22+05"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2009,
+                month: 2,
+                day: 1
+            },
+            time: Time {
+                hour: 9,
+                minute: 0,
+                second: 22,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 18000 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2010-01-01T12:00:00+01:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2010,
+                month: 1,
+                day: 1
+            },
+            time: Time {
+                hour: 12,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 3600 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2011-06-30T18:30:00+02:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2011,
+                month: 6,
+                day: 30
+            },
+            time: Time {
+                hour: 18,
+                minute: 30,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 7200 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-06-29T23:07+02:00"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 29
+            },
+            time: Time {
+                hour: 23,
+                minute: 7,
+                second: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 7200 },
+                precision: Precision::Minute
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-06-26T16:43:16"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 26
+            },
+            time: Time {
+                hour: 16,
+                minute: 43,
+                second: 16,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-06-26T16:43:16"),
+        Ok(DateTime {
+            date: Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 26
+            },
+            time: Time {
+                hour: 16,
+                minute: 43,
+                second: 16,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Unspecified,
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-W05-6T04:05:06+07:00"),
+        Ok(DateTime {
+            date: Date::Week {
+                year: 2015,
+                ww: 5,
+                d: 6
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 25200 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015W056T04:05:06+07:00"),
+        Ok(DateTime {
+            date: Date::Week {
+                year: 2015,
+                ww: 5,
+                d: 6
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 25200 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-056T04:05:06+07:00"),
+        Ok(DateTime {
+            date: Date::Ordinal {
+                year: 2015,
+                ddd: 56
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 25200 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015056T04:05:06+07:00"),
+        Ok(DateTime {
+            date: Date::Ordinal {
+                year: 2015,
+                ddd: 56
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Fixed { seconds: 25200 },
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-297T16:30:48Z"),
+        Ok(DateTime {
+            date: Date::Ordinal {
+                year: 2015,
+                ddd: 297
+            },
+            time: Time {
+                hour: 16,
+                minute: 30,
+                second: 48,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Utc,
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2015-W43-6T16:30:48Z"),
+        Ok(DateTime {
+            date: Date::Week {
+                year: 2015,
+                ww: 43,
+                d: 6
+            },
+            time: Time {
+                hour: 16,
+                minute: 30,
+                second: 48,
+                nanosecond: 0,
+                fraction_digits: 0,
+                offset: UtcOffset::Utc,
+                precision: Precision::Second
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2001-W05-6T04:05:06.1234Z"),
+        Ok(DateTime {
+            date: Date::Week {
+                year: 2001,
+                ww: 5,
+                d: 6
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 123_400_000,
+                fraction_digits: 4,
+                offset: UtcOffset::Utc,
+                precision: Precision::Fraction
+            }
+        })
+    );
+    assert_eq!(
+        datetime("2001-W05-6T04:05:06.12345Z"),
+        Ok(DateTime {
+            date: Date::Week {
+                year: 2001,
+                ww: 5,
+                d: 6
+            },
+            time: Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 123_450_000,
+                fraction_digits: 5,
+                offset: UtcOffset::Utc,
+                precision: Precision::Fraction
+            }
+        })
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn issue12_regression_1() {
+    let input = "164801.";
+
+    assert_eq!(
+        Ok(Time {
+            hour: 16,
+            minute: 48,
+            second: 1,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Second
+        }),
+        time(input)
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn issue12_regression_2() {
+    let input = "04:05:06.1226001015632)*450";
+
+    assert_eq!(
+        Ok(Time {
+            hour: 4,
+            minute: 5,
+            second: 6,
+            nanosecond: 122_600_101,
+            fraction_digits: 9,
+            offset: UtcOffset::Unspecified,
+            precision: Precision::Fraction
+        }),
+        time(input)
+    );
+}
+
+#[test]
+fn test_duration_ymdhms() {
+    use core::time::Duration as StdDuration;
+
+    // full YMDHMS
+    let dur = duration("P1Y2M3DT4H5M6S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 2,
+            day: 3,
+            hour: 4,
+            minute: 5,
+            second: 6,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 0)
+    );
+
+    // full YMDHMS with milliseconds dot delimiter
+    let dur = duration("P1Y2M3DT4H5M6.7S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 2,
+            day: 3,
+            hour: 4,
+            minute: 5,
+            second: 6,
+            millisecond: 700,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 700000000)
+    );
+
+    // full YMDHMS with milliseconds comma delimiter
+    let dur = duration("P1Y2M3DT4H5M6,7S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 2,
+            day: 3,
+            hour: 4,
+            minute: 5,
+            second: 6,
+            millisecond: 700,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 700000000)
+    );
+
+    // subset YM-HM-
+    let dur = duration("P1Y2MT4H5M").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 2,
+            day: 0,
+            hour: 4,
+            minute: 5,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36734700, 0)
+    );
+
+    // subset Y-----
+    let dur = duration("P1Y").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(31536000, 0)
+    );
+
+    // subset ---H--
+    let dur = duration("PT4H").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 4,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(14400, 0)
+    );
+
+    // subset -----S with milliseconds dot delimiter
+    let dur = duration("PT6.7S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 6,
+            millisecond: 700,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(6, 700000000)
+    );
+
+    // subset -----S with milliseconds comma delimiter
+    let dur = duration("PT6,700S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 6,
+            millisecond: 700,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(6, 700000000)
+    );
+
+    // empty duration, using Y
+    let dur = duration("P0Y").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
+
+    // empty duration, using S
+    let dur = duration("PT0S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
+
+    let dur = duration("PT42M30S").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 42,
+            second: 30,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(2550, 0)
+    );
+
+    let dur = duration("P0001-02-03T04:05:06").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 1,
+            month: 2,
+            day: 3,
+            hour: 4,
+            minute: 5,
+            second: 6,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 0)
+    );
+
+    let dur = duration("P2018-04-27T00:00:00").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 2018,
+            month: 4,
+            day: 27,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(63652348800, 0)
+    );
+
+    let dur = duration("P0003-05-00T00:00:00").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 3,
+            month: 5,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert_eq!(dur, duration("P00030500T000000").unwrap());
+    assert_eq!(duration("P0000-00-02T00:00:00"), duration("P2D"));
+    assert!(duration("P0003-13-00T00:00:00").is_err());
+}
+
+#[test]
+fn test_duration_weeks() {
+    use core::time::Duration as StdDuration;
+
+    let dur = duration("P0W").unwrap();
+    assert_eq!(Duration::Weeks(0), dur);
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
+    let dur = duration("P26W").unwrap();
+    assert_eq!(Duration::Weeks(26), dur);
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(15724800, 0)
+    );
+    let dur = duration("P52W").unwrap();
+    assert_eq!(Duration::Weeks(52), dur);
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(31449600, 0)
+    );
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_interval() {
+    let start = DateTime {
+        date: Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 8,
+        },
+        time: Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Second,
+        },
+    };
+    let end = Date::YMD {
+        year: 2023,
+        month: 2,
+        day: 10,
+    };
+    let duration = Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day: 1,
+        hour: 8,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        fraction: None,
+    };
+
+    assert_eq!(
+        Ok(Interval::StartEnd {
+            start: Bound::DateTime(start),
+            end: Bound::Date(end),
+        }),
+        interval("2023-02-08T23:40:00Z/2023-02-10")
+    );
+    assert_eq!(
+        Ok(Interval::StartDuration {
+            start: Bound::DateTime(start),
+            duration,
+        }),
+        interval("2023-02-08T23:40:00Z/P1DT8H")
+    );
+    assert_eq!(
+        Ok(Interval::DurationEnd {
+            duration,
+            end: Bound::Date(end),
+        }),
+        interval("P1DT8H/2023-02-10")
+    );
+    assert_eq!(Ok(Interval::Duration(duration)), interval("P1DT8H"));
+
+    assert!(matches!(
+        interval("2023-02-08T23:40:00Z/"),
+        Ok(Interval::StartEnd {
+            end: Bound::Unknown,
+            ..
+        })
+    ));
+    assert!(interval("/P1DT8H").is_err());
+
+    // the whole string has to be an interval
+    let trailing = |s: &str| interval(s).map_err(|e| (e.expected, e.offset));
+    assert_eq!(trailing("P1D/P2D"), Err((Expected::End, 3)));
+    assert_eq!(
+        trailing("2023-02-08T23:40:00Z/2023-02-09T10:00:00Zjunk"),
+        Err((Expected::End, 41))
+    );
+    assert_eq!(interval_prefix("P1D/P2D").map(|(_, rest)| rest), Ok("/P2D"));
+    assert!(repeating_interval("R5/2023-02-08/P1D junk").is_err());
+    assert!(repeating_interval_bytes(b"R5/2023-02-08/P1D/").is_err());
+}
+
+#[test]
+fn test_interval_open_and_unknown() {
+    let date = Bound::Date(Date::YMD {
+        year: 2023,
+        month: 2,
+        day: 8,
+    });
+
+    assert_eq!(
+        Ok(Interval::StartEnd {
+            start: date,
+            end: Bound::Open,
+        }),
+        interval("2023-02-08/..")
+    );
+    assert_eq!(
+        Ok(Interval::StartEnd {
+            start: Bound::Open,
+            end: date,
+        }),
+        interval("../2023-02-08")
+    );
+    assert_eq!(
+        Ok(Interval::StartEnd {
+            start: Bound::Unknown,
+            end: date,
+        }),
+        interval("/2023-02-08")
+    );
+    assert_eq!(
+        Ok(Interval::StartEnd {
+            start: date,
+            end: Bound::Unknown,
+        }),
+        interval("2023-02-08/")
+    );
+    assert!(matches!(
+        interval("2023-02-08/P1D"),
+        Ok(Interval::StartDuration { .. })
+    ));
+    assert!(!Bound::Open.is_known());
+    assert!(date.is_known());
+    assert!(interval("../..").is_err());
+    assert!(interval("../P1D").is_err());
+}
+
+#[cfg(feature = "legacy-fields")]
+#[test]
+fn test_repeating_interval() {
+    let start = Bound::DateTime(DateTime {
+        date: Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 8,
+        },
+        time: Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
+            offset: UtcOffset::Utc,
+            precision: Precision::Second,
+        },
+    });
+    let duration = Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        fraction: None,
+    };
+
+    assert_eq!(
+        Ok(RepeatingInterval {
+            count: Some(5),
+            interval: Interval::StartDuration { start, duration },
+        }),
+        repeating_interval("R5/2023-02-08T00:00:00Z/P1D")
+    );
+    assert_eq!(
+        Ok(RepeatingInterval {
+            count: None,
+            interval: Interval::StartDuration { start, duration },
+        }),
+        repeating_interval("R/2023-02-08T00:00:00Z/P1D")
+    );
+
+    assert!(repeating_interval("2023-02-08T00:00:00Z/P1D").is_err());
+}
+
+#[test]
+fn test_duration_signed() {
+    use core::time::Duration as StdDuration;
+
+    let dur = duration("-P1DT2H").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: -1,
+            hour: -2,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert!(dur.is_negative());
+    assert_eq!(-dur, duration("P1DT2H").unwrap());
+    assert!(StdDuration::try_from(dur).is_err());
+
+    let dur = duration("+PT30M").unwrap();
+    assert!(!dur.is_negative());
+    assert_eq!(dur, duration("PT30M").unwrap());
+    assert_eq!(StdDuration::try_from(dur), Ok(StdDuration::new(1800, 0)));
+
+    assert!(duration("-P2W").unwrap().is_negative());
+    assert!(!duration("-P0D").unwrap().is_negative());
+    assert!(duration("-P0D").unwrap().is_zero());
+}
+
+#[test]
+fn test_duration_fraction() {
+    use core::time::Duration as StdDuration;
+
+    let dur = duration("PT1,5H").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 1,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: Some(Fraction {
+                unit: Unit::Hour,
+                billionths: 500_000_000,
+            }),
+        },
+        dur
+    );
+    assert_eq!(StdDuration::try_from(dur), Ok(StdDuration::new(5400, 0)));
+    assert_eq!(
+        StdDuration::try_from(duration("P0.5D").unwrap()),
+        Ok(StdDuration::new(43_200, 0))
+    );
+    assert!(duration("PT0.0M").unwrap().is_zero());
+    assert!(duration("-P0.25Y").unwrap().is_negative());
+    assert!(duration("P1.5W").is_err());
+    assert!(duration("P1.5DT2H").is_err());
+}
+
+#[test]
+fn test_duration_excess_fraction_digits() {
+    // the top-level function drops the digits beyond milliseconds
+    assert_eq!(duration("PT0.0001S"), duration("PT0S"));
+    assert_eq!(duration("PT0.123456789S"), duration("PT0.123S"));
+    assert_eq!(
+        duration("P0000-00-00T00:00:00.0001"),
+        duration("P0000-00-00T00:00:00")
+    );
+
+    let reject = ParserConfig {
+        excess_fraction_digits: ExcessDigits::Reject,
+        ..Default::default()
+    };
+    assert_eq!(reject.duration("PT1.5000S"), duration("PT1.5S"));
+    assert_eq!(reject.duration("-PT0,123000S"), duration("-PT0.123S"));
+    assert_eq!(
+        reject.duration("PT0.123456789H"),
+        duration("PT0.123456789H")
+    );
+    for input in [
+        "PT1.0001S",
+        "PT0.123456789S",
+        "P1DT2H3M4.5678S",
+        "P0000-00-00T00:00:00.0001",
+    ] {
+        let error = reject.duration(input).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::ExcessFractionDigits, "{input}");
+        assert_eq!(input.find('.').map(|s| s + 4), Some(error.offset));
+        assert_eq!(
+            reject.duration(input),
+            ParserConfig::from(Strictness::Pedantic).duration(input)
+        );
+    }
+}
+
+#[test]
+fn test_duration_large() {
+    use core::time::Duration as StdDuration;
+
+    let dur = duration("PT300000000S").unwrap();
+    assert_eq!(dur.total_seconds(), 300_000_000);
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::from_secs(300_000_000)
+    );
+
+    let dur = duration("P9223372036854775807Y").unwrap();
+    assert_eq!(dur.total_seconds(), i128::from(i64::MAX) * 365 * 86_400);
+    assert!(StdDuration::try_from(dur).is_err());
+
+    assert_eq!(
+        duration("PT5000000000.5S").unwrap(),
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 5_000_000_000,
+            millisecond: 500,
+            fraction: None,
+        }
+    );
+    assert_eq!(
+        duration("-P5000000000W").unwrap(),
+        Duration::Weeks(-5_000_000_000)
+    );
+    assert_eq!(duration("-PT1.5H").unwrap().total_seconds(), -5400);
+
+    // too large for any unit
+    assert!(duration("P9223372036854775808D").is_err());
+    assert!(duration("P99999999999999999999W").is_err());
+}
+
+#[test]
+fn test_duration_mixed_signs() {
+    let dur = duration("P1M-3D").unwrap();
+    assert_eq!(
+        Duration::YMDHMS {
+            year: 0,
+            month: 1,
+            day: -3,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        },
+        dur
+    );
+    assert!(!dur.is_negative());
+    assert_eq!(dur.total_seconds(), 27 * 86_400);
+    assert_eq!(dur.to_string(), "P1M-3D");
+
+    // the sign in front negates all units, including their own signs
+    assert_eq!(duration("-P1M-3D").unwrap(), -dur);
+    assert_eq!(duration("-P1M-3D").unwrap().to_string(), "P-1M3D");
+
+    assert_eq!(
+        duration("PT1H-30M-1.5S").unwrap(),
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 1,
+            minute: -30,
+            second: -1,
+            millisecond: -500,
+            fraction: None,
+        }
+    );
+    assert_eq!(duration("PT1H-0.5M").unwrap().total_seconds(), 3570);
+    assert_eq!(duration("P-2W").unwrap(), Duration::Weeks(-2));
+    assert_eq!(
+        duration_lenient("P1W-2D").unwrap(),
+        duration("P5D").unwrap()
+    );
+
+    // all negative units are written with a single sign in front
+    assert_eq!(duration("P-1DT-2H").unwrap().to_string(), "-P1DT2H");
+
+    // seconds and milliseconds of different signs are written as their sum
+    let mixed = |second, millisecond| Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day: 0,
+        hour: 0,
+        minute: 0,
+        second,
+        millisecond,
+        fraction: None,
+    };
+    assert_eq!(mixed(1, -5).to_string(), "PT0.995S");
+    assert_eq!(mixed(-1, 5).to_string(), "PT-0.995S");
+    assert_eq!(mixed(-1, -5).to_string(), "-PT1.005S");
+
+    assert!(duration("P--1D").is_err());
+    assert!(duration("P+1D").is_err());
+}
+
+#[test]
+fn test_duration_lenient() {
+    assert_eq!(
+        Ok(Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 9,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            fraction: None,
+        }),
+        duration_lenient("P1W2D")
+    );
+    assert_eq!(duration_lenient("P1Y3W"), duration("P1Y21D"));
+    assert_eq!(duration_lenient("-P1WT1H"), duration("-P7DT1H"));
+    assert_eq!(duration_lenient("P1W2.5D"), duration("P9.5D"));
+    assert_eq!(duration_lenient("P3W"), Ok(Duration::Weeks(3)));
+    assert_eq!(duration_lenient("P1DT2H"), duration("P1DT2H"));
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_qualified_date() {
+    let date = qualified_date("2023-02-08?").unwrap();
+    assert_eq!(
+        EdtfDate::Date(Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 8,
+        }),
+        date.date
+    );
+    assert!(date.qualification.uncertain);
+    assert!(!date.qualification.approximate);
+
+    let date = qualified_date("-0500~").unwrap();
+    assert_eq!(
+        EdtfDate::Reduced(ReducedDate::Year { year: -500 }),
+        date.date
+    );
+    assert!(date.qualification.approximate);
+
+    let date = qualified_date("2023-02%").unwrap();
+    assert!(date.qualification.uncertain && date.qualification.approximate);
+
+    assert_eq!(
+        qualified_date("2023-02-08").unwrap().qualification,
+        Qualification::default()
+    );
+    assert!(qualified_date("?").is_err());
+    assert!(qualified_date("20230208?").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_sub_year_grouping() {
+    let ymd = |year, month, day| Date::YMD { year, month, day };
+    let days = |s| {
+        let grouping = sub_year_grouping(s).unwrap();
+        (grouping.first_day(), grouping.last_day())
+    };
+
+    assert_eq!(
+        Ok(SubYearGrouping {
+            year: 2023,
+            grouping: 22,
+        }),
+        sub_year_grouping("2023-22")
+    );
+    // seasons
+    assert_eq!(days("2023-21"), (ymd(2023, 3, 1), ymd(2023, 5, 31)));
+    assert_eq!(days("2023-24"), (ymd(2023, 12, 1), ymd(2024, 2, 29)));
+    assert_eq!(days("2023-27"), (ymd(2023, 9, 1), ymd(2023, 11, 30)));
+    assert_eq!(days("2023-29"), (ymd(2023, 9, 1), ymd(2023, 11, 30)));
+    assert_eq!(days("2022-30"), (ymd(2022, 12, 1), ymd(2023, 2, 28)));
+    assert_eq!(days("2023-32"), (ymd(2023, 6, 1), ymd(2023, 8, 31)));
+    // quarters, quadrimesters and semesters
+    assert_eq!(days("2023-33"), (ymd(2023, 1, 1), ymd(2023, 3, 31)));
+    assert_eq!(days("2023-36"), (ymd(2023, 10, 1), ymd(2023, 12, 31)));
+    assert_eq!(days("2023-38"), (ymd(2023, 5, 1), ymd(2023, 8, 31)));
+    assert_eq!(days("2023-41"), (ymd(2023, 7, 1), ymd(2023, 12, 31)));
+
+    assert!(sub_year_grouping("2023-02").is_err());
+    assert!(sub_year_grouping("2023-02-08").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_unspecified_date() {
+    let ymd = |year, month, day| Some(Date::YMD { year, month, day });
+    let bounds = |s| {
+        let date = unspecified_date(s).unwrap();
+        (date.earliest(), date.latest())
+    };
+
+    assert_eq!(bounds("201X"), (ymd(2010, 1, 1), ymd(2019, 12, 31)));
+    assert_eq!(bounds("20XX"), (ymd(2000, 1, 1), ymd(2099, 12, 31)));
+    assert_eq!(bounds("2023-XX"), (ymd(2023, 1, 1), ymd(2023, 12, 31)));
+    assert_eq!(bounds("2024-02-XX"), (ymd(2024, 2, 1), ymd(2024, 2, 29)));
+    assert_eq!(bounds("2023-1X-3X"), (ymd(2023, 10, 30), ymd(2023, 12, 31)));
+    assert_eq!(bounds("20XX-02-29"), (ymd(2000, 2, 29), ymd(2096, 2, 29)));
+    assert_eq!(bounds("2023-02-08"), (ymd(2023, 2, 8), ymd(2023, 2, 8)));
+
+    assert!(unspecified_date("2023-13").is_err());
+    assert!(unspecified_date("2023-XX-32").is_err());
+    assert!(unspecified_date("201Y").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_date_set() {
+    let ymd = |year, month, day| Date::YMD { year, month, day };
+
+    let set = date_set("[1760-01,1760-02,1760-12..]").unwrap();
+    assert_eq!(SetKind::OneOf, set.kind);
+    assert_eq!(3, set.iter().count());
+    assert!(set.contains(&ymd(1760, 1, 31)));
+    assert!(!set.contains(&ymd(1760, 3, 1)));
+    assert!(set.contains(&ymd(1760, 12, 1)));
+    assert!(set.contains(&ymd(2023, 2, 8)));
+    assert!(!set.contains(&ymd(1759, 12, 31)));
+
+    let set = date_set("{1667, 1668, 1670..1672}").unwrap();
+    assert_eq!(SetKind::AllOf, set.kind);
+    assert!(set.contains(&ymd(1668, 6, 15)));
+    assert!(!set.contains(&ymd(1669, 6, 15)));
+    assert!(set.contains(&ymd(1672, 12, 31)));
+    assert!(set.contains(&Date::Ordinal {
+        year: 1671,
+        ddd: 100,
+    }));
+    assert!(set.contains(&Date::Week {
+        year: 1670,
+        ww: 2,
+        d: 1,
+    }));
+    assert_eq!(
+        Some(&SetMember::Range {
+            start: EdtfDate::Reduced(ReducedDate::Year { year: 1670 }),
+            end: EdtfDate::Reduced(ReducedDate::Year { year: 1672 }),
+        }),
+        (&set).into_iter().last()
+    );
+
+    let set = date_set("[2023-02-08]").unwrap();
+    assert!(set.contains(&Date::Week {
+        year: 2023,
+        ww: 6,
+        d: 3,
+    }));
+    assert!(!set.contains(&Date::Week {
+        year: 2023,
+        ww: 6,
+        d: 4,
+    }));
+
+    assert!(date_set("1667, 1668").is_err());
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_long_year() {
+    let year = long_year("Y170000002").unwrap();
+    assert_eq!(170_000_002, year.year);
+    assert_eq!((170_000_002, 170_000_002), (year.earliest(), year.latest()));
+
+    let year = long_year("Y171010000S3").unwrap();
+    assert_eq!(Some(3), year.significant_digits);
+    assert_eq!((171_000_000, 171_999_999), (year.earliest(), year.latest()));
+
+    let year = long_year("Y-17E7S2").unwrap();
+    assert_eq!(-170_000_000, year.year);
+    assert_eq!(
+        (-179_999_999, -170_000_000),
+        (year.earliest(), year.latest())
+    );
+
+    assert_eq!(long_year("Y2E3").unwrap().year, 2000);
+    assert!(long_year("2023").is_err());
+}
+
+#[test]
+fn test_bytes() {
+    let buffer = b"\xff\xfe2023-02-08T23:40:00Z\x00";
+    let field = &buffer[2..22];
+
+    assert_eq!(datetime_bytes(field), datetime("2023-02-08T23:40:00Z"));
+    assert_eq!(date_bytes(&field[..10]), date("2023-02-08"));
+    assert_eq!(time_bytes(&field[11..]), time("23:40:00Z"));
+    assert_eq!(offset_bytes(b"+05:30"), offset("+05:30"));
+    assert_eq!(duration_bytes(b"P1DT2H"), duration("P1DT2H"));
+    assert_eq!(
+        interval_bytes(b"2023-02-08/P1D"),
+        interval("2023-02-08/P1D")
+    );
+    assert_eq!(
+        repeating_interval_bytes(b"R5/2023-02-08/P1D"),
+        repeating_interval("R5/2023-02-08/P1D")
+    );
+
+    assert_eq!(
+        datetime_bytes(&buffer[..]),
+        Err(ParseError {
+            component: Component::DateTime,
+            offset: 0,
+            expected: Expected::Format,
+            kind: ErrorKind::Syntax,
+            input: "\u{fffd}\u{fffd}2023-02-08T23:40:00Z\u{0}".into(),
+        })
+    );
+}
+
+#[test]
+fn test_prefix() {
+    let token = "job-2023-02-08T23:40:00Z-retry1";
+    let (dt, rest) = datetime_prefix(&token[4..]).unwrap();
+    assert_eq!(Ok(dt), datetime("2023-02-08T23:40:00Z"));
+    assert_eq!(rest, "-retry1");
+
+    assert_eq!(
+        datetime_prefix("2023-02-08T23:40:00-05:00-retry1"),
+        Ok((datetime("2023-02-08T23:40:00-05:00").unwrap(), "-retry1"))
+    );
+    assert_eq!(
+        date_prefix("2023-02-08/rest"),
+        Ok((date("2023-02-08").unwrap(), "/rest"))
+    );
+    assert_eq!(time_prefix("23:40Z"), Ok((time("23:40Z").unwrap(), "")));
+    assert_eq!(
+        offset_prefix("−05:30 ü"),
+        Ok((offset("-05:30").unwrap(), " ü"))
+    );
+    assert_eq!(
+        duration_prefix("P1D and more"),
+        Ok((duration("P1D").unwrap(), " and more"))
+    );
+    assert_eq!(
+        interval_prefix("2023-02-08/P1D|"),
+        Ok((interval("2023-02-08/P1D").unwrap(), "|"))
+    );
+
+    assert!(datetime_prefix("job-2023-02-08T23:40:00Z").is_err());
+}
+
+#[test]
+fn test_parse_any() {
+    assert_eq!(
+        parse_any("2023-02-08T23:40:00Z"),
+        Ok(Iso8601::DateTime(datetime("2023-02-08T23:40:00Z").unwrap()))
+    );
+    assert_eq!(
+        parse_any("20230208"),
+        Ok(Iso8601::Date(date("20230208").unwrap()))
+    );
+    assert_eq!(
+        parse_any("2023-W06-3"),
+        Ok(Iso8601::Date(date("2023-W06-3").unwrap()))
+    );
+    assert_eq!(parse_any("1648"), Ok(Iso8601::Time(time("1648").unwrap())));
+    assert_eq!(
+        parse_any("T23:40:00+01:00"),
+        Ok(Iso8601::Time(time("23:40:00+01:00").unwrap()))
+    );
+    assert_eq!(
+        parse_any("P1DT2H"),
+        Ok(Iso8601::Duration(duration("P1DT2H").unwrap()))
+    );
+    assert_eq!(
+        parse_any("2023-02-08T23:40:00Z/P1D"),
+        Ok(Iso8601::Interval(
+            interval("2023-02-08T23:40:00Z/P1D").unwrap()
+        ))
+    );
+    assert_eq!("P1D".parse::<Iso8601>(), parse_any("P1D"));
+
+    assert!(parse_any("2023-02-08T23:40:00Z trailing").is_err());
+    assert!(parse_any("").is_err());
+    assert!(parse_any("pppp").is_err());
+}
+
+#[test]
+fn test_case_insensitive() {
+    let config = ParserConfig {
+        case_insensitive: true,
+        ..Default::default()
+    };
+
+    assert_eq!(config.duration("p1dt2h"), duration("P1DT2H"));
+    assert_eq!(
+        config.datetime("2023-02-08t23:40z"),
+        datetime("2023-02-08T23:40Z")
+    );
+    assert_eq!(config.date("2023-w06-2"), date("2023-W06-2"));
+    assert_eq!(config.time("t23:40:00z"), time("T23:40:00Z"));
+    assert_eq!(
+        config.interval("2023-02-08/p1d"),
+        interval("2023-02-08/P1D")
+    );
+    assert_eq!(
+        config.repeating_interval("r5/2023-02-08/p1d"),
+        repeating_interval("R5/2023-02-08/P1D")
+    );
+    assert_eq!(
+        config.date("2023-x06-2").map_err(|e| e.to_string()),
+        Err("Failed to parse date: 2023-x06-2, expected the date format at byte 5".into())
+    );
+
+    let config = ParserConfig::default();
+    assert!(config.duration("p1dt2h").is_err());
+    assert_eq!(config.duration("P1DT2H"), duration("P1DT2H"));
+}
+
+#[test]
+fn test_parse_error() {
+    let error = datetime("2023-02-08T25:00").unwrap_err();
+    assert_eq!(error.component, Component::DateTime);
+    assert_eq!(error.offset, 11);
+    assert_eq!(error.expected, Expected::Format);
+    assert_eq!(
+        error.to_string(),
+        "Failed to parse datetime: 2023-02-08T25:00, expected the datetime format at byte 11"
+    );
+
+    let error = datetime_html("2023-02-08T23:40 local").unwrap_err();
+    assert_eq!(
+        (error.component, error.expected, error.offset),
+        (Component::HtmlDateTime, Expected::End, 16)
+    );
+
+    let (_, rest) = duration_prefix("P1D rest").unwrap();
+    assert_eq!(rest, " rest");
+    assert_eq!(duration("X").unwrap_err().component, Component::Duration);
+
+    let strict = ParserConfig::from(Profile::Strict8601);
+    let error = strict.date("2023-02-30").unwrap_err();
+    assert_eq!((error.expected, error.offset), (Expected::ExistingDate, 0));
+
+    // errors can be passed on with `?`
+    #[cfg(feature = "std")]
+    {
+        fn parse(s: &str) -> Result<DateTime, Box<dyn std::error::Error>> {
+            Ok(datetime(s)?)
+        }
+        assert!(parse("2023-02-08T23:40").is_ok());
+        assert!(parse("nope").is_err());
+    }
+}
+
+#[test]
+fn test_error_kind() {
+    #[track_caller]
+    fn kind<T: core::fmt::Debug>(result: Result<T, ParseError>) -> ErrorKind {
+        result.unwrap_err().kind
+    }
+
+    assert_eq!(kind(date("2023-13-01")), ErrorKind::InvalidMonth);
+    assert_eq!(kind(date("2023-02-32")), ErrorKind::InvalidDay);
+    assert_eq!(kind(date("2023-W54")), ErrorKind::InvalidWeek);
+    assert_eq!(kind(date("2023-W05-8")), ErrorKind::InvalidWeekday);
+    assert_eq!(kind(date("2023-367")), ErrorKind::InvalidOrdinal);
+    assert_eq!(kind(datetime("2023-02-08T25:00")), ErrorKind::InvalidHour);
+    assert_eq!(kind(time("23:60")), ErrorKind::InvalidMinute);
+    assert_eq!(kind(offset("+25:00")), ErrorKind::OffsetOutOfRange);
+    assert_eq!(
+        kind(datetime_rfc3339("2023-02-08T23:40:00+24:00")),
+        ErrorKind::OffsetOutOfRange
+    );
+    assert_eq!(
+        kind(datetime_rfc3339("2023-02-08T23:40:00Zx")),
+        ErrorKind::TrailingInput
+    );
+    assert_eq!(kind(date("nope")), ErrorKind::Syntax);
+
+    let strict = ParserConfig::from(Profile::Strict8601);
+    assert_eq!(kind(strict.date("2023-02-30")), ErrorKind::NonexistentDate);
+
+    // the HTML profile finds the day that does not exist, not a syntax error
+    let html = ParserConfig::from(Profile::Html);
+    let error = html.datetime("2023-02-29T00:00").unwrap_err();
+    assert_eq!(
+        (error.kind, error.offset, error.span()),
+        (ErrorKind::NonexistentDate, 8, 8..16)
+    );
+    assert_eq!(
+        html.diagnose_datetime("2023-02-29T00:00")[0].kind,
+        ErrorKind::NonexistentDate
+    );
+}
+
+#[test]
+fn test_diagnostics() {
+    let diagnostic = |kind, span| Diagnostic { kind, span };
+
+    let config = ParserConfig::default();
+    assert_eq!(config.diagnose_datetime("2023-02-08T23:40:00Z"), vec![]);
+    assert_eq!(
+        config.diagnose_datetime("2023-13-32T25:40"),
+        vec![
+            diagnostic(ErrorKind::InvalidMonth, 5..7),
+            diagnostic(ErrorKind::InvalidDay, 8..10),
+            diagnostic(ErrorKind::InvalidHour, 11..13),
+        ]
+    );
+    assert_eq!(
+        config.diagnose_date("2023-13-0x"),
+        vec![
+            diagnostic(ErrorKind::InvalidMonth, 5..7),
+            diagnostic(ErrorKind::Syntax, 8..10),
+        ]
+    );
+    assert_eq!(
+        config.diagnose_date("2023-W54-8"),
+        vec![
+            diagnostic(ErrorKind::InvalidWeek, 6..8),
+            diagnostic(ErrorKind::InvalidWeekday, 9..10),
+        ]
+    );
+
+    let strict = ParserConfig::from(Profile::Strict8601);
+    assert_eq!(
+        strict.diagnose_date("2023-02-30"),
+        vec![diagnostic(ErrorKind::NonexistentDate, 0..10)]
+    );
+    assert_eq!(
+        strict.diagnose_time("25:40Zx"),
+        vec![
+            diagnostic(ErrorKind::InvalidHour, 0..2),
+            diagnostic(ErrorKind::TrailingInput, 6..7),
+        ]
+    );
+    assert_eq!(strict.diagnose_offset("+01:00"), vec![]);
+    assert_eq!(
+        strict.diagnose_interval("2023-02-08/2023-02-31"),
+        vec![diagnostic(ErrorKind::NonexistentDate, 0..21)]
+    );
+
+    assert_eq!(
+        diagnostic(ErrorKind::InvalidMonth, 5..7).to_string(),
+        "month out of range at bytes 5..7"
+    );
+}
+
+#[test]
+fn test_conversion_error() {
+    use core::time::Duration as StdDuration;
+
+    assert_eq!(
+        StdDuration::try_from(duration("-P1D").unwrap()),
+        Err(ConversionError::OutOfRange)
+    );
+    assert_eq!(
+        ConversionError::OutOfRange.to_string(),
+        "value out of range"
+    );
+}
+
+#[test]
+fn test_trailing() {
+    let ignore = ParserConfig::default();
+    let reject = ParserConfig {
+        trailing: Trailing::Reject,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        ignore.datetime("2023-02-08T23:40:00Z trailing"),
+        datetime("2023-02-08T23:40:00Z")
+    );
+    assert_eq!(
+        reject.datetime("2023-02-08T23:40:00Z trailing"),
+        Err(ParseError {
+            component: Component::DateTime,
+            offset: 20,
+            expected: Expected::End,
+            kind: ErrorKind::TrailingInput,
+            input: "2023-02-08T23:40:00Z trailing".into(),
+        })
+    );
+    assert_eq!(
+        reject.datetime("2023-02-08T23:40:00Z"),
+        datetime("2023-02-08T23:40:00Z")
+    );
+    assert!(reject.date("2023-02-08T").is_err());
+    assert!(reject.time("23:40:00Zz").is_err());
+    assert!(reject.duration("P1D1").is_err());
+    assert!(reject.interval("2023-02-08/P1D/").is_err());
+    assert!(reject.repeating_interval("R5/P1D ").is_err());
+
+    let both = ParserConfig {
+        case_insensitive: true,
+        trailing: Trailing::Reject,
+        ..Default::default()
+    };
+    assert_eq!(both.duration("p1d"), duration("P1D"));
+    assert!(both.duration("p1d x").is_err());
+}
+
+#[test]
+fn test_profiles() {
+    let strict = ParserConfig::from(Profile::Strict8601);
+    assert_eq!(
+        strict.datetime("20230208T2340Z"),
+        datetime("20230208T2340Z")
+    );
+    assert!(strict.datetime("2023-02-08 23:40Z").is_err());
+    assert!(strict.datetime("2023-02-08T23:40Z ").is_err());
+    assert!(strict.duration("p1d").is_err());
+
+    let rfc3339 = ParserConfig::from(Profile::Rfc3339);
+    assert_eq!(
+        rfc3339.datetime("2023-02-08t23:40:00z"),
+        datetime_rfc3339("2023-02-08T23:40:00Z")
+    );
+    assert!(rfc3339.datetime("2023-02-08T23:40Z").is_err());
+
+    let html = ParserConfig::from(Profile::Html);
+    assert_eq!(
+        html.datetime("2023-02-08 23:40"),
+        datetime_html("2023-02-08T23:40")
+    );
+    assert!(html.datetime("2023-W06-3T23:40").is_err());
+
+    let sql = ParserConfig::from(Profile::Sql);
+    assert_eq!(
+        sql.datetime("2023-02-08 23:40:00+01"),
+        datetime("2023-02-08T23:40:00+01:00")
+    );
+    assert!(sql.datetime("2023-02-08 23:40:00+01 extra").is_err());
+
+    let python = ParserConfig::from(Profile::Python);
+    assert_eq!(
+        python.datetime("2023-02-08 23:40:00.000123+01:00"),
+        datetime_python("2023-02-08T23:40:00.000123+01:00")
+    );
+    assert!(python.datetime("2023-02-08T23:40:00.1+01:00").is_err());
+
+    let lenient = ParserConfig::from(Profile::Lenient);
+    assert_eq!(
+        lenient.datetime("2023-02-08 23:40:00z, more"),
+        datetime("2023-02-08T23:40:00Z")
+    );
+    assert_eq!(lenient.duration("p1dt2h"), duration("P1DT2H"));
+    assert_eq!(lenient.offset("z"), offset("Z"));
+}
+
+#[test]
+fn test_profiles_other_parsers() {
+    let strict = ParserConfig::from(Profile::Strict8601);
+    let lenient = ParserConfig::from(Profile::Lenient);
+
+    assert_eq!(
+        strict.parse_any("2023-02-08T23:40Z"),
+        parse_any("2023-02-08T23:40Z")
+    );
+    assert_eq!(lenient.parse_any("p1dt2h"), parse_any("P1DT2H"));
+    let error = strict.parse_any("2023-02-30").unwrap_err();
+    assert_eq!(
+        (error.component, error.expected),
+        (Component::Any, Expected::ExistingDate)
+    );
+    // the whole string has to match, even for a config that ignores what follows
+    assert!(lenient.parse_any("2023-02-08 x").is_err());
+
+    assert_eq!(lenient.reduced_date("2023-02 x"), reduced_date("2023-02"));
+    assert!(strict.reduced_date("2023-02 x").is_err());
+
+    assert_eq!(lenient.week("2023-w06"), week("2023-W06"));
+    assert!(strict.week("2020-W53").is_ok());
+    assert!(strict.week("2023-W53").is_err());
+    assert!(lenient.week("2023-W53").is_ok());
+
+    assert!(strict.truncated_date("--02-29").is_ok());
+    assert!(strict.truncated_date("--02-30").is_err());
+    assert!(lenient.truncated_date("--02-30").is_ok());
+
+    assert_eq!(
+        strict.date_expanded("+0020230-02-08", 3),
+        date_expanded("+0020230-02-08", 3)
+    );
+    assert!(strict.date_expanded("+0020230-02-30", 3).is_err());
+    assert!(strict.date_expanded("+0020230-02-08 x", 3).is_err());
+
+    let s = "2023-02-08T23:40:00.123456+01:00[Europe/Berlin]";
+    assert_eq!(strict.datetime_ixdtf(s), datetime_ixdtf(s));
+    assert!(strict.datetime_ixdtf("2023-02-30T23:40:00Z").is_err());
+    // time zone names keep their case
+    let time_zone = lenient.datetime_ixdtf(s).unwrap().time_zone.unwrap();
+    assert_eq!(time_zone.name, "Europe/Berlin");
+    let millis = ParserConfig {
+        max_fraction_digits: Some(3),
+        ..Default::default()
+    };
+    assert_eq!(
+        millis
+            .datetime_ixdtf(s)
+            .map(|dt| dt.datetime.time().nanosecond()),
+        Ok(123_000_000)
+    );
+    #[cfg(feature = "temporal")]
+    assert_eq!(
+        millis
+            .datetime_temporal("20230208T234000.123456[UTC]")
+            .map(|dt| dt.datetime.time.nanosecond()),
+        Ok(123_000_000)
+    );
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_profiles_edtf() {
+    let strict = ParserConfig::from(Profile::Strict8601);
+    let lenient = ParserConfig::from(Profile::Lenient);
+
+    assert_eq!(
+        strict.qualified_date("2023-02-08?"),
+        qualified_date("2023-02-08?")
+    );
+    assert!(strict.qualified_date("2023-02-30?").is_err());
+    assert!(strict.qualified_date("2023-02-08? x").is_err());
+    assert_eq!(
+        lenient.unspecified_date("2023-xx"),
+        unspecified_date("2023-XX")
+    );
+    assert_eq!(
+        lenient.sub_year_grouping("2023-21 x"),
+        sub_year_grouping("2023-21")
+    );
+    assert_eq!(
+        strict.date_set("[2023-01, 2023-02]"),
+        date_set("[2023-01, 2023-02]")
+    );
+    assert_eq!(lenient.long_year("y-17e7"), long_year("Y-17E7"));
+    assert!(strict.long_year("y-17e7").is_err());
+
+    assert!(QualifiedDate::parse_with("2023-02-30?", Strictness::Pedantic).is_err());
+    assert!(QualifiedDate::parse_with("2023-02-30?", Strictness::Standard).is_ok());
+    assert!(SubYearGrouping::parse_with("2023-21 x", Strictness::Pedantic).is_err());
+    assert!(UnspecifiedDate::parse_with("2023-xx", Strictness::Lenient).is_ok());
+    assert!(DateSet::parse_with("[2023-01, 2023-02] x", Strictness::Pedantic).is_err());
+    assert_eq!(
+        LongYear::parse_with("y-17e7", Strictness::Lenient),
+        long_year("Y-17E7")
+    );
+}
+
+#[test]
+fn test_calendar_validation() {
+    let raw = ParserConfig::default();
+    let validating = ParserConfig {
+        validate_calendar: true,
+        ..Default::default()
+    };
+
+    for invalid in [
+        "2023-02-29",
+        "2023-02-30",
+        "2023-04-31",
+        "2023-W53-1",
+        "2023-366",
+    ] {
+        assert!(!date(invalid).unwrap().is_valid(), "{}", invalid);
+        assert!(raw.date(invalid).is_ok(), "{}", invalid);
+        assert!(validating.date(invalid).is_err(), "{}", invalid);
+    }
+    for valid in ["2024-02-29", "2020-W53-5", "2024-366", "2023-12-31"] {
+        assert!(date(valid).unwrap().is_valid(), "{}", valid);
+        assert!(validating.date(valid).is_ok(), "{}", valid);
+    }
+
+    assert!(validating.datetime("2023-02-30T23:40:00Z").is_err());
+    assert!(validating.datetime("2023-02-28T23:40:00Z").is_ok());
+    assert!(validating.interval("2023-02-28/2023-02-30").is_err());
+    assert!(validating.interval("2023-02-30/P1D").is_err());
+    assert!(validating.interval("../2023-02-28").is_ok());
+    assert!(validating.repeating_interval("R2/2023-02-30/P1D").is_err());
+
+    assert!(ParserConfig::from(Profile::Strict8601)
+        .date("2023-02-30")
+        .is_err());
+    assert!(ParserConfig::from(Profile::Lenient)
+        .date("2023-02-30")
+        .is_ok());
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn test_datetime_temporal() {
+    let dt = datetime_temporal("2023-02-08T23:40:00+01:00[Europe/Berlin][u-ca=hebrew]").unwrap();
+    assert_eq!(
+        dt,
+        datetime_ixdtf("2023-02-08T23:40:00+01:00[Europe/Berlin][u-ca=hebrew]").unwrap()
+    );
+
+    let dt = datetime_temporal("2023-02-08T22:40:00Z[!UTC]").unwrap();
+    assert_eq!(
+        dt.time_zone,
+        Some(TimeZoneAnnotation {
+            name: "UTC".into(),
+            critical: true,
+        })
+    );
+    assert_eq!(dt.datetime, datetime("2023-02-08T22:40:00Z").unwrap());
+
+    let dt = datetime_temporal("+275760-09-13T00:00[u-ca=iso8601]").unwrap();
+    assert_eq!(
+        dt.datetime.date,
+        Date::YMD {
+            year: 275760,
+            month: 9,
+            day: 13,
+        }
+    );
+    assert_eq!(dt.time_zone, None);
+    assert_eq!(dt.annotations[0].value, "iso8601");
+
+    assert!(datetime_temporal("2023-02-08").is_err());
+    assert!(datetime_temporal("2023-02-08T23:40:00Z[Europe/Berlin] ").is_err());
+    assert!(datetime_temporal("2023-02-08T23:40:00Z[U-CA=hebrew]").is_err());
+}
+
+#[test]
+fn test_violations() {
+    assert_eq!(date("2023-02-08").unwrap().violations(), vec![]);
+    assert_eq!(
+        Date::YMD {
+            year: 2023,
+            month: 0,
+            day: 0
+        }
+        .violations(),
+        vec![ErrorKind::InvalidMonth, ErrorKind::InvalidDay]
+    );
+    assert_eq!(
+        Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 29
+        }
+        .violations(),
+        vec![ErrorKind::NonexistentDate]
+    );
+    assert_eq!(
+        Date::Week {
+            year: 2023,
+            ww: 0,
+            d: 8
+        }
+        .violations(),
+        vec![ErrorKind::InvalidWeek, ErrorKind::InvalidWeekday]
+    );
+    assert_eq!(
+        Date::Ordinal {
+            year: 2023,
+            ddd: 366
+        }
+        .violations(),
+        vec![ErrorKind::NonexistentDate]
+    );
+    assert_eq!(
+        Date::Ordinal {
+            year: 2023,
+            ddd: 367
+        }
+        .violations(),
+        vec![ErrorKind::InvalidOrdinal]
+    );
+
+    // the same dates are invalid as with `validate()`
+    for year in [2020, 2023, i32::MIN, i32::MAX] {
+        for (a, b) in (0..=14).flat_map(|a| (0..=370).map(move |b| (a, b))) {
+            for date in [
+                Date::YMD {
+                    year,
+                    month: a,
+                    day: b,
+                },
+                Date::Week { year, ww: b, d: a },
+                Date::Ordinal { year, ddd: b },
+            ] {
+                assert_eq!(
+                    date.violations().is_empty(),
+                    date.validate().is_ok(),
+                    "{date:?}"
+                );
+            }
+        }
+    }
+
+    assert_eq!(time("24:00:00").unwrap().violations(), vec![]);
+    assert_eq!(
+        time("23:59:60.999999999+23:59:59").unwrap().violations(),
+        vec![]
+    );
+    // times out of range can only be built through the public fields
+    #[cfg(feature = "legacy-fields")]
+    {
+        let mut t = time("24:00:00").unwrap();
+        t.minute = 60;
+        t.second = 61;
+        t.nanosecond = 1_000_000_000;
+        assert_eq!(
+            t.violations(),
+            vec![
+                ErrorKind::InvalidHour,
+                ErrorKind::InvalidMinute,
+                ErrorKind::InvalidSecond,
+                ErrorKind::InvalidFraction
+            ]
+        );
+    }
+    let t = time("12:00:00")
+        .unwrap()
+        .set_offset(UtcOffset::Fixed { seconds: -90_000 });
+    assert_eq!(t.violations(), vec![ErrorKind::OffsetOutOfRange]);
+
+    let dt = datetime("2023-02-08T23:40:00Z").unwrap();
+    assert_eq!(dt.violations(), vec![]);
+    let dt = DateTime::new(
+        Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 30,
+        },
+        dt.time(),
+    );
+    assert_eq!(dt.violations(), vec![ErrorKind::NonexistentDate]);
+    #[cfg(feature = "legacy-fields")]
+    {
+        let mut dt = dt;
+        dt.time.hour = 25;
+        assert_eq!(
+            dt.violations(),
+            vec![ErrorKind::NonexistentDate, ErrorKind::InvalidHour]
+        );
+    }
+
+    for s in [
+        "P1Y",
+        "PT1.5H",
+        "P1,5Y",
+        "P1Y2.5M",
+        "PT1M0.999S",
+        "-PT1.5M",
+        "P2W",
+    ] {
+        assert_eq!(duration(s).unwrap().violations(), vec![], "{s}");
+    }
+    let fraction = |unit, billionths| Some(Fraction { unit, billionths });
+    let d = |day, hour, millisecond, fraction| Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day,
+        hour,
+        minute: 0,
+        second: 0,
+        millisecond,
+        fraction,
+    };
+    assert_eq!(
+        d(0, 0, 1000, None).violations(),
+        vec![ErrorKind::InvalidFraction]
+    );
+    assert_eq!(
+        d(0, 0, -1000, None).violations(),
+        vec![ErrorKind::InvalidFraction]
+    );
+    for invalid in [
+        d(0, 1, 0, fraction(Unit::Day, 500_000_000)),
+        d(0, 0, 1, fraction(Unit::Hour, 500_000_000)),
+        d(0, 1, 0, fraction(Unit::Hour, 1_000_000_000)),
+        d(0, 0, 0, fraction(Unit::Second, 1)),
+        d(0, 0, 0, fraction(Unit::Week, 1)),
+    ] {
+        assert_eq!(
+            invalid.violations(),
+            vec![ErrorKind::InvalidFraction],
+            "{invalid:?}"
+        );
+    }
+    assert_eq!(
+        d(1, 0, 1000, fraction(Unit::Day, 1)).violations(),
+        vec![ErrorKind::InvalidFraction, ErrorKind::InvalidFraction]
+    );
+}
+
+#[test]
+fn test_empty_duration() {
+    for s in ["P", "PT", "-P", "-PT"] {
+        assert_eq!(
+            duration(s).map_err(|e| e.kind),
+            Err(ErrorKind::Syntax),
+            "{s}"
+        );
+    }
+    let strict = ParserConfig::from(Profile::Strict8601);
+    assert!(strict.duration("PT").is_err());
+    assert!(strict.duration("P1DT").is_err());
+    assert!(duration("PT0S").unwrap().is_zero());
+    assert!(duration("P0D").unwrap().is_zero());
+    assert!(!duration("PT1S").unwrap().is_zero());
+
+    let config = ParserConfig {
+        empty_durations: true,
+        ..Default::default()
+    };
+    for s in ["P", "PT", "-PT"] {
+        assert!(config.duration(s).unwrap().is_zero(), "{s}");
+    }
+    assert_eq!(config.duration("PT1H"), duration("PT1H"));
+    assert!(ParserConfig::from(Profile::Lenient)
+        .duration("pt")
+        .unwrap()
+        .is_zero());
+}
+
+#[test]
+fn test_fraction_digit_limits() {
+    let config = |max_fraction_digits, excess_fraction_digits| ParserConfig {
+        max_fraction_digits,
+        excess_fraction_digits,
+        ..Default::default()
+    };
+    let nanos = |config: ParserConfig, s| {
+        let t = config.time(s).unwrap();
+        (
+            t.hour(),
+            t.minute(),
+            t.second(),
+            t.nanosecond(),
+            t.fraction_digits(),
+        )
+    };
+
+    // like the top-level functions by default
+    let long = "12:00:00.123456789987654";
+    assert_eq!(ParserConfig::default().time(long), time(long));
+    assert_eq!(nanos(Default::default(), long), (12, 0, 0, 123_456_789, 9));
+
+    let truncate = config(Some(3), ExcessDigits::Truncate);
+    assert_eq!(
+        nanos(truncate, "12:00:00.123999"),
+        (12, 0, 0, 123_000_000, 3)
+    );
+    assert_eq!(nanos(truncate, "12:00:00,12"), (12, 0, 0, 120_000_000, 2));
+    assert_eq!(nanos(truncate, "12:00:00.5"), (12, 0, 0, 500_000_000, 1));
+
+    let round = config(Some(3), ExcessDigits::Round);
+    assert_eq!(nanos(round, "12:00:00.1234"), (12, 0, 0, 123_000_000, 3));
+    assert_eq!(nanos(round, "12:00:00.1235"), (12, 0, 0, 124_000_000, 3));
+    assert_eq!(nanos(round, "12:00:59.9996"), (12, 1, 0, 0, 3));
+    assert_eq!(nanos(round, "12:59:60.9999"), (13, 0, 0, 0, 3));
+    assert_eq!(nanos(round, "23:59:59.9999Z"), (24, 0, 0, 0, 3));
+    assert_eq!(
+        nanos(config(None, ExcessDigits::Round), long),
+        (12, 0, 0, 123_456_790, 9)
+    );
+    assert_eq!(
+        nanos(config(Some(12), ExcessDigits::Round), long),
+        (12, 0, 0, 123_456_790, 9)
+    );
+
+    let whole = config(Some(0), ExcessDigits::Round)
+        .time("12:00:00.5")
+        .unwrap();
+    assert_eq!(whole, time("12:00:01").unwrap());
+    assert_eq!(whole.precision(), Precision::Second);
+
+    let reject = config(Some(3), ExcessDigits::Reject);
+    assert_eq!(nanos(reject, "12:00:00.123"), (12, 0, 0, 123_000_000, 3));
+    let error = reject.time("12:00:00.12345Z").unwrap_err();
+    assert_eq!(error.kind, ErrorKind::ExcessFractionDigits);
+    assert_eq!(error.span(), 12..14);
+    assert_eq!(
+        reject.diagnose_time("12:00:00.12345"),
+        vec![Diagnostic {
+            kind: ErrorKind::ExcessFractionDigits,
+            span: 12..14
+        }]
+    );
+    let reject_nanos = config(None, ExcessDigits::Reject);
+    assert!(reject_nanos.time("12:00:00.123456789").is_ok());
+    assert_eq!(reject_nanos.time(long).map_err(|e| e.offset), Err(18));
+
+    // fractions of hours and minutes are left alone
+    assert_eq!(reject.time("12,12345"), time("12,12345"));
+
+    let rfc3339 = ParserConfig {
+        max_fraction_digits: Some(6),
+        excess_fraction_digits: ExcessDigits::Round,
+        ..Profile::Rfc3339.into()
+    };
+    let dt = rfc3339
+        .datetime("2023-02-08T23:59:59.999999987654321Z")
+        .unwrap();
+    assert_eq!(dt, datetime("2023-02-08T24:00:00.000000Z").unwrap());
+    assert!(rfc3339.datetime("2023-02-08T23:40:00.123456789Z").is_ok());
+}
+
+#[test]
+fn test_offset_range() {
+    let kind = |r: Result<Time, ParseError>| r.map_err(|e| (e.kind, e.offset));
+    let strict = ParserConfig::from(Profile::Strict8601);
+
+    assert!(strict.time("23:40:00+23:59:59").is_ok());
+    assert!(strict.time("23:40:00+24:00").is_err());
+    assert_eq!(
+        strict.offset("-99:00").map_err(|e| (e.kind, e.offset)),
+        Err((ErrorKind::OffsetOutOfRange, 1))
+    );
+    assert_eq!(
+        datetime_rfc3339("2023-02-08T23:40:00+24:00").map_err(|e| e.kind),
+        Err(ErrorKind::OffsetOutOfRange)
+    );
+
+    let eighteen = ParserConfig {
+        offset_range: OffsetRange::EighteenHours,
+        ..strict
+    };
+    for s in ["+18:00", "-18:00", "\u{2212}18:00", "Z", "-00:00", "+05:30"] {
+        assert!(eighteen.offset(s).is_ok(), "{s}");
+    }
+    for s in ["+18:01", "-18:00:01", "+23:00"] {
+        assert_eq!(
+            eighteen.offset(s).map_err(|e| (e.kind, e.offset)),
+            Err((ErrorKind::OffsetOutOfRange, 1)),
+            "{s}"
+        );
+    }
+    assert_eq!(
+        eighteen
+            .offset("\u{2212}19:00")
+            .map_err(|e| (e.kind, e.offset)),
+        Err((ErrorKind::OffsetOutOfRange, 3))
+    );
+    assert_eq!(
+        kind(eighteen.time("T2340-1900")),
+        Err((ErrorKind::OffsetOutOfRange, 6))
+    );
+    assert_eq!(
+        eighteen
+            .datetime("-2023-02-08T23:40:00-20:00")
+            .map_err(|e| (e.kind, e.span())),
+        Err((ErrorKind::OffsetOutOfRange, 21..23))
+    );
+    assert_eq!(
+        eighteen.diagnose_datetime("2023-02-08T23:40:00+19:00"),
+        vec![Diagnostic {
+            kind: ErrorKind::OffsetOutOfRange,
+            span: 20..22
+        }]
+    );
+}
+
+#[test]
+fn test_no_panics() {
+    let min = Duration::YMDHMS {
+        year: i64::MIN,
+        month: i64::MIN,
+        day: i64::MIN,
+        hour: i64::MIN,
+        minute: i64::MIN,
+        second: i64::MIN,
+        millisecond: i64::MIN,
+        fraction: Some(Fraction {
+            unit: Unit::Year,
+            billionths: i64::MIN,
+        }),
+    };
+    assert!(!(-min).is_negative());
+    assert_eq!(-Duration::Weeks(i64::MIN), Duration::Weeks(i64::MAX));
+    assert!(min.total_seconds() < 0);
+    assert!(std::time::Duration::try_from(min).is_err());
+    let _ = (min.to_string(), min.violations(), min.partial_cmp(&-min));
+
+    #[cfg(feature = "legacy-fields")]
+    {
+        let mut t = time("23:59:60").unwrap();
+        t.minute = u32::MAX;
+        t.nanosecond = u32::MAX;
+        t.fraction_digits = u8::MAX;
+        let _ = (t.to_string(), t.violations(), t.normalize_midnight());
+        assert!(t.resolve_leap_second(LeapSecondPolicy::Carry).is_some());
+
+        let dt = DateTime::new(Date::MAX, t);
+        let _ = (
+            dt.to_string(),
+            dt.cmp(&DateTime::MIN),
+            dt.normalize_midnight(),
+        );
+        let _ = dt.resolve_leap_second(LeapSecondPolicy::Carry);
+    }
+
+    for date in [
+        Date::YMD {
+            year: i32::MIN,
+            month: u32::MAX,
+            day: u32::MAX,
+        },
+        Date::Week {
+            year: i32::MAX,
+            ww: u32::MAX,
+            d: u32::MAX,
+        },
+        Date::Ordinal {
+            year: i32::MIN,
+            ddd: u32::MAX,
+        },
+    ] {
+        let _ = (
+            date.to_string(),
+            date.validate(),
+            date.violations(),
+            date.canonical(),
+        );
+        let _ = (date.cmp(&Date::MAX), date.same_day(&Date::MIN));
+    }
+
+    // parsing numbers of any length
+    let long = format!("P{}D", "9".repeat(100));
+    assert!(duration(&long).is_err());
+    assert!(datetime_html(&format!("{}-02-08T23:40", "9".repeat(100))).is_err());
+    assert!(time(&format!("23:40:00.{}", "9".repeat(100))).is_ok());
+}
+
+#[cfg(feature = "edtf")]
+#[test]
+fn test_edtf_no_panics() {
+    let year = LongYear {
+        year: i64::MIN,
+        exponent: Some(30),
+        significant_digits: Some(0),
+    };
+    let _ = (year.to_string(), year.earliest(), year.latest());
+    assert_eq!(
+        long_year("Y02321").map_err(|e| e.kind),
+        Err(ErrorKind::Syntax)
+    );
+    assert!(long_year("Y12321").is_ok());
+
+    for grouping in [0, 20, 42, u32::MAX] {
+        let grouping = SubYearGrouping {
+            year: i32::MAX,
+            grouping,
+        };
+        let _ = (
+            grouping.to_string(),
+            grouping.first_day(),
+            grouping.last_day(),
+        );
+    }
+}
+
+#[test]
+fn test_strictness() {
+    assert_eq!(
+        ParserConfig::from(Strictness::Standard),
+        ParserConfig::default()
+    );
+    assert_eq!(
+        ParserConfig::from(Strictness::Lenient),
+        ParserConfig::from(Profile::Lenient)
+    );
+
+    let pedantic = ParserConfig::from(Strictness::Pedantic);
+    for consistent in [
+        "2023-02-08T23:40:00.5+01:00",
+        "20230208T234000,5+0100",
+        "2023-W06-3T23:40Z",
+        "2023039T2340-05",
+        "-0333-07-11T23",
+        "2023-02-08T23+01:00",
+        "20230208T23+0100",
+        "2023-02-08T23:40:00\u{2212}05:30",
+    ] {
+        assert!(pedantic.datetime(consistent).is_ok(), "{}", consistent);
+        assert_eq!(pedantic.diagnose_datetime(consistent), vec![]);
+        assert!(datetime(consistent).is_ok());
+    }
+    assert!(pedantic.time("T23:40:00+01:00").is_ok());
+    assert!(pedantic.time("234000+0100").is_ok());
+
+    let kind = |r: Result<DateTime, ParseError>| r.map_err(|e| (e.kind, e.offset));
+    assert_eq!(
+        kind(pedantic.datetime("2023-02-08T234000")),
+        Err((ErrorKind::MixedFormat, 11))
+    );
+    assert_eq!(
+        kind(pedantic.datetime("20230208T23:40:00")),
+        Err((ErrorKind::MixedFormat, 9))
+    );
+    assert_eq!(
+        kind(pedantic.datetime("2023-02-08T23:40:00+0100")),
+        Err((ErrorKind::MixedFormat, 20))
+    );
+    assert_eq!(
+        kind(pedantic.datetime("2023-02-08T23+0100")),
+        Err((ErrorKind::MixedFormat, 14))
+    );
+    assert_eq!(
+        pedantic.time("2340+01:00").map_err(|e| (e.kind, e.offset)),
+        Err((ErrorKind::MixedFormat, 5))
+    );
+    assert_eq!(
+        pedantic.diagnose_datetime("2023-02-08T234000"),
+        vec![Diagnostic {
+            kind: ErrorKind::MixedFormat,
+            span: 11..17
+        }]
+    );
+    assert_eq!(
+        pedantic
+            .datetime("2023-02-08T234000")
+            .unwrap_err()
+            .snippet()
+            .to_string(),
+        "2023-02-08T234000\n           ^ basic and extended format mixed"
+    );
+
+    // everything else follows the strict profile, and more than nine digits are refused
+    assert!(pedantic.datetime("2023-02-08 23:40:00Z").is_err());
+    assert!(pedantic.datetime("2023-02-08t23:40:00Z").is_err());
+    assert!(pedantic.datetime("2023-02-30T23:40:00Z").is_err());
+    assert!(pedantic.datetime("2023-02-08T23:40:00Z ").is_err());
+    assert!(pedantic.duration("PT").is_err());
+    assert!(pedantic.time("23:40:00.123456789").is_ok());
+    assert_eq!(
+        pedantic.time("23:40:00.1234567891").map_err(|e| e.kind),
+        Err(ErrorKind::ExcessFractionDigits)
+    );
+
+    let standard = ParserConfig::from(Strictness::Standard);
+    assert_eq!(
+        standard.datetime("2023-02-08T234000"),
+        datetime("2023-02-08T23:40:00")
+    );
+    assert!(standard.datetime("2023-02-08 23:40:00").is_err());
+    assert!(standard.datetime("2023-02-30T23:40:00").is_ok());
+
+    let lenient = ParserConfig::from(Strictness::Lenient);
+    assert_eq!(
+        lenient.datetime("2023-02-08 234000z"),
+        datetime("2023-02-08T23:40:00Z")
+    );
+    assert!(lenient.duration("pt").is_ok());
+}
+
+#[test]
+fn test_parse_with() {
+    assert_eq!(
+        Date::parse_with("2023-02-08", Strictness::Standard),
+        date("2023-02-08")
+    );
+    assert!(Date::parse_with("2023-02-30", Strictness::Standard).is_ok());
+    assert!(Date::parse_with("2023-02-30", Strictness::Pedantic).is_err());
+    assert!(Time::parse_with("T23:40:00+01:00", Strictness::Pedantic).is_ok());
+    assert!(Time::parse_with("T23:40:00+0100", Strictness::Pedantic).is_err());
+    assert!(DateTime::parse_with("2023-02-08 23:40:00z", Strictness::Lenient).is_ok());
+    assert!(DateTime::parse_with("2023-02-08 23:40:00z", Strictness::Standard).is_err());
+    assert!(DateTime::parse_with("2023-02-08T23:40Z", Profile::Rfc3339).is_err());
+    assert!(UtcOffset::parse_with("+01:00 ", Strictness::Standard).is_ok());
+    assert!(UtcOffset::parse_with("+01:00 ", Strictness::Pedantic).is_err());
+    assert!(Duration::parse_with("pt", Strictness::Lenient).is_ok());
+    assert!(Duration::parse_with("PT", Strictness::Standard).is_err());
+    assert!(Interval::parse_with("2023-02-08/P1D", Strictness::Pedantic).is_ok());
+    assert!(RepeatingInterval::parse_with("R2/2023-02-08/P1D", Strictness::Pedantic).is_ok());
+    assert!(Iso8601::parse_with("2023-02-30", Strictness::Pedantic).is_err());
+    assert!(Iso8601::parse_with("2023-02-30", Strictness::Standard).is_ok());
+    assert!(ReducedDate::parse_with("2023-02", Strictness::Pedantic).is_ok());
+    assert!(Week::parse_with("2023-W53", Strictness::Pedantic).is_err());
+    assert!(Week::parse_with("2023-W53", Strictness::Standard).is_ok());
+    assert!(TruncatedDate::parse_with("--02-30", Strictness::Pedantic).is_err());
+    assert!(IxdtfDateTime::parse_with("2023-02-30T23:40Z[UTC]", Strictness::Pedantic).is_err());
+    assert_eq!(
+        IxdtfDateTime::parse_with("2023-02-08T23:40Z[UTC]", Strictness::Standard),
+        datetime_ixdtf("2023-02-08T23:40Z[UTC]")
+    );
+
+    let config = ParserConfig {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    assert_eq!(Duration::parse_with("p1d", config), duration("P1D"));
+}
+
+#[test]
+fn test_datetime_parts() {
+    let dt = datetime("2023-W06-3T23:40:00.5-05:00").unwrap();
+    let (date, time) = dt.into_parts();
+    assert_eq!(
+        date,
+        Date::Week {
+            year: 2023,
+            ww: 6,
+            d: 3
+        }
+    );
+    assert_eq!(time, iso8601::time("23:40:00.5-05:00").unwrap());
+    assert_eq!(DateTime::from_parts((date, time)), dt);
+    assert_eq!(DateTime::from((date, time)), DateTime::new(date, time));
+    assert_eq!(<(Date, Time)>::from(dt), (date, time));
+}
+
+#[test]
+fn test_builders() {
+    let time = Time::builder()
+        .hour(23)
+        .minute(40)
+        .second(5)
+        .millisecond(123)
+        .offset_hm(-5, -30)
+        .build()
+        .unwrap();
+    assert_eq!(time, iso8601::time("23:40:05.123-05:30").unwrap());
+    assert_eq!(Time::builder().build().ok(), iso8601::time("00:00:00").ok());
+    assert_eq!(
+        Time::builder().hour(24).build().ok(),
+        iso8601::time("24:00:00").ok()
+    );
+    assert_eq!(
+        Time::builder().offset_hms(0, 19, 32).build().ok(),
+        iso8601::time("00:00:00+00:19:32").ok()
+    );
+    assert_eq!(
+        Time::builder().hour(24).nanosecond(1).build(),
+        Err(TimeError::Hour)
+    );
+    assert_eq!(
+        Time::builder().millisecond(1000).build(),
+        Err(TimeError::Nanosecond)
+    );
+    assert_eq!(
+        Time::builder()
+            .offset_hms(-23, -59, -59)
+            .build()
+            .map(|t| t.offset()),
+        Ok(UtcOffset::Fixed { seconds: -86_399 })
+    );
+    assert_eq!(
+        Time::builder().offset_hm(i32::MAX, 0).build(),
+        Err(TimeError::Offset)
+    );
+
+    let dt = DateTime::builder()
+        .ordinal(2024, 366)
+        .hour(12)
+        .nanosecond(500)
+        .offset(UtcOffset::UnknownLocal)
+        .build()
+        .unwrap();
+    assert_eq!(dt, datetime("2024-366T12:00:00.0000005-00:00").unwrap());
+    assert_eq!(
+        DateTime::builder().date(Date::MAX).utc().build(),
+        Ok(DateTime::new(
+            Date::MAX,
+            iso8601::time("00:00:00Z").unwrap()
+        ))
+    );
+    assert_eq!(
+        DateTime::builder().iso_week(2023, 53, 1).build(),
+        Err(DateTimeError::Date(DateError::Week))
+    );
+    assert_eq!(
+        DateTime::builder().ymd(2023, 2, 8).offset_hm(25, 0).build(),
+        Err(DateTimeError::Time(TimeError::Offset))
+    );
+    assert_eq!(DateTimeError::MissingDate.to_string(), "no date set");
+    assert_eq!(
+        DateTimeError::from(TimeError::Offset).to_string(),
+        "offset out of range"
+    );
+}
+
+#[test]
+fn test_date_accessors() {
+    let ymd = |s| {
+        let date = iso8601::date(s).unwrap();
+        (date.year(), date.month(), date.day())
+    };
+    assert_eq!(ymd("2023-02-08"), (2023, 2, 8));
+    assert_eq!(ymd("2023-W06-3"), (2023, 2, 8));
+    assert_eq!(ymd("2023-039"), (2023, 2, 8));
+    assert_eq!(ymd("2024-366"), (2024, 12, 31));
+    assert_eq!(ymd("2020-W53-5"), (2021, 1, 1));
+    assert_eq!(ymd("2019-W01-1"), (2018, 12, 31));
+    assert_eq!(ymd("-0001-W52-7"), (0, 1, 2));
+    // calendar dates keep what they were written with
+    assert_eq!(ymd("2023-02-30"), (2023, 2, 30));
+    assert_eq!(ymd("2023-366"), (2024, 1, 1));
+
+    // the first and last weeks of the range stay within it
+    let first = Date::Week {
+        year: i32::MIN,
+        ww: 1,
+        d: 1,
+    };
+    assert_eq!((first.year(), first.month(), first.day()), (i32::MIN, 1, 1));
+    let last = Date::Week {
+        year: i32::MAX,
+        ww: 99,
+        d: 9,
+    };
+    assert_eq!((last.year(), last.month(), last.day()), (i32::MAX, 12, 31));
+}
+
+#[test]
+fn test_weekday() {
+    let weekday = |s: &str| iso8601::date(s).unwrap().weekday();
+    assert_eq!(weekday("1970-01-01"), Weekday::Thursday);
+    assert_eq!(weekday("2023-02-08"), Weekday::Wednesday);
+    assert_eq!(weekday("2023-W06-3"), Weekday::Wednesday);
+    assert_eq!(weekday("2023-039"), Weekday::Wednesday);
+    assert_eq!(weekday("2024-02-29"), Weekday::Thursday);
+    assert_eq!(weekday("-0333-07-11"), Weekday::Monday);
+    assert_eq!(weekday("2023-02-30"), Weekday::Thursday);
+    assert_eq!(Date::MIN.weekday(), Weekday::Tuesday);
+    assert_eq!(Date::MAX.weekday(), Weekday::Tuesday);
+
+    for number in 1..=7 {
+        let day = Weekday::from_number_from_monday(number).unwrap();
+        assert_eq!(day.number_from_monday(), number);
+        assert_eq!(
+            weekday(&format!("2023-W06-{}", number)).to_string(),
+            day.to_string()
+        );
+    }
+    assert_eq!(Weekday::from_number_from_monday(0), None);
+    assert_eq!(Weekday::from_number_from_monday(8), None);
+    assert!(Weekday::Monday < Weekday::Sunday);
+    assert_eq!(Weekday::Sunday.to_string(), "Sunday");
+}
+
+#[test]
+fn test_ordinal_and_iso_week() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    assert_eq!(date("2023-02-08").ordinal(), 39);
+    assert_eq!(date("2023-W06-3").ordinal(), 39);
+    assert_eq!(date("2023-039").ordinal(), 39);
+    assert_eq!(date("2024-12-31").ordinal(), 366);
+    assert_eq!(date("2019-W01-1").ordinal(), 365);
+
+    // the days around the turn of a year
+    assert_eq!(date("2019-12-29").iso_week(), (2019, 52));
+    assert_eq!(date("2019-12-30").iso_week(), (2020, 1));
+    assert_eq!(date("2021-01-03").iso_week(), (2020, 53));
+    assert_eq!(date("2021-01-04").iso_week(), (2021, 1));
+    assert_eq!(date("2027-01-01").iso_week(), (2026, 53));
+    assert_eq!(date("2016-01-03").iso_week(), (2015, 53));
+    assert_eq!(date("2008-12-29").iso_week(), (2009, 1));
+    assert_eq!(date("2020-W53-7").iso_week(), (2020, 53));
+    assert_eq!(date("2023-W06-3").iso_week(), (2023, 6));
+    assert_eq!(date("2020-366").iso_week(), (2020, 53));
+    assert_eq!(date("-0001-12-31").iso_week(), (-1, 52));
+
+    assert_eq!(Date::MIN.iso_week(), (i32::MIN, 1));
+    assert_eq!(Date::MIN.ordinal(), 1);
+    assert_eq!(Date::MAX.iso_week(), (i32::MAX, 1));
+    assert_eq!(Date::MAX.ordinal(), 365);
+    assert_eq!(
+        Date::YMD {
+            year: i32::MAX,
+            month: 12,
+            day: 29
+        }
+        .iso_week(),
+        (i32::MAX, 52)
+    );
+}
+
+#[test]
+fn test_date_conversions() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    for (ymd, week, ordinal) in [
+        ("2023-02-08", "2023-W06-3", "2023-039"),
+        ("2021-01-03", "2020-W53-7", "2021-003"),
+        ("2019-12-30", "2020-W01-1", "2019-364"),
+        ("2024-12-31", "2025-W01-2", "2024-366"),
+        ("0000-01-01", "-0001-W52-6", "0000-001"),
+        ("-0333-07-11", "-0333-W28-1", "-0333-192"),
+    ] {
+        for written in [ymd, week, ordinal] {
+            assert_eq!(date(written).to_ymd(), Ok(date(ymd)), "{}", written);
+            assert_eq!(date(written).to_week_date(), Ok(date(week)), "{}", written);
+            assert_eq!(date(written).to_ordinal(), Ok(date(ordinal)), "{}", written);
+        }
+    }
+
+    assert_eq!(date("2023-02-29").to_week_date(), Err(DateError::Day));
+    assert_eq!(date("2023-W53-1").to_ordinal(), Err(DateError::Week));
+    assert_eq!(date("2023-366").to_ymd(), Err(DateError::Ordinal));
+
+    assert_eq!(
+        Date::MIN.to_week_date(),
+        Ok(Date::Week {
+            year: i32::MIN,
+            ww: 1,
+            d: 2
+        })
+    );
+    assert_eq!(Date::MAX.to_week_date(), Err(DateError::OutOfRange));
+    assert_eq!(
+        Date::MAX.to_ordinal(),
+        Ok(Date::Ordinal {
+            year: i32::MAX,
+            ddd: 365
+        })
+    );
+}
+
+#[test]
+fn test_calendar() {
+    use iso8601::calendar::{days_in_month, days_in_year, is_leap_year, weeks_in_iso_year};
+
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(2100));
+    assert!(is_leap_year(-4));
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 0), 0);
+    assert_eq!(days_in_year(2023), 365);
+    assert_eq!(days_in_year(0), 366);
+    assert_eq!(weeks_in_iso_year(2015), 53);
+    assert_eq!(weeks_in_iso_year(2019), 52);
+    assert_eq!(weeks_in_iso_year(i32::MIN), 52);
+    assert_eq!(weeks_in_iso_year(i32::MAX), 52);
+
+    // the dates that exist are those the helpers count
+    for year in [1900, 2000, 2023, 2024] {
+        let days = (1..=12)
+            .map(|month| days_in_month(year, month))
+            .sum::<u32>();
+        assert_eq!(days, days_in_year(year));
+        assert!(Date::from_ordinal(year, days_in_year(year)).is_ok());
+        assert!(Date::from_iso_week(year, weeks_in_iso_year(year), 7).is_ok());
+        assert!(Date::from_iso_week(year, weeks_in_iso_year(year) + 1, 1).is_err());
+    }
+}
+
+#[test]
+fn test_date_until() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    let days = |start: &str, end: &str| {
+        date(start)
+            .until(date(end))
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        days("2024-02-28", "2024-03-02"),
+        ["2024-02-28", "2024-02-29", "2024-03-01"]
+    );
+    assert_eq!(
+        days("2020-W53-6", "2021-01-05"),
+        ["2020-W53-6", "2020-W53-7", "2021-W01-1"]
+    );
+    assert_eq!(
+        days("2023-364", "2024-W01-2"),
+        ["2023-364", "2023-365", "2024-001"]
+    );
+    assert!(days("2023-02-08", "2023-02-08").is_empty());
+    assert!(days("2023-02-08", "2023-01-01").is_empty());
+
+    let year = date("2024-01-01").until(date("2025-01-01"));
+    assert_eq!(year.size_hint(), (366, Some(366)));
+    assert_eq!(year.count(), 366);
+    assert_eq!(year.last(), Some(date("2024-12-31")));
+
+    // the range stops after the last day
+    let end = Date::MAX.pred().unwrap().until(Date::Week {
+        year: i32::MAX,
+        ww: 53,
+        d: 7,
+    });
+    assert_eq!(end.size_hint(), (2, Some(2)));
+    assert_eq!(end.count(), 2);
+
+    for start in [
+        "2023-02-08",
+        "2023-W06-3",
+        "2023-039",
+        "2020-12-31",
+        "2020-W53-7",
+    ] {
+        let start = date(start);
+        assert_eq!(start.succ().unwrap().pred(), Some(start));
+        assert_eq!(start.pred().unwrap().succ(), Some(start));
+        assert!(start
+            .succ()
+            .unwrap()
+            .same_day(&start.to_ymd().unwrap().succ().unwrap()));
+    }
+    assert_eq!(Date::MIN.pred(), None);
+    assert_eq!(Date::MIN.to_week_date().unwrap().pred(), None);
+    assert_eq!(Date::MIN.to_ordinal().unwrap().pred(), None);
+    assert_eq!(Date::MAX.succ(), None);
+}
+
+#[test]
+fn test_date_arithmetic() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    let months = |s: &str, n, policy| date(s).add_months(n, policy).map(|d| d.to_string());
+    let years = |s: &str, n, policy| date(s).add_years(n, policy).map(|d| d.to_string());
+
+    assert_eq!(date("2023-02-08").add_days(0), Ok(date("2023-02-08")));
+    assert_eq!(date("2023-02-08").add_days(365), Ok(date("2024-02-08")));
+    assert_eq!(date("2024-03-01").add_days(-1), Ok(date("2024-02-29")));
+    assert_eq!(date("2023-039").add_days(-39), Ok(date("2022-365")));
+    assert_eq!(date("2020-W53-7").add_days(1), Ok(date("2021-W01-1")));
+    assert_eq!(Date::MAX.add_days(1), Err(DateError::OutOfRange));
+    assert_eq!(Date::MIN.add_days(-1), Err(DateError::OutOfRange));
+    assert_eq!(Date::MIN.add_days(i64::MIN), Err(DateError::OutOfRange));
+    assert_eq!(date("2023-02-30").add_days(1), Err(DateError::Day));
+
+    use OverflowPolicy::*;
+    for policy in [Constrain, Overflow, Reject] {
+        assert_eq!(months("2023-01-15", 1, policy), Ok("2023-02-15".into()));
+        assert_eq!(months("2023-01-15", -1, policy), Ok("2022-12-15".into()));
+        assert_eq!(months("2023-01-15", 23, policy), Ok("2024-12-15".into()));
+        assert_eq!(months("2023-01-15", -13, policy), Ok("2021-12-15".into()));
+        assert_eq!(years("2023-02-28", 1, policy), Ok("2024-02-28".into()));
+    }
+    assert_eq!(months("2023-01-31", 1, Constrain), Ok("2023-02-28".into()));
+    assert_eq!(months("2024-01-31", 1, Constrain), Ok("2024-02-29".into()));
+    assert_eq!(months("2023-03-31", -1, Constrain), Ok("2023-02-28".into()));
+    assert_eq!(months("2023-01-31", 1, Overflow), Ok("2023-03-03".into()));
+    assert_eq!(months("2024-01-31", 1, Overflow), Ok("2024-03-02".into()));
+    assert_eq!(months("2023-05-31", 1, Overflow), Ok("2023-07-01".into()));
+    assert_eq!(months("2023-01-31", 1, Reject), Err(DateError::Day));
+    assert_eq!(years("2024-02-29", 1, Constrain), Ok("2025-02-28".into()));
+    assert_eq!(years("2024-02-29", -4, Reject), Ok("2020-02-29".into()));
+    assert_eq!(years("2024-02-29", 1, Reject), Err(DateError::Day));
+    assert_eq!(OverflowPolicy::default(), Constrain);
+
+    // other formats are moved by their calendar date and keep their format
+    assert_eq!(months("2023-W05-2", 1, Constrain), Ok("2023-W09-2".into()));
+    assert_eq!(months("2023-031", 1, Constrain), Ok("2023-059".into()));
+    assert_eq!(months("2023-031", 1, Overflow), Ok("2023-062".into()));
+    assert_eq!(years("2023-039", 1, Reject), Ok("2024-039".into()));
+
+    assert_eq!(
+        Date::MAX.add_months(1, Constrain),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::MIN.add_years(-1, Constrain),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::MIN.add_years(i64::MAX, Constrain),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::MIN.add_months(i64::MAX, Constrain),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::MIN.add_years(i64::from(u32::MAX), Constrain),
+        Ok(Date::YMD {
+            year: i32::MAX,
+            month: 1,
+            day: 1
+        })
+    );
+}
+
+#[test]
+fn test_datetime_checked_add() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let add = |s: &str, d: &str| {
+        dt(s)
+            .checked_add(&iso8601::duration(d).unwrap())
+            .map(|dt| dt.to_string())
+    };
+    let sub = |s: &str, d: &str| {
+        dt(s)
+            .checked_sub(&iso8601::duration(d).unwrap())
+            .map(|dt| dt.to_string())
+    };
+
+    // months and years by the calendar, not as 30 or 365 days
+    assert_eq!(
+        add("2023-01-31T12:00:00Z", "P1M"),
+        Some("2023-02-28T12:00:00Z".into())
+    );
+    assert_eq!(
+        add("2023-02-08T23:40:00Z", "P1M"),
+        Some("2023-03-08T23:40:00Z".into())
+    );
+    assert_eq!(
+        add("2024-02-29T00:00:00Z", "P1Y"),
+        Some("2025-02-28T00:00:00Z".into())
+    );
+    assert_eq!(
+        add("2023-03-31T00:00:00Z", "P1M1D"),
+        Some("2023-05-01T00:00:00Z".into())
+    );
+    assert_eq!(
+        sub("2023-03-31T00:00:00Z", "P1M"),
+        Some("2023-02-28T00:00:00Z".into())
+    );
+
+    // everything else exactly, keeping the offset and the date format
+    assert_eq!(
+        add("2023-02-08T23:40:00+05:30", "PT30M"),
+        Some("2023-02-09T00:10:00+05:30".into())
+    );
+    assert_eq!(
+        add("2023-W06-3T12:00:00", "P10DT36H"),
+        Some("2023-W08-1T00:00:00".into())
+    );
+    assert_eq!(
+        add("2023-039T00:00:00Z", "-PT1S"),
+        Some("2023-038T23:59:59Z".into())
+    );
+    assert_eq!(
+        add("2023-02-08T00:00:00Z", "PT1.5H"),
+        Some("2023-02-08T01:30:00Z".into())
+    );
+    assert_eq!(
+        add("2023-02-08T00:00:00Z", "PT0.001S"),
+        Some("2023-02-08T00:00:00.001Z".into())
+    );
+    assert_eq!(
+        add("2023-02-08T00:00:00.5Z", "PT0.5S"),
+        Some("2023-02-08T00:00:01.0Z".into())
+    );
+    assert_eq!(
+        sub("2023-01-01T00:00:00Z", "P0.5D"),
+        Some("2022-12-31T12:00:00Z".into())
+    );
+    assert_eq!(
+        add("2016-12-31T23:59:60Z", "PT1S"),
+        Some("2017-01-01T00:00:01Z".into())
+    );
+    assert_eq!(
+        add("2023-02-08T24:00:00Z", "PT0S"),
+        Some("2023-02-09T00:00:00Z".into())
+    );
+
+    // the time shows the components it needs
+    let minutes = dt("2023-02-08T23:40Z").checked_add(&iso8601::duration("PT5S").unwrap());
+    assert_eq!(minutes.unwrap().precision(), Precision::Second);
+    let minutes = dt("2023-02-08T23:40Z").checked_add(&iso8601::duration("PT5M").unwrap());
+    assert_eq!(minutes.unwrap().precision(), Precision::Minute);
+
+    assert_eq!(add("2023-02-08T00:00:00Z", "P0.5Y"), None);
+    assert_eq!(add("2023-02-29T00:00:00Z", "P1D"), None);
+    assert_eq!(add("2023-02-08T00:00:00Z", "P9223372036854775807Y"), None);
+    assert_eq!(add("2023-02-08T00:00:00Z", "PT9223372036854775807H"), None);
+    assert_eq!(
+        DateTime::MAX.checked_add(&Duration::Weeks(0)),
+        Some(DateTime::MAX)
+    );
+    assert_eq!(
+        DateTime::MAX.checked_add(&iso8601::duration("PT0.001S").unwrap()),
+        None
+    );
+    assert_eq!(
+        DateTime::MIN.checked_sub(&iso8601::duration("PT1S").unwrap()),
+        None
+    );
+}
+
+#[test]
+fn test_time_add() {
+    let t = |s: &str| iso8601::time(s).unwrap();
+    let add = |s: &str, d: &str| {
+        let (time, days) = t(s).add(&iso8601::duration(d).unwrap());
+        (time.to_string(), days)
+    };
+
+    assert_eq!(add("22:00:00", "PT9H"), ("07:00:00".into(), 1));
+    assert_eq!(add("22:00:00", "PT1H"), ("23:00:00".into(), 0));
+    assert_eq!(add("22:00:00", "PT2H"), ("00:00:00".into(), 1));
+    assert_eq!(add("22:00:00+02:00", "PT50H"), ("00:00:00+02:00".into(), 3));
+    assert_eq!(add("01:00:00Z", "-PT1H"), ("00:00:00Z".into(), 0));
+    assert_eq!(
+        add("01:00:00Z", "-PT1H0.001S"),
+        ("23:59:59.999Z".into(), -1)
+    );
+    assert_eq!(add("12:00:00", "-P2DT12H"), ("00:00:00".into(), -2));
+    assert_eq!(add("12:00:00", "P2W"), ("12:00:00".into(), 14));
+    assert_eq!(add("12:00:00", "PT0.5M"), ("12:00:30".into(), 0));
+    assert_eq!(add("23:59:60Z", "PT0S"), ("00:00:00Z".into(), 1));
+    assert_eq!(add("24:00:00", "PT1M"), ("00:01:00".into(), 1));
+    assert_eq!(add("12:00:00.50", "PT0.5S"), ("12:00:01.00".into(), 0));
+
+    // years and months need a date
+    assert_eq!(add("12:00:00", "P1Y2M3DT4H"), ("16:00:00".into(), 3));
+    assert_eq!(add("12:00:00", "P0.5M"), ("12:00:00".into(), 0));
+
+    let (time, _) = t("12:00").add(&iso8601::duration("PT5S").unwrap());
+    assert_eq!(time.precision(), Precision::Second);
+    let (time, _) = t("12:00").add(&iso8601::duration("PT5M").unwrap());
+    assert_eq!(time.precision(), Precision::Minute);
+
+    assert_eq!(
+        t("00:00:00").add(&Duration::Weeks(i64::MAX)),
+        (t("00:00:00"), i32::MAX)
+    );
+    assert_eq!(
+        t("00:00:00").add(&Duration::Weeks(i64::MIN)),
+        (t("00:00:00"), i32::MIN)
+    );
+}
+
+#[test]
+fn test_datetime_since() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let since = |a: &str, b: &str| dt(a).since(&dt(b)).to_string();
+
+    assert_eq!(since("2023-02-08T23:40:00Z", "2023-02-08T23:40:00Z"), "P0D");
+    assert_eq!(
+        since("2023-02-09T00:10:00Z", "2023-02-08T23:40:00Z"),
+        "PT30M"
+    );
+    assert_eq!(
+        since("2023-02-08T23:40:00Z", "2023-02-09T00:10:00Z"),
+        "-PT30M"
+    );
+    assert_eq!(
+        since("2023-02-09T00:10:00+01:00", "2023-02-08T23:40:00Z"),
+        "-PT30M"
+    );
+    assert_eq!(
+        since("2024-03-01T00:00:00", "2024-02-01T00:00:00"),
+        "PT696H"
+    );
+    assert_eq!(since("2024-W01-1T00:00:00Z", "2023-365T12:00:00Z"), "PT12H");
+    assert_eq!(
+        since("2023-02-08T23:40:01.0019999Z", "2023-02-08T23:40:00Z"),
+        "PT1.001S"
+    );
+    assert_eq!(
+        since("2023-02-08T23:40:00Z", "2023-02-08T23:39:58.9999Z"),
+        "PT1S"
+    );
+    // a leap second is the same instant as the start of the next day
+    assert_eq!(since("2017-01-01T00:00:00Z", "2016-12-31T23:59:60Z"), "P0D");
+
+    // adding the difference gives the same instant back
+    for (a, b) in [
+        ("2023-02-08T23:40:00.123Z", "1970-01-01T00:00:00Z"),
+        ("2023-02-08T23:40:00+05:30", "2024-02-29T12:00:00+05:30"),
+        ("-0333-07-11T00:00:00Z", "2023-02-08T23:40:00Z"),
+    ] {
+        let elapsed = dt(a).since(&dt(b));
+        assert!(dt(b).checked_add(&elapsed).unwrap().same_instant(&dt(a)));
+        assert_eq!(dt(b).since(&dt(a)), -elapsed);
+    }
+
+    let span = DateTime::MAX.since(&DateTime::MIN);
+    assert_eq!(
+        span,
+        Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 37_648_910_222_639,
+            minute: 59,
+            second: 59,
+            millisecond: 999,
+            fraction: None,
+        }
+    );
+    assert_eq!(-span, DateTime::MIN.since(&DateTime::MAX));
+}
+
+#[test]
+fn test_date_age_until() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    let age = |a: &str, b: &str| date(a).age_until(&date(b)).to_string();
+
+    assert_eq!(age("2023-02-08", "2023-02-08"), "P0D");
+    assert_eq!(age("2023-02-08", "2023-02-09"), "P1D");
+    assert_eq!(age("2023-02-08", "2023-03-08"), "P1M");
+    assert_eq!(age("2023-02-08", "2023-03-07"), "P27D");
+    assert_eq!(age("2023-02-08", "2024-02-08"), "P1Y");
+    assert_eq!(age("2023-02-08", "2024-02-07"), "P11M30D");
+    assert_eq!(age("1990-05-17", "2023-02-08"), "P32Y8M22D");
+    assert_eq!(age("2023-02-08", "1990-05-17"), "-P32Y8M22D");
+
+    // around the ends of months
+    assert_eq!(age("2023-01-31", "2023-02-28"), "P1M");
+    assert_eq!(age("2023-01-31", "2023-03-01"), "P1M1D");
+    assert_eq!(age("2023-01-31", "2023-03-31"), "P2M");
+    assert_eq!(age("2023-01-30", "2023-02-28"), "P1M");
+    assert_eq!(age("2023-01-30", "2023-02-27"), "P28D");
+    assert_eq!(age("2023-03-31", "2023-02-28"), "-P1M");
+    assert_eq!(age("2023-03-31", "2023-03-01"), "-P30D");
+    assert_eq!(age("2024-02-29", "2025-02-28"), "P1Y");
+    assert_eq!(age("2024-02-29", "2028-02-29"), "P4Y");
+    assert_eq!(age("2025-02-28", "2024-02-29"), "-P11M28D");
+
+    // any format, and dates that do not exist as the days they overflow into
+    assert_eq!(age("2023-W06-3", "2023-100"), "P2M2D");
+    assert_eq!(age("2023-02-30", "2023-04-02"), "P1M");
+
+    // adding the result gives the other date back
+    for (a, b) in [
+        ("2020-02-29", "2023-02-28"),
+        ("2023-05-31", "2023-09-30"),
+        ("2023-12-31", "2024-02-29"),
+        ("-0333-07-11", "2023-02-08"),
+    ] {
+        let duration = date(a).age_until(&date(b));
+        let added = DateTime::new(date(a), Time::new(0, 0, 0, 0).unwrap())
+            .checked_add(&duration)
+            .unwrap();
+        assert_eq!(added.date(), date(b));
+    }
+
+    let span = Date::MIN.age_until(&Date::MAX);
+    assert_eq!(span.to_string(), "P4294967295Y11M30D");
+    assert_eq!(Date::MAX.age_until(&Date::MIN), -span);
+}
+
+#[test]
+fn test_duration_normalize() {
+    let normalize = |s: &str, largest| {
+        iso8601::duration(s)
+            .unwrap()
+            .normalize(largest)
+            .map(|d| d.to_string())
+    };
+
+    assert_eq!(normalize("PT90M", Unit::Hour), Some("PT1H30M".into()));
+    assert_eq!(normalize("PT90M", Unit::Minute), Some("PT90M".into()));
+    assert_eq!(normalize("PT1H30M", Unit::Minute), Some("PT90M".into()));
+    assert_eq!(normalize("PT90M", Unit::Second), Some("PT5400S".into()));
+    assert_eq!(
+        normalize("PT3661.5S", Unit::Day),
+        Some("PT1H1M1.500S".into())
+    );
+    assert_eq!(normalize("PT48H", Unit::Day), Some("P2D".into()));
+    assert_eq!(normalize("P2D", Unit::Hour), Some("PT48H".into()));
+    assert_eq!(normalize("P0D", Unit::Year), Some("P0D".into()));
+
+    // days are not carried into months, nor weeks
+    assert_eq!(normalize("P400D", Unit::Year), Some("P400D".into()));
+    assert_eq!(normalize("P1M40D", Unit::Year), Some("P1M40D".into()));
+    assert_eq!(normalize("P14D", Unit::Week), Some("P14D".into()));
+    assert_eq!(normalize("PT25H", Unit::Week), Some("P1DT1H".into()));
+
+    // years and months only with one of them as the largest unit
+    assert_eq!(normalize("P30M", Unit::Year), Some("P2Y6M".into()));
+    assert_eq!(normalize("P2Y6M", Unit::Month), Some("P30M".into()));
+    assert_eq!(normalize("P30M", Unit::Day), Some("P30M".into()));
+    assert_eq!(normalize("P1.5Y", Unit::Month), Some("P1.5Y".into()));
+    assert_eq!(normalize("P12.5M", Unit::Year), Some("P1Y0.5M".into()));
+
+    // weeks
+    assert_eq!(normalize("P2W", Unit::Week), Some("P2W".into()));
+    assert_eq!(normalize("P2W", Unit::Year), Some("P2W".into()));
+    assert_eq!(normalize("P2W", Unit::Day), Some("P14D".into()));
+    assert_eq!(normalize("P1W", Unit::Hour), Some("PT168H".into()));
+
+    // mixed signs are balanced
+    assert_eq!(normalize("PT1H-30M", Unit::Hour), Some("PT30M".into()));
+    assert_eq!(normalize("-PT90M", Unit::Hour), Some("-PT1H30M".into()));
+    assert_eq!(normalize("P1Y-1M", Unit::Year), Some("P11M".into()));
+    assert_eq!(normalize("P1M-3D", Unit::Year), Some("P1M-3D".into()));
+
+    // fractions of days, hours and minutes in whole milliseconds
+    assert_eq!(normalize("P1.5D", Unit::Day), Some("P1DT12H".into()));
+    assert_eq!(normalize("PT0.5M", Unit::Hour), Some("PT30S".into()));
+    assert_eq!(normalize("PT0.000001H", Unit::Hour), None);
+    assert_eq!(
+        iso8601::duration("P9223372036854775807D")
+            .unwrap()
+            .normalize(Unit::Hour),
+        None
+    );
+    assert_eq!(Duration::Weeks(i64::MAX).normalize(Unit::Day), None);
+}
+
+#[test]
+fn test_duration_mul_div() {
+    let duration = |s: &str| iso8601::duration(s).unwrap();
+    let mul = |s: &str, n| duration(s).checked_mul(n).map(|d| d.to_string());
+    let div = |s: &str, n| duration(s).checked_div(n).map(|d| d.to_string());
+
+    assert_eq!(mul("PT20S", 3), Some("PT60S".into()));
+    assert_eq!(mul("P1Y2M3DT4H5M6S", 2), Some("P2Y4M6DT8H10M12S".into()));
+    assert_eq!(mul("PT0.600S", 3), Some("PT1.800S".into()));
+    assert_eq!(mul("PT1.5H", 3), Some("PT4.5H".into()));
+    assert_eq!(mul("PT0.5H", 2), Some("PT1H".into()));
+    assert_eq!(mul("-P1D", 3), Some("-P3D".into()));
+    assert_eq!(mul("P2W", 3), Some("P6W".into()));
+    assert_eq!(mul("P1D", 0), Some("P0D".into()));
+    assert_eq!(mul("P9223372036854775807D", 2), None);
+    assert_eq!(Duration::Weeks(i64::MIN).checked_mul(2), None);
+
+    assert_eq!(div("PT1M", 3), Some("PT20S".into()));
+    assert_eq!(div("PT1H", 4), Some("PT15M".into()));
+    assert_eq!(div("PT90M", 2), Some("PT45M".into()));
+    assert_eq!(div("P1D", 2), Some("PT12H".into()));
+    assert_eq!(div("P3D", 2), Some("P1DT12H".into()));
+    assert_eq!(div("P1W", 7), Some("P1D".into()));
+    assert_eq!(div("P2W", 2), Some("P1W".into()));
+    assert_eq!(div("P1W", 2), Some("P3DT12H".into()));
+    assert_eq!(div("PT1.5S", 3), Some("PT0.500S".into()));
+    assert_eq!(div("PT1.5H", 3), Some("PT30M".into()));
+    assert_eq!(div("-PT1M", 4), Some("-PT15S".into()));
+    assert_eq!(div("P1Y", 4), Some("P3M".into()));
+    assert_eq!(div("P1Y6M", 3), Some("P6M".into()));
+    assert_eq!(div("P2Y", 1), Some("P2Y".into()));
+    assert_eq!(div("P30M", 1), Some("P30M".into()));
+    assert_eq!(div("P1M", 2), Some("P0.5M".into()));
+    assert_eq!(div("P1Y1D", 2), Some("P6MT12H".into()));
+    assert_eq!(div("P1MT1H", 2), None);
+    assert_eq!(div("PT1S", 3), None);
+    assert_eq!(div("PT1S", 0), None);
+
+    // the operators truncate and saturate instead
+    assert_eq!(duration("PT20S") * 3, duration("PT60S"));
+    assert_eq!(duration("PT1S") / 3, duration("PT0.333S"));
+    assert_eq!(duration("P1MT1H") / 2, duration("PT30M"));
+    assert_eq!(
+        duration("P9223372036854775807D") * 2,
+        duration("P9223372036854775807D")
+    );
+    assert_eq!(Duration::Weeks(i64::MIN) * 2, Duration::Weeks(i64::MIN));
+    assert_eq!(Duration::Weeks(-3) / 0, Duration::Weeks(-i64::MAX));
+    assert_eq!(duration("PT0S") / 0, duration("PT0S"));
+    assert_eq!(duration("PT1H") / 0, duration("PT9223372036854775807H"));
+
+    for s in ["PT1M", "P1Y2M3DT4H5M6.789S", "P3W", "-PT1.5H"] {
+        assert_eq!(duration(s) * 1, duration(s));
+        assert_eq!(
+            duration(s)
+                .checked_mul(6)
+                .unwrap()
+                .checked_div(6)
+                .map(|d| d.total_seconds()),
+            Some(duration(s).total_seconds())
+        );
+    }
+
+    let ratio = |a: &str, b: &str| duration(a).div_duration(&duration(b));
+    assert_eq!(ratio("PT1M", "PT20S"), Some(3.0));
+    assert_eq!(ratio("PT20S", "PT1M"), Some(1.0 / 3.0));
+    assert_eq!(ratio("P1D", "PT1H"), Some(24.0));
+    assert_eq!(ratio("P1W", "P1D"), Some(7.0));
+    assert_eq!(ratio("-PT1H", "PT30M"), Some(-2.0));
+    assert_eq!(ratio("PT0S", "PT1S"), Some(0.0));
+    assert_eq!(ratio("P1Y", "P1M"), Some(12.0));
+    assert_eq!(ratio("P0D", "P1M"), Some(0.0));
+    assert_eq!(ratio("P1.5Y", "P6M"), Some(3.0));
+    assert_eq!(ratio("P1M", "P30D"), None);
+    assert_eq!(ratio("P30D", "P1M"), None);
+    assert_eq!(ratio("P1M1D", "P1M"), None);
+    assert_eq!(ratio("PT1H", "PT0S"), None);
+}
+
+#[test]
+fn test_duration_add_sub() {
+    let duration = |s: &str| iso8601::duration(s).unwrap();
+    let add = |a: &str, b: &str| duration(a).checked_add(&duration(b)).map(|d| d.to_string());
+    let sub = |a: &str, b: &str| duration(a).checked_sub(&duration(b)).map(|d| d.to_string());
+
+    assert_eq!(add("PT1H", "PT30M"), Some("PT1H30M".into()));
+    assert_eq!(add("P1Y2M3D", "P4Y5M6DT7H"), Some("P5Y7M9DT7H".into()));
+    assert_eq!(add("PT0.600S", "PT0.600S"), Some("PT1.200S".into()));
+    assert_eq!(add("PT59S", "PT1S"), Some("PT60S".into()));
+    assert_eq!(add("P1W", "P2W"), Some("P3W".into()));
+    assert_eq!(add("P1W", "P1D"), Some("P8D".into()));
+    assert_eq!(add("P1D", "-P1D"), Some("P0D".into()));
+    assert_eq!(add("P1D", "-PT1H"), Some("P1DT-1H".into()));
+    assert_eq!(sub("PT1H30M", "PT30M"), Some("PT1H".into()));
+    assert_eq!(sub("P1D", "P2D"), Some("-P1D".into()));
+    assert_eq!(sub("P2W", "P1W"), Some("P1W".into()));
+    assert_eq!(sub("PT0.500S", "PT0.700S"), Some("-PT0.200S".into()));
+    assert_eq!(sub("PT1S", "PT0.005S"), Some("PT0.995S".into()));
+    assert_eq!(add("PT1S", "-PT0.005S"), Some("PT0.995S".into()));
+    assert_eq!(add("-PT1S", "PT0.5S"), Some("-PT0.500S".into()));
+    assert_eq!(
+        duration("PT1S") - duration("PT0.005S"),
+        duration("PT0.995S")
+    );
+
+    // fractions of the same unit add up, and are otherwise counted into smaller units
+    assert_eq!(add("PT1.5H", "PT1.5H"), Some("PT3H".into()));
+    assert_eq!(add("PT1.25H", "PT0.5H"), Some("PT1.75H".into()));
+    assert_eq!(add("PT1.5H", "PT1M"), Some("PT1H1M1800S".into()));
+    assert_eq!(add("PT1M", "PT1.5H"), Some("PT1H1M1800S".into()));
+    assert_eq!(add("PT0.5H", "PT0.5M"), Some("PT1830S".into()));
+    assert_eq!(add("P1.5Y", "P1M"), Some("P1Y7M".into()));
+    assert_eq!(add("P1.25Y", "P1M"), Some("P1Y4M".into()));
+    assert_eq!(add("P1.5M", "P1D"), None);
+    assert_eq!(add("PT0.0000001H", "PT1M"), None);
+
+    assert_eq!(add("PT9223372036854775807S", "PT1S"), None);
+    assert_eq!(sub("-PT9223372036854775807S", "PT2S"), None);
+    assert_eq!(
+        Duration::Weeks(i64::MIN).checked_sub(&Duration::Weeks(i64::MIN)),
+        Some(Duration::Weeks(0))
+    );
+
+    // the operators saturate and truncate instead
+    assert_eq!(duration("PT1H") + duration("PT30M"), duration("PT1H30M"));
+    assert_eq!(duration("PT1H") - duration("PT30M"), duration("PT1H-30M"));
+    assert_eq!(
+        duration("PT9223372036854775807S") + duration("PT1S"),
+        duration("PT9223372036854775807S")
+    );
+    assert_eq!(
+        Duration::Weeks(i64::MIN) - Duration::Weeks(1),
+        Duration::Weeks(i64::MIN)
+    );
+    assert_eq!(
+        duration("PT0.0000001H") + duration("PT1M"),
+        duration("PT1M")
+    );
+
+    for (a, b) in [
+        ("P1Y2M3DT4H5M6.789S", "P9Y8M7DT6H5M4.321S"),
+        ("PT1.5H", "-PT0.25H"),
+        ("P2W", "P3D"),
+        ("PT1S", "-PT0.005S"),
+    ] {
+        let (a, b) = (duration(a), duration(b));
+        assert_eq!(a.checked_add(&b), b.checked_add(&a));
+        assert_eq!(
+            a.checked_add(&b)
+                .unwrap()
+                .checked_sub(&b)
+                .map(|d| d.to_string()),
+            a.checked_add(&Duration::default()).map(|d| d.to_string())
+        );
+    }
+}
+
+#[test]
+fn test_checked_and_saturating_arithmetic() {
+    use OverflowPolicy::*;
+    let date = |s: &str| iso8601::date(s).unwrap();
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let duration = |s: &str| iso8601::duration(s).unwrap();
+
+    let d = date("2023-01-31");
+    assert_eq!(d.checked_add_days(1), Some(date("2023-02-01")));
+    assert_eq!(d.checked_add_months(1, Reject), None);
+    assert_eq!(d.checked_add_years(-1, Reject), Some(date("2022-01-31")));
+    assert_eq!(Date::MAX.checked_add_days(1), None);
+    assert_eq!(d.saturating_add_days(-1), date("2023-01-30"));
+    assert_eq!(d.saturating_add_months(1, Constrain), date("2023-02-28"));
+    assert_eq!(d.saturating_add_years(1, Constrain), date("2024-01-31"));
+    assert_eq!(d.saturating_add_days(i64::MAX), Date::MAX);
+    assert_eq!(d.saturating_add_months(i64::MIN, Constrain), Date::MIN);
+    assert_eq!(d.saturating_add_years(i64::MAX, Constrain), Date::MAX);
+    assert_eq!(d.saturating_add_years(-(1 << 40), Constrain), Date::MIN);
+    // where the range is not the reason, nothing changes
+    assert_eq!(d.saturating_add_months(1, Reject), d);
+    assert_eq!(
+        date("2023-02-30").saturating_add_days(1),
+        date("2023-02-30")
+    );
+
+    // the bounds in the format of the date
+    let week = date("2023-W06-3");
+    let last = week.saturating_add_days(i64::MAX);
+    assert_eq!(last.to_ymd(), Date::MAX.add_days(-2));
+    assert_eq!(last.weekday(), Weekday::Sunday);
+    assert_eq!(last.checked_add_days(1), None);
+    assert_eq!(week.saturating_add_days(i64::MIN).to_ymd(), Ok(Date::MIN));
+    let ordinal = date("2023-039");
+    assert_eq!(
+        ordinal.saturating_add_days(i64::MAX),
+        Date::MAX.to_ordinal().unwrap()
+    );
+    assert_eq!(
+        ordinal.saturating_add_days(i64::MIN),
+        Date::MIN.to_ordinal().unwrap()
+    );
+
+    let start = dt("2023-02-08T23:40:00+01:00");
+    assert_eq!(
+        start.saturating_add(&duration("PT20M")),
+        dt("2023-02-09T00:00:00+01:00")
+    );
+    assert_eq!(
+        start.saturating_sub(&duration("P1M")),
+        dt("2023-01-08T23:40:00+01:00")
+    );
+    let end = start.saturating_add(&duration("P9223372036854775807Y"));
+    assert_eq!(end.date(), Date::MAX);
+    assert_eq!(
+        end.time(),
+        DateTime::MAX
+            .time()
+            .set_offset(UtcOffset::Fixed { seconds: 3600 })
+    );
+    let end = start.saturating_add(&duration("PT9223372036854775807H"));
+    assert_eq!(end.date(), Date::MAX);
+    let begin = start.saturating_sub(&duration("P9223372036854775807D"));
+    assert_eq!(begin.date(), Date::MIN);
+    assert_eq!(
+        begin.time(),
+        DateTime::MIN
+            .time()
+            .set_offset(UtcOffset::Fixed { seconds: 3600 })
+    );
+    assert_eq!(
+        start
+            .saturating_add(&Duration::YMDHMS {
+                year: i64::MAX,
+                month: i64::MAX,
+                day: 0,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                millisecond: 0,
+                fraction: None,
+            })
+            .date(),
+        Date::MAX
+    );
+    assert_eq!(start.saturating_add(&duration("P0.5Y")), start);
+    assert_eq!(
+        dt("2023-W06-3T00:00:00Z")
+            .saturating_sub(&duration("P9223372036854775807D"))
+            .date(),
+        Date::MIN.to_week_date().unwrap()
+    );
+
+    let time = iso8601::time("22:00:00").unwrap();
+    assert_eq!(
+        time.checked_add(&duration("PT9H")),
+        Some((iso8601::time("07:00:00").unwrap(), 1))
+    );
+    assert_eq!(time.checked_add(&Duration::Weeks(i64::MIN)), None);
+    assert_eq!(time.add(&Duration::Weeks(i64::MIN)).1, i32::MIN);
+
+    let max = duration("PT9223372036854775807S");
+    assert_eq!(max.saturating_add(&duration("PT1S")), max);
+    assert_eq!(max.saturating_sub(&duration("-PT1S")), max);
+    assert_eq!(max.saturating_mul(2), max);
+    assert_eq!(max.saturating_div(2), max.checked_div(2).unwrap_or(max / 2));
+    assert_eq!(duration("PT1S").saturating_div(0), max);
+    assert_eq!(
+        duration("PT1H") + duration("PT1H"),
+        duration("PT1H").saturating_mul(2)
+    );
+    assert_eq!(duration("PT1H") - duration("PT1H"), duration("PT0S"));
+}
+
+#[test]
+fn test_unix_timestamps() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+
+    assert_eq!(dt("1970-01-01T00:00:00Z").to_unix(), (0, 0));
+    assert_eq!(dt("1970-01-01T00:00:00").to_unix(), (0, 0));
+    assert_eq!(dt("1970-01-01T01:00:00+01:00").to_unix(), (0, 0));
+    assert_eq!(dt("2023-02-08T23:40:00Z").to_unix(), (1_675_899_600, 0));
+    assert_eq!(dt("2023-W06-3T23:40:00Z").to_unix(), (1_675_899_600, 0));
+    assert_eq!(
+        dt("2023-039T23:40:00.123456789Z").to_unix(),
+        (1_675_899_600, 123_456_789)
+    );
+    assert_eq!(dt("1969-12-31T23:59:59Z").to_unix(), (-1, 0));
+    assert_eq!(dt("1969-12-31T23:59:59.999Z").to_unix(), (-1, 999_000_000));
+    assert_eq!(dt("1900-01-01T00:00:00Z").to_unix(), (-2_208_988_800, 0));
+    assert_eq!(dt("0000-01-01T00:00:00Z").to_unix(), (-62_167_219_200, 0));
+    assert_eq!(dt("2016-12-31T23:59:60Z").to_unix(), (1_483_228_800, 0));
+    assert_eq!(
+        dt("2023-02-08T24:00:00Z").to_unix(),
+        dt("2023-02-09T00:00:00Z").to_unix()
+    );
+    assert_eq!(DateTime::MIN.to_unix(), (-67_768_100_567_971_200, 0));
+    assert_eq!(
+        DateTime::MAX.to_unix(),
+        (67_767_976_233_532_799, 999_999_999)
+    );
+
+    let from = |s: i64, n: u32, offset| DateTime::from_unix(s, n, offset).map(|dt| dt.to_string());
+    assert_eq!(
+        from(0, 0, UtcOffset::Utc),
+        Some("1970-01-01T00:00:00Z".into())
+    );
+    assert_eq!(
+        from(0, 0, UtcOffset::Unspecified),
+        Some("1970-01-01T00:00:00".into())
+    );
+    assert_eq!(
+        from(1_675_899_600, 0, UtcOffset::Fixed { seconds: -5 * 3600 }),
+        Some("2023-02-08T18:40:00-05:00".into())
+    );
+    assert_eq!(
+        from(1_675_899_600, 0, UtcOffset::Fixed { seconds: 3600 }),
+        Some("2023-02-09T00:40:00+01:00".into())
+    );
+    assert_eq!(
+        from(-1, 999_000_000, UtcOffset::Utc),
+        Some("1969-12-31T23:59:59.999Z".into())
+    );
+    assert_eq!(
+        from(-86_401, 0, UtcOffset::Utc),
+        Some("1969-12-30T23:59:59Z".into())
+    );
+    assert_eq!(
+        from(-2_208_988_800, 0, UtcOffset::Utc),
+        Some("1900-01-01T00:00:00Z".into())
+    );
+    assert_eq!(from(0, 1_000_000_000, UtcOffset::Utc), None);
+    assert_eq!(from(i64::MAX, 0, UtcOffset::Utc), None);
+    assert_eq!(from(i64::MIN, 0, UtcOffset::Utc), None);
+
+    let (seconds, nanoseconds) = DateTime::MAX.to_unix();
+    assert_eq!(
+        DateTime::from_unix(seconds, nanoseconds, UtcOffset::Unspecified),
+        Some(DateTime::MAX)
+    );
+    assert_eq!(
+        DateTime::from_unix(seconds + 1, 0, UtcOffset::Unspecified),
+        None
+    );
+    let (seconds, nanoseconds) = DateTime::MIN.to_unix();
+    assert_eq!(
+        DateTime::from_unix(seconds, nanoseconds, UtcOffset::Unspecified),
+        Some(DateTime::MIN)
+    );
+    assert_eq!(
+        DateTime::from_unix(seconds - 1, 0, UtcOffset::Unspecified),
+        None
+    );
+
+    for s in [
+        "2023-02-08T23:40:00.5+01:00",
+        "1969-07-20T20:17:40Z",
+        "-0333-07-11T12:00:00.000000001-03:30",
+    ] {
+        let (seconds, nanoseconds) = dt(s).to_unix();
+        let back = DateTime::from_unix(seconds, nanoseconds, dt(s).offset()).unwrap();
+        assert_eq!(back.to_string(), s);
+    }
+}
+
+#[test]
+fn test_julian_day() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+
+    assert_eq!(date("1970-01-01").to_julian_day(), 2_440_588);
+    assert_eq!(date("2000-01-01").to_julian_day(), 2_451_545);
+    assert_eq!(date("1858-11-17").to_julian_day(), 2_400_001);
+    assert_eq!(date("1582-10-15").to_julian_day(), 2_299_161);
+    assert_eq!(date("2023-02-08").to_julian_day(), 2_459_984);
+    assert_eq!(date("2023-W06-3").to_julian_day(), 2_459_984);
+    assert_eq!(date("2023-039").to_julian_day(), 2_459_984);
+    assert_eq!(date("-4713-11-24").to_julian_day(), 0);
+    assert_eq!(
+        date("2023-02-30").to_julian_day(),
+        date("2023-03-02").to_julian_day()
+    );
+    assert_eq!(Date::MIN.to_julian_day(), -784_350_575_245);
+    assert_eq!(Date::MAX.to_julian_day(), 784_354_017_364);
+
+    assert_eq!(Date::from_julian_day(2_440_588), Ok(date("1970-01-01")));
+    assert_eq!(Date::from_julian_day(2_459_984), Ok(date("2023-02-08")));
+    assert_eq!(Date::from_julian_day(0), Ok(date("-4713-11-24")));
+    assert_eq!(Date::from_julian_day(-1), Ok(date("-4713-11-23")));
+    assert_eq!(
+        Date::from_julian_day(Date::MIN.to_julian_day()),
+        Ok(Date::MIN)
+    );
+    assert_eq!(
+        Date::from_julian_day(Date::MAX.to_julian_day()),
+        Ok(Date::MAX)
+    );
+    assert_eq!(
+        Date::from_julian_day(Date::MIN.to_julian_day() - 1),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::from_julian_day(Date::MAX.to_julian_day() + 1),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(Date::from_julian_day(i64::MIN), Err(DateError::OutOfRange));
+    assert_eq!(Date::from_julian_day(i64::MAX), Err(DateError::OutOfRange));
+
+    for day in (-1_000_000..3_000_000).step_by(997) {
+        let date = Date::from_julian_day(day).unwrap();
+        assert_eq!(date.to_julian_day(), day);
+        assert_eq!(date.succ().unwrap().to_julian_day(), day + 1);
+    }
+}
+
+#[test]
+fn test_modified_julian_day() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+
+    assert_eq!(date("1858-11-17").to_modified_julian_day(), 0);
+    assert_eq!(date("1970-01-01").to_modified_julian_day(), 40_587);
+    assert_eq!(date("2000-01-01").to_modified_julian_day(), 51_544);
+    assert_eq!(date("2023-W06-3").to_modified_julian_day(), 59_983);
+    for s in ["1582-10-15", "2023-02-08", "-4713-11-24"] {
+        assert_eq!(
+            date(s).to_modified_julian_day(),
+            date(s).to_julian_day() - 2_400_001
+        );
+    }
+
+    assert_eq!(Date::from_modified_julian_day(0), Ok(date("1858-11-17")));
+    assert_eq!(Date::from_modified_julian_day(-1), Ok(date("1858-11-16")));
+    assert_eq!(
+        Date::from_modified_julian_day(Date::MAX.to_modified_julian_day()),
+        Ok(Date::MAX)
+    );
+    assert_eq!(
+        Date::from_modified_julian_day(Date::MIN.to_modified_julian_day() - 1),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        Date::from_modified_julian_day(i64::MIN),
+        Err(DateError::OutOfRange)
+    );
+
+    assert_eq!(dt("1858-11-17T00:00:00Z").to_modified_julian_date(), 0.0);
+    assert_eq!(dt("1858-11-16T12:00:00Z").to_modified_julian_date(), -0.5);
+    assert_eq!(
+        dt("1970-01-01T06:00:00Z").to_modified_julian_date(),
+        40_587.25
+    );
+    assert_eq!(
+        dt("2023-02-08T18:00:00+06:00").to_modified_julian_date(),
+        59_983.5
+    );
+    assert_eq!(
+        dt("2023-02-08T23:59:60Z").to_modified_julian_date(),
+        59_984.0
+    );
+
+    let from = |mjd, offset| DateTime::from_modified_julian_date(mjd, offset);
+    assert_eq!(
+        from(40_587.0, UtcOffset::Utc),
+        Some(dt("1970-01-01T00:00:00Z"))
+    );
+    assert_eq!(from(-0.5, UtcOffset::Utc), Some(dt("1858-11-16T12:00:00Z")));
+    assert_eq!(
+        from(59_983.5, UtcOffset::Fixed { seconds: 6 * 3600 }),
+        Some(dt("2023-02-08T18:00:00+06:00"))
+    );
+    assert_eq!(
+        from(59_983.1, UtcOffset::Utc),
+        Some(dt("2023-02-08T02:24:00Z"))
+    );
+    assert_eq!(from(f64::NAN, UtcOffset::Utc), None);
+    assert_eq!(from(f64::INFINITY, UtcOffset::Utc), None);
+    assert_eq!(from(1e20, UtcOffset::Utc), None);
+    assert_eq!(from(-1e13, UtcOffset::Utc), None);
+
+    // whole microseconds survive the way back within these centuries
+    let precise = dt("2023-02-08T23:40:00.123456Z");
+    assert_eq!(
+        from(precise.to_modified_julian_date(), UtcOffset::Utc),
+        Some(precise)
+    );
+}
+
+#[test]
+fn test_days_since_epoch() {
+    let date = |s: &str| iso8601::date(s).unwrap();
+
+    assert_eq!(date("1970-01-01").to_days_since(Epoch::Unix), 0);
+    assert_eq!(date("1969-12-31").to_days_since(Epoch::Unix), -1);
+    assert_eq!(date("2000-01-01").to_days_since(Epoch::Unix), 10_957);
+    assert_eq!(date("0001-01-01").to_days_since(Epoch::RataDie), 1);
+    assert_eq!(date("0000-12-31").to_days_since(Epoch::RataDie), 0);
+    assert_eq!(date("1970-01-01").to_days_since(Epoch::RataDie), 719_163);
+    assert_eq!(date("2023-W06-3").to_days_since(Epoch::RataDie), 738_559);
+    assert_eq!(date("2023-039").to_days_since(Epoch::Unix), 19_396);
     assert_eq!(
-        datetime("20070831T1646+00:00"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2007,
-                month: 8,
-                day: 31
-            },
-            time: Time {
-                hour: 16,
-                minute: 46,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        Date::MAX.to_days_since(Epoch::Unix),
+        Date::MAX.to_julian_day() - 2_440_588
     );
+
+    for epoch in [Epoch::Unix, Epoch::RataDie] {
+        assert_eq!(
+            Date::from_days(Date::MIN.to_days_since(epoch), epoch),
+            Ok(Date::MIN)
+        );
+        assert_eq!(
+            Date::from_days(Date::MAX.to_days_since(epoch) + 1, epoch),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(Date::from_days(i64::MIN, epoch), Err(DateError::OutOfRange));
+
+        // every day number of an i32 is a date, and comes back the same
+        for days in [i32::MIN, -1, 0, 1, i32::MAX] {
+            let date = Date::from_days(days.into(), epoch).unwrap();
+            assert_eq!(date.to_days_since(epoch), i64::from(days));
+        }
+    }
     assert_eq!(
-        datetime("20070831T1647+0000"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2007,
-                month: 8,
-                day: 31
-            },
-            time: Time {
-                hour: 16,
-                minute: 47,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
+        Date::from_days(i32::MIN.into(), Epoch::Unix),
+        Ok(Date::YMD {
+            year: -5_877_641,
+            month: 6,
+            day: 23
         })
     );
     assert_eq!(
-        datetime("2009-02-01T09:00:22+05"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2009,
-                month: 2,
-                day: 1
-            },
-            time: Time {
-                hour: 9,
-                minute: 0,
-                second: 22,
-                millisecond: 0,
-                tz_offset_hours: 5,
-                tz_offset_minutes: 0
-            }
+        Date::from_days(i32::MAX.into(), Epoch::RataDie),
+        Ok(Date::YMD {
+            year: 5_879_611,
+            month: 7,
+            day: 11
         })
     );
+}
+
+#[test]
+fn test_truncate_to() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let truncated = |s: &str, unit| dt(s).truncate_to(unit).to_string();
+
+    let s = "2023-02-08T23:40:12.345+01:00";
+    assert_eq!(truncated(s, Unit::Second), "2023-02-08T23:40:12+01:00");
+    assert_eq!(truncated(s, Unit::Minute), "2023-02-08T23:40+01:00");
+    assert_eq!(truncated(s, Unit::Hour), "2023-02-08T23+01:00");
+    assert_eq!(truncated(s, Unit::Day), "2023-02-08T00+01:00");
+    assert_eq!(truncated(s, Unit::Week), "2023-02-06T00+01:00");
+    assert_eq!(truncated(s, Unit::Month), "2023-02-01T00+01:00");
+    assert_eq!(truncated(s, Unit::Year), "2023-01-01T00+01:00");
+
+    // week and ordinal dates stay in their format
+    assert_eq!(truncated("2023-W06-3T12:00Z", Unit::Week), "2023-W06-1T00Z");
     assert_eq!(
-        datetime("2010-01-01T12:00:00+01:00"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2010,
-                month: 1,
-                day: 1
-            },
-            time: Time {
-                hour: 12,
-                minute: 0,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 1,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-W06-3T12:00Z", Unit::Month),
+        "2023-W05-3T00Z"
     );
+    assert_eq!(truncated("2020-W53-5T12:00Z", Unit::Year), "2020-W01-1T00Z");
+    assert_eq!(truncated("2023-039T12:00Z", Unit::Month), "2023-032T00Z");
+    assert_eq!(truncated("2023-039T12:00Z", Unit::Week), "2023-037T00Z");
+    assert_eq!(truncated("2023-039T12:00Z", Unit::Year), "2023-001T00Z");
+
+    // the end of a day is the start of the next for days and larger units
     assert_eq!(
-        datetime("2011-06-30T18:30:00+02:00"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2011,
-                month: 6,
-                day: 30
-            },
-            time: Time {
-                hour: 18,
-                minute: 30,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 2,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-02-28T24:00:00Z", Unit::Hour),
+        "2023-02-28T24Z"
     );
     assert_eq!(
-        datetime("2015-06-29T23:07+02:00"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2015,
-                month: 6,
-                day: 29
-            },
-            time: Time {
-                hour: 23,
-                minute: 7,
-                second: 0,
-                millisecond: 0,
-                tz_offset_hours: 2,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-02-28T24:00:00Z", Unit::Day),
+        "2023-03-01T00Z"
     );
     assert_eq!(
-        datetime("2015-06-26T16:43:16"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2015,
-                month: 6,
-                day: 26
-            },
-            time: Time {
-                hour: 16,
-                minute: 43,
-                second: 16,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-02-28T24:00:00Z", Unit::Month),
+        "2023-03-01T00Z"
     );
     assert_eq!(
-        datetime("2015-06-26T16:43:16"),
-        Ok(DateTime {
-            date: Date::YMD {
-                year: 2015,
-                month: 6,
-                day: 26
-            },
-            time: Time {
-                hour: 16,
-                minute: 43,
-                second: 16,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2016-12-31T23:59:60.5Z", Unit::Second),
+        "2016-12-31T23:59:60Z"
     );
     assert_eq!(
-        datetime("2015-W05-6T04:05:06+07:00"),
-        Ok(DateTime {
-            date: Date::Week {
-                year: 2015,
-                ww: 5,
-                d: 6
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2016-12-31T23:59:60.5Z", Unit::Minute),
+        "2016-12-31T23:59Z"
     );
+
+    // dates that do not exist keep their month and year
     assert_eq!(
-        datetime("2015W056T04:05:06+07:00"),
-        Ok(DateTime {
-            date: Date::Week {
-                year: 2015,
-                ww: 5,
-                d: 6
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-02-30T12:00:00", Unit::Month),
+        "2023-02-01T00"
     );
     assert_eq!(
-        datetime("2015-056T04:05:06+07:00"),
-        Ok(DateTime {
-            date: Date::Ordinal {
-                year: 2015,
-                ddd: 56
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
-            }
-        })
+        truncated("2023-02-30T12:00:00", Unit::Week),
+        "2023-02-27T00"
     );
+
+    // the bounds stay in range
     assert_eq!(
-        datetime("2015056T04:05:06+07:00"),
-        Ok(DateTime {
-            date: Date::Ordinal {
-                year: 2015,
-                ddd: 56
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
-            }
-        })
+        DateTime::MIN.truncate_to(Unit::Week).to_string(),
+        "-2147483648-01-01T00"
     );
     assert_eq!(
-        datetime("2015-297T16:30:48Z"),
-        Ok(DateTime {
-            date: Date::Ordinal {
-                year: 2015,
-                ddd: 297
-            },
-            time: Time {
-                hour: 16,
-                minute: 30,
-                second: 48,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        DateTime::MIN.truncate_to(Unit::Year),
+        DateTime::MIN.truncate_to(Unit::Week)
+    );
+    assert_eq!(
+        DateTime::MAX.truncate_to(Unit::Year).to_string(),
+        "+2147483647-01-01T00"
+    );
+
+    for unit in [
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Month,
+    ] {
+        let truncated = dt(s).truncate_to(unit);
+        assert_eq!(truncated.truncate_to(unit), truncated);
+        assert!(truncated <= dt(s));
+    }
+
+    let time = |s: &str| iso8601::time(s).unwrap();
+    assert_eq!(time("23:40:12Z").truncate_to(Unit::Minute), time("23:40Z"));
+    assert_eq!(time("23:40").truncate_to(Unit::Hour), time("T23"));
+    assert_eq!(time("24:00").truncate_to(Unit::Day), time("T00"));
+    assert_eq!(time("23:40:12").truncate_to(Unit::Year), time("T00"));
+
+    // the precision goes down to the unit, and the fraction goes with it
+    let t = time("23:40:12.345Z");
+    assert_eq!(t.truncate_to(Unit::Second), time("23:40:12Z"));
+    assert_eq!(t.truncate_to(Unit::Second).fraction_digits(), 0);
+    assert_eq!(t.truncate_to(Unit::Hour).to_string(), "T23Z");
+    assert_eq!(time("23:40").truncate_to(Unit::Second), time("23:40"));
+}
+
+#[test]
+fn test_round_to() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let round = |s: &str, increment, unit, mode| {
+        dt(s)
+            .round_to_increment(increment, unit, mode)
+            .map(|dt| dt.to_string())
+    };
+    let some = |s: &str| Some(s.to_string());
+    use RoundingMode::{Ceil, Floor, Nearest};
+
+    // billing in quarters of an hour
+    let s = "2023-02-08T23:52:30+01:00";
+    assert_eq!(
+        round(s, 15, Unit::Minute, Nearest),
+        some("2023-02-09T00:00+01:00")
+    );
+    assert_eq!(
+        round(s, 15, Unit::Minute, Floor),
+        some("2023-02-08T23:45+01:00")
+    );
+    assert_eq!(
+        round(s, 15, Unit::Minute, Ceil),
+        some("2023-02-09T00:00+01:00")
+    );
+    assert_eq!(
+        round("2023-02-08T10:07:29Z", 15, Unit::Minute, Nearest),
+        some("2023-02-08T10:00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T10:07:30Z", 15, Unit::Minute, Nearest),
+        some("2023-02-08T10:15Z")
+    );
+    assert_eq!(
+        round("2023-02-08T10:15:00Z", 15, Unit::Minute, Ceil),
+        some("2023-02-08T10:15Z")
+    );
+
+    // fractions of a second
+    let s = "2023-02-08T23:59:59.499999999Z";
+    assert_eq!(
+        round(s, 1, Unit::Second, Nearest),
+        some("2023-02-08T23:59:59Z")
+    );
+    assert_eq!(
+        round(s, 1, Unit::Second, Ceil),
+        some("2023-02-09T00:00:00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T12:00:00.5", 1, Unit::Second, Nearest),
+        some("2023-02-08T12:00:01")
+    );
+    assert_eq!(
+        round("2023-02-08T12:00:00.5", 10, Unit::Second, Floor),
+        some("2023-02-08T12:00:00")
+    );
+
+    // days, weeks, months and years
+    assert_eq!(
+        round("2023-02-08T11:59:59Z", 1, Unit::Day, Nearest),
+        some("2023-02-08T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T12:00:00Z", 1, Unit::Day, Nearest),
+        some("2023-02-09T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T12:00:00Z", 1, Unit::Week, Floor),
+        some("2023-02-06T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T12:00:00Z", 1, Unit::Week, Nearest),
+        some("2023-02-06T00Z")
+    );
+    assert_eq!(
+        round("2023-02-09T12:00:00Z", 1, Unit::Week, Nearest),
+        some("2023-02-13T00Z")
+    );
+    assert_eq!(
+        round("2023-W06-3T12:00:00Z", 1, Unit::Week, Ceil),
+        some("2023-W07-1T00Z")
+    );
+    assert_eq!(
+        round("2023-02-15T00:00:00Z", 1, Unit::Month, Nearest),
+        some("2023-03-01T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T00:00:00Z", 1, Unit::Month, Nearest),
+        some("2023-02-01T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T00:00:00Z", 6, Unit::Month, Ceil),
+        some("2023-07-01T00Z")
+    );
+    assert_eq!(
+        round("2023-07-03T00:00:00Z", 1, Unit::Year, Nearest),
+        some("2024-01-01T00Z")
+    );
+    assert_eq!(
+        round("2023-02-08T00:00:00Z", 10, Unit::Year, Floor),
+        some("2020-01-01T00Z")
+    );
+    assert_eq!(
+        round("-0005-02-08T00:00:00Z", 10, Unit::Year, Floor),
+        some("-0010-01-01T00Z")
     );
     assert_eq!(
-        datetime("2015-W43-6T16:30:48Z"),
-        Ok(DateTime {
-            date: Date::Week {
-                year: 2015,
-                ww: 43,
-                d: 6
-            },
-            time: Time {
-                hour: 16,
-                minute: 30,
-                second: 48,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        round("2023-039T12:00:00Z", 1, Unit::Month, Ceil),
+        some("2023-060T00Z")
     );
     assert_eq!(
-        datetime("2001-W05-6T04:05:06.1234Z"),
-        Ok(DateTime {
-            date: Date::Week {
-                year: 2001,
-                ww: 5,
-                d: 6
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        round("2020-W53-5T12:00:00Z", 1, Unit::Year, Floor),
+        some("2020-W01-1T00Z")
     );
     assert_eq!(
-        datetime("2001-W05-6T04:05:06.12345Z"),
-        Ok(DateTime {
-            date: Date::Week {
-                year: 2001,
-                ww: 5,
-                d: 6
-            },
-            time: Time {
-                hour: 4,
-                minute: 5,
-                second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
-            }
-        })
+        round("2020-W53-5T12:00:00Z", 1, Unit::Year, Ceil),
+        some("2021-W01-1T00Z")
     );
-}
-
-#[test]
-fn issue12_regression_1() {
-    let input = "164801.";
 
+    // leap seconds and the end of a day count as the next minute and day
     assert_eq!(
-        Ok(Time {
-            hour: 16,
-            minute: 48,
-            second: 1,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
-        }),
-        time(input)
+        round("2016-12-31T23:59:60Z", 1, Unit::Minute, Floor),
+        some("2017-01-01T00:00Z")
+    );
+    assert_eq!(
+        round("2023-02-28T24:00:00Z", 1, Unit::Day, Floor),
+        some("2023-03-01T00Z")
     );
-}
 
-#[test]
-fn issue12_regression_2() {
-    let input = "04:05:06.1226001015632)*450";
+    // increments have to divide the next larger unit
+    for (increment, unit) in [
+        (0, Unit::Second),
+        (7, Unit::Second),
+        (45, Unit::Minute),
+        (5, Unit::Hour),
+        (2, Unit::Day),
+        (2, Unit::Week),
+        (5, Unit::Month),
+        (0, Unit::Year),
+    ] {
+        assert_eq!(round("2023-02-08T12:00:00Z", increment, unit, Floor), None);
+    }
+    assert_eq!(
+        round("2023-02-08T12:00:00Z", u32::MAX, Unit::Year, Floor),
+        some("0000-01-01T00Z")
+    );
 
+    // datetimes that do not exist, and rounding out of range
+    assert_eq!(round("2023-02-30T12:00:00Z", 1, Unit::Hour, Floor), None);
+    assert_eq!(DateTime::MAX.round_to(Unit::Second, Ceil), None);
+    assert_eq!(DateTime::MAX.round_to(Unit::Year, Nearest), None);
     assert_eq!(
-        Ok(Time {
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 122,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
-        }),
-        time(input)
+        DateTime::MAX.round_to(Unit::Year, Floor),
+        Some(DateTime::MAX.truncate_to(Unit::Year))
     );
+    assert_eq!(
+        DateTime::MIN.round_to(Unit::Year, Ceil),
+        Some(DateTime::MIN.truncate_to(Unit::Year))
+    );
+    assert_eq!(DateTime::MIN.round_to(Unit::Week, Floor), None);
+
+    // rounding down is truncating, but for leap seconds and the bounds
+    let s = "2023-02-08T23:40:12.345+01:00";
+    for unit in [
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Month,
+        Unit::Year,
+    ] {
+        assert_eq!(dt(s).round_to(unit, Floor), Some(dt(s).truncate_to(unit)));
+        assert!(dt(s).round_to(unit, Ceil).unwrap() > dt(s));
+    }
 }
 
 #[test]
-fn test_duration_ymdhms() {
-    use core::time::Duration as StdDuration;
+fn test_with_offset() {
+    let dt = |s: &str| iso8601::datetime(s).unwrap();
+    let moved = |s: &str, offset| dt(s).with_offset(offset).map(|dt| dt.to_string());
+    let fixed = |seconds| UtcOffset::Fixed { seconds };
+    let some = |s: &str| Some(s.to_string());
 
-    // full YMDHMS
-    let dur = duration("P1Y2M3DT4H5M6S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 2,
-            day: 3,
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 0,
-        },
-        dur
+        moved("2023-02-08T23:40:00Z", fixed(3600)),
+        some("2023-02-09T00:40:00+01:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36993906, 0));
-
-    // full YMDHMS with milliseconds dot delimiter
-    let dur = duration("P1Y2M3DT4H5M6.7S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 2,
-            day: 3,
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 700,
-        },
-        dur
+        moved("2023-02-08T00:40:00Z", fixed(-3600)),
+        some("2023-02-07T23:40:00-01:00")
     );
     assert_eq!(
-        StdDuration::from(dur),
-        StdDuration::new(36993906, 700000000)
+        moved("2023-02-08T23:40:00+01:00", UtcOffset::Utc),
+        some("2023-02-08T22:40:00Z")
     );
-
-    // full YMDHMS with milliseconds comma delimiter
-    let dur = duration("P1Y2M3DT4H5M6,7S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 2,
-            day: 3,
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 700,
-        },
-        dur
+        moved("2023-02-08T23:40:00+01:00", fixed(0)),
+        some("2023-02-08T22:40:00+00:00")
     );
     assert_eq!(
-        StdDuration::from(dur),
-        StdDuration::new(36993906, 700000000)
+        moved("2023-02-08T23:40:00+01:00", UtcOffset::UnknownLocal),
+        some("2023-02-08T22:40:00-00:00")
     );
-
-    // subset YM-HM-
-    let dur = duration("P1Y2MT4H5M").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 2,
-            day: 0,
-            hour: 4,
-            minute: 5,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        moved("2023-02-08T23:40:00", fixed(-9 * 3600)),
+        some("2023-02-08T14:40:00-09:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36734700, 0));
-
-    // subset Y-----
-    let dur = duration("P1Y").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        moved("2023-02-28T23:40:00Z", fixed(3600)),
+        some("2023-03-01T00:40:00+01:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(31536000, 0));
 
-    // subset ---H--
-    let dur = duration("PT4H").unwrap();
+    // the format, precision and fraction stay
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 4,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        moved("2023-W06-3T23:40:00.500Z", fixed(3600)),
+        some("2023-W06-4T00:40:00.500+01:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(14400, 0));
-
-    // subset -----S with milliseconds dot delimiter
-    let dur = duration("PT6.7S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 0,
-            second: 6,
-            millisecond: 700,
-        },
-        dur
+        moved("2023-039T23:40Z", fixed(1800)),
+        some("2023-040T00:10+00:30")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(6, 700000000));
-
-    // subset -----S with milliseconds comma delimiter
-    let dur = duration("PT6,700S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 0,
-            second: 6,
-            millisecond: 700,
-        },
-        dur
+        moved("2023-02-08T23Z", fixed(5 * 3600 + 1800)),
+        some("2023-02-09T04:30+05:30")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(6, 700000000));
-
-    // empty duration, using Y
-    let dur = duration("P0Y").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        moved("2023-02-08T24:00:00Z", fixed(3600)),
+        some("2023-02-09T01:00:00+01:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
 
-    // empty duration, using S
-    let dur = duration("PT0S").unwrap();
+    // leap seconds stay for whole minutes
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        moved("2016-12-31T23:59:60.5Z", fixed(-3600)),
+        some("2016-12-31T22:59:60.5-01:00")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
-
-    let dur = duration("PT42M30S").unwrap();
     assert_eq!(
-        Duration::YMDHMS {
-            year: 0,
-            month: 0,
-            day: 0,
-            hour: 0,
-            minute: 42,
-            second: 30,
-            millisecond: 0,
-        },
-        dur
+        moved("2016-12-31T23:59:60Z", fixed(30)),
+        some("2017-01-01T00:00:30+00:00:30")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(2550, 0));
 
-    let dur = duration("P0001-02-03T04:05:06").unwrap();
-    assert_eq!(
-        Duration::YMDHMS {
-            year: 1,
-            month: 2,
-            day: 3,
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 0,
-        },
-        dur
-    );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36993906, 0));
+    // the instant stays
+    for offset in [
+        fixed(-86_399),
+        fixed(-3600),
+        UtcOffset::Utc,
+        fixed(20_700),
+        fixed(86_399),
+    ] {
+        let s = dt("2023-02-08T23:40:00.123+01:00");
+        let with = s.with_offset(offset).unwrap();
+        assert!(with.same_instant(&s));
+        assert_eq!(with.offset(), offset);
+        assert_eq!(with.with_offset(s.offset()), Some(s));
+    }
 
-    let dur = duration("P2018-04-27T00:00:00").unwrap();
+    assert_eq!(moved("2023-02-30T12:00:00Z", fixed(3600)), None);
+    #[cfg(feature = "legacy-fields")]
+    {
+        let mut late = dt("2023-02-08T23:00:00Z");
+        late.time.hour = 25;
+        assert_eq!(late.with_offset(fixed(3600)), None);
+    }
+    assert_eq!(DateTime::MAX.with_offset(fixed(1)), None);
+    assert_eq!(DateTime::MIN.with_offset(fixed(-1)), None);
     assert_eq!(
-        Duration::YMDHMS {
-            year: 2018,
-            month: 4,
-            day: 27,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-        },
-        dur
+        DateTime::MIN.with_offset(fixed(1)).map(|dt| dt.to_string()),
+        some("-2147483648-01-01T00:00:01+00:00:01")
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(63652348800, 0));
 }
 
 #[test]
-fn test_duration_weeks() {
-    use core::time::Duration as StdDuration;
+fn test_time_of_day() {
+    let time = |s: &str| iso8601::time(s).unwrap();
 
-    let dur = duration("P0W").unwrap();
-    assert_eq!(Duration::Weeks(0), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
-    let dur = duration("P26W").unwrap();
-    assert_eq!(Duration::Weeks(26), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(15724800, 0));
-    let dur = duration("P52W").unwrap();
-    assert_eq!(Duration::Weeks(52), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(31449600, 0));
+    assert_eq!(time("00:00:00").seconds_of_day(), 0);
+    assert_eq!(time("12:00").seconds_of_day(), 43_200);
+    assert_eq!(time("23:59:59.999999999").seconds_of_day(), 86_399);
+    assert_eq!(time("23:59:60.5Z").seconds_of_day(), 86_400);
+    assert_eq!(time("24:00:00").seconds_of_day(), 86_400);
+    assert_eq!(time("12:30:00+05:30").seconds_of_day(), 45_000);
+
+    assert_eq!(time("00:00:00").nanos_of_day(), 0);
+    assert_eq!(
+        time("23:59:59.999999999").nanos_of_day(),
+        86_399_999_999_999
+    );
+    assert_eq!(time("23:59:60.5").nanos_of_day(), 86_400_500_000_000);
+    assert_eq!(time("24:00").nanos_of_day(), 86_400_000_000_000);
+
+    assert_eq!(time("00:00").day_fraction(), 0.0);
+    assert_eq!(time("06:00").day_fraction(), 0.25);
+    assert_eq!(time("12:00:00,0").day_fraction(), 0.5);
+    assert_eq!(time("24:00").day_fraction(), 1.0);
+    assert!(time("23:59:59.999999999").day_fraction() < 1.0);
+
+    // the fraction of the day is the one of a Modified Julian Date in UTC, as far as it holds
+    let dt = iso8601::datetime("2023-02-08T23:40:12.5Z").unwrap();
+    let mjd = dt.to_modified_julian_date();
+    assert!((mjd.fract() - dt.time().day_fraction()).abs() < 1e-9);
+    assert_eq!(mjd.trunc() as i64, dt.date().to_modified_julian_day());
+
+    // times out of range, which can only be built by hand, saturate
+    #[cfg(feature = "legacy-fields")]
+    {
+        let mut time = time("00:00");
+        time.hour = u32::MAX;
+        assert_eq!(time.seconds_of_day(), u32::MAX);
+        assert_eq!(time.nanos_of_day(), u64::from(u32::MAX) * 1_000_000_000);
+    }
 }